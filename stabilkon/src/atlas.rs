@@ -0,0 +1,125 @@
+use crate::common_types::{Rectangle, Vec2};
+
+/// Calculates texture source rectangles for every frame of a flipbook animation laid out in a texture atlas.
+///
+/// Frames are expected to go left-to-right, row by row: each next frame is offset by `advance.x`,
+/// and once `columns` frames were taken from a row, x offset is reset and `advance.y` is added instead.
+/// Returned rectangles can be fed to any `set` method of a mesh builder one frame at a time.
+/// Returns `None` if `columns` is 0, since no frame would fit into a row.
+///
+/// * `first` - Texture source rectangle of the very first frame.
+/// * `frame_count` - Total amount of frames in the animation.
+/// * `columns` - Amount of frames in a single atlas row, must be greater than 0.
+/// * `advance` - Offset between frames: x is used inside a row, y is used between rows.
+#[must_use]
+pub fn animation_frames<TRect, TVec2>(
+    first: TRect,
+    frame_count: u32,
+    columns: u32,
+    advance: TVec2,
+) -> Option<Vec<Rectangle>>
+where
+    TRect: Into<Rectangle>,
+    TVec2: Into<Vec2>,
+{
+    if columns == 0 {
+        return None;
+    }
+    let first: Rectangle = first.into();
+    let advance: Vec2 = advance.into();
+    let frames = (0..frame_count)
+        .map(|frame| {
+            let column = frame % columns;
+            let row = frame / columns;
+            Rectangle {
+                x: first.x + column as f32 * advance.x,
+                y: first.y + row as f32 * advance.y,
+                z: first.z,
+                w: first.w,
+            }
+        })
+        .collect();
+    Some(frames)
+}
+
+/// Texture atlas sliced into a uniform grid of equally sized tiles, e.g. a Tiled tileset.
+///
+/// Tiles are numbered left-to-right, row by row, starting from 0 at the top-left of the atlas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AtlasGrid {
+    /// Size of the texture atlas in pixels.
+    pub texture_size: Vec2,
+    /// Size of a single tile in pixels.
+    pub tile_size: Vec2,
+    /// Amount of tiles in a single row.
+    pub columns: u32,
+    /// Amount of tile rows.
+    pub rows: u32,
+    /// Gap between neighbouring tiles in pixels.
+    pub spacing: Vec2,
+    /// Gap between atlas edges and outermost tiles in pixels.
+    pub margin: Vec2,
+}
+
+impl AtlasGrid {
+    /// Creates a grid fitting as many whole tiles into the given texture atlas as possible.
+    ///
+    /// * `texture_size` - Size of the texture atlas in pixels.
+    /// * `tile_size` - Size of a single tile in pixels.
+    /// * `spacing` - Gap between neighbouring tiles in pixels.
+    /// * `margin` - Gap between atlas edges and outermost tiles in pixels.
+    #[must_use]
+    pub fn new<TVec2: Into<Vec2>>(
+        texture_size: TVec2,
+        tile_size: TVec2,
+        spacing: TVec2,
+        margin: TVec2,
+    ) -> Self {
+        let texture_size: Vec2 = texture_size.into();
+        let tile_size: Vec2 = tile_size.into();
+        let spacing: Vec2 = spacing.into();
+        let margin: Vec2 = margin.into();
+        let fit = |texture: f32, tile: f32, spacing: f32, margin: f32| {
+            if tile > 0.0 {
+                ((texture - 2.0 * margin + spacing) / (tile + spacing)).max(0.0) as u32
+            } else {
+                0
+            }
+        };
+        Self {
+            texture_size,
+            tile_size,
+            columns: fit(texture_size.x, tile_size.x, spacing.x, margin.x),
+            rows: fit(texture_size.y, tile_size.y, spacing.y, margin.y),
+            spacing,
+            margin,
+        }
+    }
+
+    /// Gets the total amount of tiles in the grid.
+    #[inline]
+    #[must_use]
+    pub fn tile_count(&self) -> u32 {
+        self.columns.saturating_mul(self.rows)
+    }
+
+    /// Gets the texture source rectangle in pixels for the tile with the given index,
+    /// or `None` if the index is out of the grid.
+    ///
+    /// * `index` - Linear tile index, tiles go left-to-right, row by row.
+    #[must_use]
+    pub fn source_of(&self, index: u32) -> Option<Rectangle> {
+        if index >= self.tile_count() {
+            return None;
+        }
+        let column = index % self.columns;
+        let row = index / self.columns;
+        Some(Rectangle {
+            x: self.margin.x + column as f32 * (self.tile_size.x + self.spacing.x),
+            y: self.margin.y + row as f32 * (self.tile_size.y + self.spacing.y),
+            z: self.tile_size.x,
+            w: self.tile_size.y,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 pub(crate) type Color = Vec4;
 pub(crate) type Rectangle = Vec4;
 pub(crate) type Vec2 = mint::Vector2<f32>;
+pub(crate) type Vec3 = mint::Vector3<f32>;
 pub(crate) type Vec4 = mint::Vector4<f32>;
 
 pub(crate) static VEC2_ZERO: Vec2 = mint::Vector2 {
@@ -8,13 +9,71 @@ pub(crate) static VEC2_ZERO: Vec2 = mint::Vector2 {
     y: 0.0_f32,
 };
 
+/// Index of a quad in a mesh builder.
+///
+/// Builder methods taking a quad index accept anything convertible into it, including plain `u32`.
+/// Wrap indices into it when juggling several builders to make intent explicit.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadIndex(pub u32);
+
+impl From<u32> for QuadIndex {
+    #[inline]
+    fn from(index: u32) -> Self {
+        Self(index)
+    }
+}
+
+impl From<QuadIndex> for u32 {
+    #[inline]
+    fn from(index: QuadIndex) -> Self {
+        index.0
+    }
+}
+
+/// Tile map layout of a mesh builder created with `MeshFromQuads::for_grid`,
+/// mapping 2D tile coordinates to quad indices row by row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TileGrid {
+    /// Amount of tiles in a single row.
+    pub columns: u32,
+    /// Amount of tile rows.
+    pub rows: u32,
+    /// Destination size of a single tile.
+    pub tile_size: Vec2,
+}
+
+impl TileGrid {
+    /// Gets index of the quad used for the tile at the given column and row.
+    /// Returns `None` if the tile is out of grid bounds.
+    #[inline]
+    #[must_use]
+    pub fn quad_index(&self, column: u32, row: u32) -> Option<u32> {
+        if column < self.columns && row < self.rows {
+            Some(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct PosUvColor {
     pub position: Vec2,
     pub uv: Vec2,
     pub color: Color,
 }
 
+// SAFETY: `PosUvColor` is `repr(C)` and consists of `repr(C)` mint vectors of f32 only,
+// so it has no padding and any bit pattern is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for PosUvColor {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for PosUvColor {}
+
 impl PosUvColor {
     #[inline]
     pub fn new<TColor, TVec2>(position: TVec2, uv: TVec2, color: TColor) -> Self
@@ -30,6 +89,40 @@ impl PosUvColor {
     }
 }
 
+#[cfg(feature = "wgpu")]
+impl PosUvColor {
+    /// Vertex attributes of `PosUvColor`: position, UV and color at shader locations 0, 1 and 2.
+    const WGPU_ATTRIBUTES: [wgpu::VertexAttribute; 3] = [
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x2,
+            offset: std::mem::offset_of!(PosUvColor, position) as wgpu::BufferAddress,
+            shader_location: 0,
+        },
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x2,
+            offset: std::mem::offset_of!(PosUvColor, uv) as wgpu::BufferAddress,
+            shader_location: 1,
+        },
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x4,
+            offset: std::mem::offset_of!(PosUvColor, color) as wgpu::BufferAddress,
+            shader_location: 2,
+        },
+    ];
+
+    /// Describes memory layout of `PosUvColor` for wgpu render pipelines: position, UV and color
+    /// go to shader locations 0, 1 and 2 respectively, one vertex per step.
+    #[inline]
+    #[must_use]
+    pub const fn vertex_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<PosUvColor>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::WGPU_ATTRIBUTES,
+        }
+    }
+}
+
 #[cfg(feature = "ggez")]
 impl From<PosUvColor> for ggez::graphics::Vertex {
     fn from(color_pos_uv: PosUvColor) -> Self {
@@ -41,6 +134,13 @@ impl From<PosUvColor> for ggez::graphics::Vertex {
     }
 }
 
+#[cfg(feature = "ggez")]
+impl From<ggez::graphics::Vertex> for PosUvColor {
+    fn from(vertex: ggez::graphics::Vertex) -> Self {
+        Self::new(vertex.pos, vertex.uv, vertex.color)
+    }
+}
+
 #[cfg(feature = "tetra")]
 impl From<PosUvColor> for tetra::graphics::mesh::Vertex {
     fn from(color_pos_uv: PosUvColor) -> Self {
@@ -56,3 +156,362 @@ impl From<PosUvColor> for tetra::graphics::mesh::Vertex {
         )
     }
 }
+
+#[cfg(feature = "tetra")]
+impl From<tetra::graphics::mesh::Vertex> for PosUvColor {
+    fn from(vertex: tetra::graphics::mesh::Vertex) -> Self {
+        Self::new(
+            [vertex.position.x, vertex.position.y],
+            [vertex.uv.x, vertex.uv.y],
+            [
+                vertex.color.r,
+                vertex.color.g,
+                vertex.color.b,
+                vertex.color.a,
+            ],
+        )
+    }
+}
+
+/// Converts a color given by its hue, saturation and value into a color accepted by mesh builder setters,
+/// e.g. to animate a tint by shifting its hue.
+///
+/// * `h` - Hue in degrees, wraps around, so 360 and -360 are the same red as 0.
+/// * `s` - Saturation from 0 to 1, 0 gives a shade of gray.
+/// * `v` - Value from 0 to 1, i.e. brightness of the resulting color.
+/// * `a` - Alpha, used as is.
+#[must_use]
+pub fn color_from_hsv(h: f32, s: f32, v: f32, a: f32) -> Color {
+    let s = s.clamp(0.0, 1.0);
+    let v = v.clamp(0.0, 1.0);
+    if s <= 0.0 {
+        return Color {
+            x: v,
+            y: v,
+            z: v,
+            w: a,
+        };
+    }
+
+    // Hue is split into six 60 degree sectors, each one fading a single RGB component in or out:
+    let sector = h.rem_euclid(360.0) / 60.0;
+    let chroma = v * s;
+    let rising = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, rising, 0.0),
+        1 => (rising, chroma, 0.0),
+        2 => (0.0, chroma, rising),
+        3 => (0.0, rising, chroma),
+        4 => (rising, 0.0, chroma),
+        _ => (chroma, 0.0, rising),
+    };
+    let min = v - chroma;
+    Color {
+        x: r + min,
+        y: g + min,
+        z: b + min,
+        w: a,
+    }
+}
+
+/// Composes a 2D affine transform usable with `MeshFromQuads::transform_all`, so transforms can be built
+/// without pulling in a math library. Points are scaled first, then rotated, then translated,
+/// the same order `DetailedParams` applies to its corners.
+///
+/// * `translation` - Offset applied last.
+/// * `rotation` - Rotation angle in radians, positive angles rotate from x axis towards y axis.
+/// * `scale` - Scale along x and y axes, applied first.
+#[must_use]
+pub fn affine2<TVec2: Into<Vec2>>(
+    translation: TVec2,
+    rotation: f32,
+    scale: TVec2,
+) -> mint::ColumnMatrix3<f32> {
+    let translation = translation.into();
+    let scale = scale.into();
+    let (sin, cos) = rotation.sin_cos();
+    mint::ColumnMatrix3 {
+        x: Vec3 {
+            x: cos * scale.x,
+            y: sin * scale.x,
+            z: 0.0,
+        },
+        y: Vec3 {
+            x: -sin * scale.y,
+            y: cos * scale.y,
+            z: 0.0,
+        },
+        z: Vec3 {
+            x: translation.x,
+            y: translation.y,
+            z: 1.0,
+        },
+    }
+}
+
+// Orphan rules forbid `From` impls between mint and ggez or Tetra types, since neither is local to this crate,
+// so conversions of colors and rectangles are free functions instead.
+
+/// Converts ggez color into a color accepted by mesh builder setters, mapping r, g, b and a to x, y, z and w.
+#[cfg(feature = "ggez")]
+#[inline]
+#[must_use]
+pub fn color_from_ggez(color: ggez::graphics::Color) -> Vec4 {
+    Vec4 {
+        x: color.r,
+        y: color.g,
+        z: color.b,
+        w: color.a,
+    }
+}
+
+/// Converts color used by mesh builders into ggez color, mapping x, y, z and w to r, g, b and a.
+#[cfg(feature = "ggez")]
+#[inline]
+#[must_use]
+pub fn color_into_ggez(color: Vec4) -> ggez::graphics::Color {
+    ggez::graphics::Color::new(color.x, color.y, color.z, color.w)
+}
+
+/// Converts ggez rect into a texture source rectangle accepted by mesh builder setters,
+/// mapping x, y, w and h to x, y, z and w.
+#[cfg(feature = "ggez")]
+#[inline]
+#[must_use]
+pub fn rectangle_from_ggez(rect: ggez::graphics::Rect) -> Vec4 {
+    Vec4 {
+        x: rect.x,
+        y: rect.y,
+        z: rect.w,
+        w: rect.h,
+    }
+}
+
+/// Converts texture source rectangle used by mesh builders into ggez rect,
+/// mapping x, y, z and w to x, y, w and h.
+#[cfg(feature = "ggez")]
+#[inline]
+#[must_use]
+pub fn rectangle_into_ggez(rectangle: Vec4) -> ggez::graphics::Rect {
+    ggez::graphics::Rect::new(rectangle.x, rectangle.y, rectangle.z, rectangle.w)
+}
+
+/// Converts Tetra color into a color accepted by mesh builder setters, mapping r, g, b and a to x, y, z and w.
+#[cfg(feature = "tetra")]
+#[inline]
+#[must_use]
+pub fn color_from_tetra(color: tetra::graphics::Color) -> Vec4 {
+    Vec4 {
+        x: color.r,
+        y: color.g,
+        z: color.b,
+        w: color.a,
+    }
+}
+
+/// Converts color used by mesh builders into Tetra color, mapping x, y, z and w to r, g, b and a.
+#[cfg(feature = "tetra")]
+#[inline]
+#[must_use]
+pub fn color_into_tetra(color: Vec4) -> tetra::graphics::Color {
+    tetra::graphics::Color::rgba(color.x, color.y, color.z, color.w)
+}
+
+/// Converts Tetra rectangle into a texture source rectangle accepted by mesh builder setters,
+/// mapping x, y, width and height to x, y, z and w.
+#[cfg(feature = "tetra")]
+#[inline]
+#[must_use]
+pub fn rectangle_from_tetra(rectangle: tetra::graphics::Rectangle<f32>) -> Vec4 {
+    Vec4 {
+        x: rectangle.x,
+        y: rectangle.y,
+        z: rectangle.width,
+        w: rectangle.height,
+    }
+}
+
+/// Converts texture source rectangle used by mesh builders into Tetra rectangle,
+/// mapping x, y, z and w to x, y, width and height.
+#[cfg(feature = "tetra")]
+#[inline]
+#[must_use]
+pub fn rectangle_into_tetra(rectangle: Vec4) -> tetra::graphics::Rectangle<f32> {
+    tetra::graphics::Rectangle::new(rectangle.x, rectangle.y, rectangle.z, rectangle.w)
+}
+
+#[cfg(feature = "macroquad")]
+impl From<PosUvColor> for macroquad::models::Vertex {
+    fn from(color_pos_uv: PosUvColor) -> Self {
+        Self {
+            position: macroquad::math::Vec3::new(
+                color_pos_uv.position.x,
+                color_pos_uv.position.y,
+                0.0,
+            ),
+            uv: macroquad::math::Vec2::new(color_pos_uv.uv.x, color_pos_uv.uv.y),
+            color: macroquad::color::Color::new(
+                color_pos_uv.color.x,
+                color_pos_uv.color.y,
+                color_pos_uv.color.z,
+                color_pos_uv.color.w,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "macroquad")]
+impl From<macroquad::models::Vertex> for PosUvColor {
+    fn from(vertex: macroquad::models::Vertex) -> Self {
+        Self::new(
+            [vertex.position.x, vertex.position.y],
+            [vertex.uv.x, vertex.uv.y],
+            [
+                vertex.color.r,
+                vertex.color.g,
+                vertex.color.b,
+                vertex.color.a,
+            ],
+        )
+    }
+}
+
+/// Same as `PosUvColor`, but with depth, e.g. for layered meshes drawn with depth testing.
+///
+/// ggez and Tetra vertices are 2D, so converting into them drops `z`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct PosUvColorZ {
+    pub position: Vec3,
+    pub uv: Vec2,
+    pub color: Color,
+}
+
+// SAFETY: Same as for `PosUvColor`, `PosUvColorZ` is `repr(C)` and consists of f32 mint vectors only.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for PosUvColorZ {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for PosUvColorZ {}
+
+impl PosUvColorZ {
+    #[inline]
+    pub fn new<TColor, TVec2, TVec3>(position: TVec3, uv: TVec2, color: TColor) -> Self
+    where
+        TColor: Into<Color>,
+        TVec2: Into<Vec2>,
+        TVec3: Into<Vec3>,
+    {
+        Self {
+            position: position.into(),
+            uv: uv.into(),
+            color: color.into(),
+        }
+    }
+}
+
+impl From<PosUvColor> for PosUvColorZ {
+    #[inline]
+    fn from(color_pos_uv: PosUvColor) -> Self {
+        Self::new(
+            [color_pos_uv.position.x, color_pos_uv.position.y, 0.0],
+            color_pos_uv.uv,
+            color_pos_uv.color,
+        )
+    }
+}
+
+#[cfg(feature = "ggez")]
+impl From<PosUvColorZ> for ggez::graphics::Vertex {
+    fn from(color_pos_uv: PosUvColorZ) -> Self {
+        Self {
+            pos: [color_pos_uv.position.x, color_pos_uv.position.y],
+            uv: color_pos_uv.uv.into(),
+            color: color_pos_uv.color.into(),
+        }
+    }
+}
+
+#[cfg(feature = "tetra")]
+impl From<PosUvColorZ> for tetra::graphics::mesh::Vertex {
+    fn from(color_pos_uv: PosUvColorZ) -> Self {
+        Self::new(
+            tetra::math::Vec2::new(color_pos_uv.position.x, color_pos_uv.position.y),
+            tetra::math::Vec2::new(color_pos_uv.uv.x, color_pos_uv.uv.y),
+            tetra::graphics::Color::rgba(
+                color_pos_uv.color.x,
+                color_pos_uv.color.y,
+                color_pos_uv.color.z,
+                color_pos_uv.color.w,
+            ),
+        )
+    }
+}
+
+#[cfg(feature = "macroquad")]
+impl From<PosUvColorZ> for macroquad::models::Vertex {
+    fn from(color_pos_uv: PosUvColorZ) -> Self {
+        Self {
+            position: macroquad::math::Vec3::new(
+                color_pos_uv.position.x,
+                color_pos_uv.position.y,
+                color_pos_uv.position.z,
+            ),
+            uv: macroquad::math::Vec2::new(color_pos_uv.uv.x, color_pos_uv.uv.y),
+            color: macroquad::color::Color::new(
+                color_pos_uv.color.x,
+                color_pos_uv.color.y,
+                color_pos_uv.color.z,
+                color_pos_uv.color.w,
+            ),
+        }
+    }
+}
+
+/// Same as `PosUvColor`, but with an index of the texture to sample, e.g. a layer of an array texture.
+///
+/// ggez, Tetra and macroquad vertices have nowhere to store the index, so there are no conversions into them:
+/// use it with custom vertex types and shaders.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct PosUvColorTex {
+    pub position: Vec2,
+    pub uv: Vec2,
+    pub color: Color,
+    pub tex_index: f32,
+}
+
+// SAFETY: Same as for `PosUvColor`, `PosUvColorTex` is `repr(C)` and consists of f32 values only.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for PosUvColorTex {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for PosUvColorTex {}
+
+impl PosUvColorTex {
+    #[inline]
+    pub fn new<TColor, TVec2>(position: TVec2, uv: TVec2, color: TColor, tex_index: f32) -> Self
+    where
+        TColor: Into<Color>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            uv: uv.into(),
+            color: color.into(),
+            tex_index,
+        }
+    }
+}
+
+impl From<PosUvColor> for PosUvColorTex {
+    #[inline]
+    fn from(color_pos_uv: PosUvColor) -> Self {
+        Self::new(
+            color_pos_uv.position,
+            color_pos_uv.uv,
+            color_pos_uv.color,
+            0.0,
+        )
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    common_types::{PosUvColor, Vec2},
+    draw_params::{AlphaMode, IndexTopology, UvInset, WindingOrder},
+    MeshFromQuads, Result,
+};
+
+/// Collects mesh builder options in one place, so a `MeshFromQuads` can be created without picking
+/// between its many constructors. Options not set explicitly keep the same defaults as in `MeshFromQuads::new`:
+/// indexed clockwise quads drawn as triangle lists without UV inset and with colors used as-is.
+///
+/// ```
+/// use stabilkon::*;
+///
+/// let builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new([256.0, 256.0], 64)
+///     .with_half_pixel_offset(true)
+///     .with_indices(false)
+///     .build()
+///     .unwrap();
+/// assert_eq!(builder.vertices_per_quad(), 6);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshFromQuadsConfig {
+    texture_size: Vec2,
+    uv_inset: UvInset,
+    quad_limit: u32,
+    use_indices: bool,
+    winding: WindingOrder,
+    index_topology: IndexTopology,
+    alpha_mode: AlphaMode,
+}
+
+impl MeshFromQuadsConfig {
+    /// Creates a new config for a mesh builder with default options.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `quad_limit` - Amount of quads in the built static mesh.
+    #[must_use]
+    pub fn new<T: Into<Vec2>>(texture_size: T, quad_limit: u32) -> Self {
+        MeshFromQuadsConfig {
+            texture_size: texture_size.into(),
+            uv_inset: UvInset::NONE,
+            quad_limit,
+            use_indices: true,
+            winding: WindingOrder::Clockwise,
+            index_topology: IndexTopology::TriangleList,
+            alpha_mode: AlphaMode::default(),
+        }
+    }
+
+    /// Sets whether half pixel correction is applied to UVs, see `MeshFromQuads::new`.
+    /// Replaces inset set with `with_uv_inset`.
+    #[inline]
+    #[must_use]
+    pub fn with_half_pixel_offset(self, use_half_pixel_offset: bool) -> Self {
+        self.with_uv_inset(use_half_pixel_offset)
+    }
+
+    /// Sets the inset applied to UV edges, see `UvInset`.
+    #[inline]
+    #[must_use]
+    pub fn with_uv_inset<T: Into<UvInset>>(mut self, uv_inset: T) -> Self {
+        self.uv_inset = uv_inset.into();
+        self
+    }
+
+    /// Sets amount of quads in the built static mesh.
+    #[inline]
+    #[must_use]
+    pub fn with_quad_limit(mut self, quad_limit: u32) -> Self {
+        self.quad_limit = quad_limit;
+        self
+    }
+
+    /// Sets whether the mesh is indexed: 4 vertices per quad if true, 6 vertices otherwise.
+    #[inline]
+    #[must_use]
+    pub fn with_indices(mut self, use_indices: bool) -> Self {
+        self.use_indices = use_indices;
+        self
+    }
+
+    /// Sets triangle winding of all quads, see `MeshFromQuads::new_with_winding`.
+    #[inline]
+    #[must_use]
+    pub fn with_winding(mut self, winding: WindingOrder) -> Self {
+        self.winding = winding;
+        self
+    }
+
+    /// Sets the topology of indices, see `MeshFromQuads::set_index_topology`. Ignored for meshes without indices.
+    #[inline]
+    #[must_use]
+    pub fn with_index_topology(mut self, index_topology: IndexTopology) -> Self {
+        self.index_topology = index_topology;
+        self
+    }
+
+    /// Sets the conversion applied to colors of the quads being set, see `MeshFromQuads::set_alpha_mode`.
+    #[inline]
+    #[must_use]
+    pub fn with_alpha_mode(mut self, alpha_mode: AlphaMode) -> Self {
+        self.alpha_mode = alpha_mode;
+        self
+    }
+
+    /// Creates a mesh builder with the configured options.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or `quad_limit` is too high.
+    pub fn build<TVertex>(&self) -> Result<MeshFromQuads<TVertex>>
+    where
+        TVertex: Clone + From<PosUvColor>,
+    {
+        let mut builder = MeshFromQuads::new_with_winding(
+            self.texture_size,
+            false,
+            self.quad_limit,
+            self.use_indices,
+            self.winding,
+        )?;
+        builder.set_index_topology(self.index_topology)?;
+        builder.set_uv_inset(self.uv_inset);
+        builder.set_alpha_mode(self.alpha_mode);
+        Ok(builder)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    common_types::{Color, PosUvColor, Rectangle, Vec2, VEC2_ZERO},
+    common_types::{Color, PosUvColor, PosUvColorTex, PosUvColorZ, Rectangle, Vec2, VEC2_ZERO},
     vertices_per_quad,
 };
 
@@ -7,6 +7,7 @@ use crate::{
 ///
 /// Can be used to change how `source` parameter is treated when adding quads to a builder.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UvFlip {
     /// Results in usual left-to-right, bottom-to-top (↑→).
     None,
@@ -16,6 +17,405 @@ pub enum UvFlip {
     Vertical,
     /// Results in right-to-left, top-to-bottom (←↓).
     Both,
+    /// Rotates the texture by 90 degrees to the left of the ↑→ system.
+    /// Swaps U and V axes, see `UvFlip::arrange_corner_uvs`.
+    DiagonalLeft,
+    /// Rotates the texture by 90 degrees to the right of the ↑→ system.
+    DiagonalRight,
+}
+
+impl UvFlip {
+    /// Checks if this flip mode swaps U and V axes, i.e. rotates or mirrors the texture along a diagonal.
+    #[inline]
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        matches!(self, UvFlip::DiagonalLeft | UvFlip::DiagonalRight)
+    }
+
+    /// Rearranges UVs of quad corners for diagonal flip modes, other modes return UVs as is.
+    ///
+    /// Diagonal modes start from `UvFlip::None` UVs and move them between corners, so they are defined
+    /// for the ↑→ system, where corners go clockwise starting from the bottom-left one.
+    /// In the top-to-bottom system used along with `UvFlip::Vertical` the texture is mirrored vertically,
+    /// so there `DiagonalLeft` and `DiagonalRight` mirror the texture along a diagonal instead of rotating it.
+    ///
+    /// * `uvs` - UVs of quad corners in `QuadDrawParams::corner_points` order.
+    #[inline]
+    #[must_use]
+    pub fn arrange_corner_uvs(self, uvs: [Vec2; 4]) -> [Vec2; 4] {
+        let [c1, c2, c3, c4] = uvs;
+        match self {
+            UvFlip::DiagonalLeft => [c2, c3, c4, c1],
+            UvFlip::DiagonalRight => [c4, c1, c2, c3],
+            UvFlip::None | UvFlip::Horizontal | UvFlip::Vertical | UvFlip::Both => uvs,
+        }
+    }
+}
+
+/// Determines the order in which quad triangle vertices are emitted.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindingOrder {
+    /// Corners go in the usual `corner_points` order.
+    #[default]
+    Clockwise,
+    /// Corners go in the reversed order, which flips the facing of the quad triangles.
+    CounterClockwise,
+}
+
+impl WindingOrder {
+    /// Gets index offsets of the two quad triangles relative to the first quad vertex.
+    #[inline]
+    #[must_use]
+    pub const fn quad_index_pattern(self) -> [u32; 6] {
+        match self {
+            WindingOrder::Clockwise => [0, 1, 2, 2, 3, 0],
+            WindingOrder::CounterClockwise => [0, 3, 2, 2, 1, 0],
+        }
+    }
+
+    /// Gets index offsets of the quad triangle strip relative to the first quad vertex.
+    /// Strip consists of the same triangles as `quad_index_pattern`, with the same facing.
+    #[inline]
+    #[must_use]
+    pub const fn quad_strip_pattern(self) -> [u32; 4] {
+        match self {
+            WindingOrder::Clockwise => [1, 2, 0, 3],
+            WindingOrder::CounterClockwise => [3, 2, 0, 1],
+        }
+    }
+}
+
+/// Coordinate axis, e.g. for mirroring a mesh with `MeshFromQuads::mirror`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    /// Horizontal axis, mirroring along it flips x coordinates across a vertical line.
+    X,
+    /// Vertical axis, mirroring along it flips y coordinates across a horizontal line.
+    Y,
+}
+
+/// Index value which ends the current triangle strip, see `IndexTopology::TriangleStrip`.
+pub const STRIP_RESTART_INDEX: u32 = u32::MAX;
+
+/// Determines how indices of an indexed mesh form quad triangles.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndexTopology {
+    /// Every quad is drawn as two separate triangles, 6 indices per quad. Supported by all backends.
+    #[default]
+    TriangleList,
+    /// Every quad is drawn as a triangle strip of 4 indices followed by `STRIP_RESTART_INDEX`, 5 indices per quad.
+    /// Requires primitive restart with u32 indices, e.g. `strip_index_format` of wgpu pipelines.
+    /// Tetra, ggez and macroquad draw triangle lists only, so their meshes can't be created from strips.
+    TriangleStrip,
+}
+
+impl IndexTopology {
+    /// Gets the amount of indices used per single quad.
+    #[inline]
+    #[must_use]
+    pub const fn indices_per_quad(self) -> u32 {
+        match self {
+            IndexTopology::TriangleList => 6,
+            IndexTopology::TriangleStrip => 5,
+        }
+    }
+
+    /// Appends indices of a single quad starting at `first_vertex` to the given index vec.
+    #[inline]
+    pub(crate) fn extend_quad_indices(
+        self,
+        winding: WindingOrder,
+        first_vertex: u32,
+        indices: &mut Vec<u32>,
+    ) {
+        match self {
+            IndexTopology::TriangleList => indices.extend(
+                winding
+                    .quad_index_pattern()
+                    .iter()
+                    .map(|offset| first_vertex + offset),
+            ),
+            IndexTopology::TriangleStrip => {
+                indices.extend(
+                    winding
+                        .quad_strip_pattern()
+                        .iter()
+                        .map(|offset| first_vertex + offset),
+                );
+                indices.push(STRIP_RESTART_INDEX);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "wgpu")]
+impl IndexTopology {
+    /// Gets wgpu primitive topology drawing indices of this topology.
+    /// Triangle strips also need `strip_index_format` set to `wgpu::IndexFormat::Uint32`.
+    #[inline]
+    #[must_use]
+    pub const fn wgpu_primitive_topology(self) -> wgpu::PrimitiveTopology {
+        match self {
+            IndexTopology::TriangleList => wgpu::PrimitiveTopology::TriangleList,
+            IndexTopology::TriangleStrip => wgpu::PrimitiveTopology::TriangleStrip,
+        }
+    }
+}
+
+/// Point of a quad which is placed at the quad position, see `PosColorAnchorSource`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anchor {
+    #[default]
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Gets anchor point location as a fraction of quad size, measured from its top-left corner:
+    /// (0, 0) is the top-left corner and (1, 1) is the bottom-right one.
+    #[inline]
+    #[must_use]
+    pub const fn fraction(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::TopCenter => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::CenterLeft => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::CenterRight => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::BottomCenter => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+/// Determines units of texture source rectangles.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SourceSpace {
+    /// Source rectangles are in texture pixels and are divided by texture size to get UVs.
+    #[default]
+    Pixels,
+    /// Source rectangles are already normalized to 0..1 and are used as UVs directly,
+    /// e.g. when they come from a texture packer. UV inset is not applied to them.
+    Normalized,
+}
+
+/// Offsets each UV edge of a texture source rectangle inwards, in texel units.
+/// Positive values shrink the sampled area, e.g. to hide bleeding from neighbouring atlas images.
+///
+/// `true` converts into a symmetric inset of half a texel, which is the usual half pixel correction;
+/// `false` converts into no inset at all.
+///
+/// Edges refer to the source rectangle in the texture rather than to quad sides, and insets are applied
+/// before any flip. So for rotated atlas images drawn with diagonal flip modes, which swap U and V axes on screen,
+/// left and right insets still offset U, while top and bottom ones still offset V, following the texture texels.
+///
+/// Insets never eat more than half of the source rectangle: when insets of the opposite edges add up to more than
+/// half of the source extent, both are shrunk proportionally to fit. So tiny sources still get a sampling window
+/// centered on their texels instead of a degenerate zero-sized one, e.g. a 1×1 source with half pixel correction
+/// samples the middle half of its texel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UvInset {
+    /// Inset of the left source edge.
+    pub left: f32,
+    /// Inset of the top source edge.
+    pub top: f32,
+    /// Inset of the right source edge.
+    pub right: f32,
+    /// Inset of the bottom source edge.
+    pub bottom: f32,
+}
+
+impl UvInset {
+    /// No inset, UVs match source rectangle edges exactly.
+    pub const NONE: UvInset = UvInset::uniform(0.0);
+
+    /// Half a texel inset on every edge, same as half pixel correction.
+    pub const HALF_PIXEL: UvInset = UvInset::uniform(0.5);
+
+    /// Creates a new inset with the given edge offsets.
+    #[inline]
+    #[must_use]
+    pub const fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        UvInset {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Creates a new inset with the same offset for every edge.
+    #[inline]
+    #[must_use]
+    pub const fn uniform(inset: f32) -> Self {
+        UvInset::new(inset, inset, inset, inset)
+    }
+
+    /// Gets the inset actually applied to a source rectangle of the given size, see type docs.
+    #[must_use]
+    pub(crate) fn fit_to(self, width: f32, height: f32) -> Self {
+        let (left, right) = fit_edge_insets(self.left, self.right, width);
+        let (top, bottom) = fit_edge_insets(self.top, self.bottom, height);
+        UvInset::new(left, top, right, bottom)
+    }
+}
+
+/// Shrinks insets of the opposite edges proportionally if they add up to more than half of the given extent.
+#[inline]
+fn fit_edge_insets(start: f32, end: f32, extent: f32) -> (f32, f32) {
+    let total = start + end;
+    let max_total = extent.abs() * 0.5;
+    if total > max_total {
+        let scale = max_total / total;
+        (start * scale, end * scale)
+    } else {
+        (start, end)
+    }
+}
+
+/// Restores start and extent of a source rectangle side from its UV span in texels, undoing `UvInset::fit_to`.
+#[must_use]
+pub(crate) fn source_side_from_uv_span(
+    uv_start: f32,
+    uv_end: f32,
+    start_inset: f32,
+    end_inset: f32,
+) -> (f32, f32) {
+    let span = uv_end - uv_start;
+    let total = start_inset + end_inset;
+    if span >= total {
+        (uv_start - start_inset, span + total)
+    } else {
+        // Insets were shrunk to take exactly half of the extent:
+        (uv_start - start_inset * span / total, span * 2.0)
+    }
+}
+
+impl From<bool> for UvInset {
+    #[inline]
+    fn from(use_half_pixel_offset: bool) -> Self {
+        if use_half_pixel_offset {
+            UvInset::HALF_PIXEL
+        } else {
+            UvInset::NONE
+        }
+    }
+}
+
+/// Determines how vertex colors are converted when quad vertices are calculated.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlphaMode {
+    /// Colors are used as-is.
+    #[default]
+    AsIs,
+    /// Colors are treated as premultiplied by alpha and are converted to straight alpha:
+    /// color RGB components are divided by its alpha. Fully transparent colors are left as-is.
+    UnpremultiplyColor,
+    /// Colors are treated as straight alpha and are converted to premultiplied alpha:
+    /// color RGB components are multiplied by its alpha. Use it for renderers blending premultiplied colors,
+    /// otherwise blended sprites get dark fringes.
+    PremultiplyColor,
+}
+
+impl AlphaMode {
+    /// Converts the given color according to this mode.
+    #[must_use]
+    #[inline]
+    pub fn convert<TColor: Into<Color>>(self, color: TColor) -> Color {
+        let color: Color = color.into();
+        match self {
+            AlphaMode::AsIs => color,
+            AlphaMode::UnpremultiplyColor => {
+                if color.w > 0.0 {
+                    Color {
+                        x: color.x / color.w,
+                        y: color.y / color.w,
+                        z: color.z / color.w,
+                        w: color.w,
+                    }
+                } else {
+                    color
+                }
+            }
+            AlphaMode::PremultiplyColor => Color {
+                x: color.x * color.w,
+                y: color.y * color.w,
+                z: color.z * color.w,
+                w: color.w,
+            },
+        }
+    }
+}
+
+/// Options applied to every quad when its vertices are calculated, see `QuadDrawParams::set_vertices`.
+/// Mesh builders keep them for all of their quads, see `MeshFromQuads::vertex_options`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VertexOptions {
+    /// Offsets UV edges inwards, see `UvInset`. Half pixel inset applies [half pixel correction]
+    /// (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// It is better to use padded texture atlas with this fix,
+    /// otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
+    /// but keep it in mind.
+    /// No inset expects end users to deal with texture bleeding themselves,
+    /// e.g. with correct texture sampling or shifting viewport by half a pixel.
+    pub uv_inset: UvInset,
+    /// If set to true, quad will consist of 4 vertices; otherwise, 6 vertices will be used.
+    pub use_indices: bool,
+    /// Determines how vertex color is converted.
+    pub alpha_mode: AlphaMode,
+    /// Triangle winding of the mesh. For indexed meshes it should match the index pattern,
+    /// see `WindingOrder::quad_index_pattern`. Quad's own `winding` overrides it.
+    pub winding: WindingOrder,
+}
+
+impl VertexOptions {
+    /// Creates options with the given UV inset and indexing, colors used as-is and clockwise winding.
+    ///
+    /// * `uv_inset` - Offsets UV edges inwards, true is a shorthand for half pixel correction.
+    /// * `use_indices` - If set to true, quad will consist of 4 vertices; otherwise, 6 vertices will be used.
+    #[inline]
+    #[must_use]
+    pub fn new<TInset: Into<UvInset>>(uv_inset: TInset, use_indices: bool) -> Self {
+        Self {
+            uv_inset: uv_inset.into(),
+            use_indices,
+            alpha_mode: AlphaMode::AsIs,
+            winding: WindingOrder::Clockwise,
+        }
+    }
+
+    /// Sets how vertex colors are converted.
+    #[inline]
+    #[must_use]
+    pub fn with_alpha_mode(mut self, alpha_mode: AlphaMode) -> Self {
+        self.alpha_mode = alpha_mode;
+        self
+    }
+
+    /// Sets triangle winding of the mesh.
+    #[inline]
+    #[must_use]
+    pub fn with_winding(mut self, winding: WindingOrder) -> Self {
+        self.winding = winding;
+        self
+    }
 }
 
 /// Used to represent a single quad for a static sprites mesh.
@@ -33,37 +433,62 @@ pub trait QuadDrawParams {
         c4: &mut Vec2,
     );
 
-    /// Calculates top-left and bottom-right UVs.
+    /// Gets vertex colors for each corner, in the same order as `corner_points`.
+    /// By default all corners use `get_color`.
+    #[inline]
+    fn corner_colors(&self) -> [Color; 4] {
+        let color = self.get_color();
+        [color, color, color, color]
+    }
+
+    /// Gets the triangle winding for this quad, `None` means the builder default is used.
+    ///
+    /// When it differs from the builder winding, it is applied by swapping the second and the fourth quad vertices,
+    /// so it works for both indexed and unindexed meshes without changing their indices.
+    /// Keep in mind that vertex order of such quads no longer matches `corner_points` order.
+    #[inline]
+    fn winding(&self) -> Option<WindingOrder> {
+        None
+    }
+
+    /// Calculates top-left and bottom-right UVs, with edges offset by the given inset.
     fn uvs(
         &self,
         texture_size: Vec2,
-        use_half_pixel_offset: bool,
+        uv_inset: UvInset,
         top_left: &mut Vec2,
         bottom_right: &mut Vec2,
     );
 
-    /// Calculates vertices and sets them in the given vertex buffer starting at the specified offset.
+    /// Gets UV flip mode of this quad. `uvs` is expected to handle mirroring flip modes itself,
+    /// while diagonal ones are applied to corner UVs afterwards, see `UvFlip::arrange_corner_uvs`.
+    /// By default no diagonal flip is applied.
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        UvFlip::None
+    }
+
+    /// Gets a copy of these params which can be retained by a mesh builder, see `MeshFromQuads::set_retain_params`.
+    /// `None` means these params cannot be retained; only built-in params are retained.
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        None
+    }
+
+    /// Calculates quad vertices in the order they are placed into a vertex buffer: `corner_points` order,
+    /// with the second and the fourth vertices swapped if the quad winding requires it, see `winding`.
+    /// Both `set_vertices` and `write_vertices` place these vertices, so override this method to customize
+    /// vertices of a quad regardless of the mesh vertex layout.
     ///
     /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
-    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
-    /// (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
-    /// It is better to use padded texture atlas with this fix,
-    /// otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
-    /// but keep it in mind.
-    /// If set to false, expects end users to deal with texture bleeding themselves,
-    /// e.g. with correct texture sampling or shifting viewport by half a pixel.
-    /// * `use_indices` - If set to true, quad will consist of 4 vertices; otherwise, 6 vertices will be used.
-    /// * `vertex_offset` - Index at which quad vertices will be set in `vertices` buffer.
-    /// * `vertices` - Vertices buffer, must be pre-allocated.
-    fn set_vertices<TVertex>(
+    /// * `options` - UV inset, indexing, color conversion and winding of the quad vertices, see `VertexOptions`.
+    fn corner_vertices<TVertex>(
         &self,
         texture_size: Vec2,
-        use_half_pixel_offset: bool,
-        use_indices: bool,
-        vertex_offset: usize,
-        vertices: &mut Vec<TVertex>,
-    ) where
-        TVertex: Clone + From<PosUvColor>,
+        options: VertexOptions,
+    ) -> (TVertex, TVertex, TVertex, TVertex)
+    where
+        TVertex: From<PosUvColor>,
     {
         let mut c1_position = VEC2_ZERO;
         let mut c2_position = VEC2_ZERO;
@@ -77,16 +502,12 @@ pub trait QuadDrawParams {
             &mut c4_position,
         );
         let mut c1_uv = VEC2_ZERO;
-        let mut c2_uv = VEC2_ZERO;
         let mut c3_uv = VEC2_ZERO;
-        let mut c4_uv = VEC2_ZERO;
-        self.uvs(texture_size, use_half_pixel_offset, &mut c1_uv, &mut c3_uv);
-        c2_uv.x = c1_uv.x;
-        c2_uv.y = c3_uv.y;
-        c4_uv.x = c3_uv.x;
-        c4_uv.y = c1_uv.y;
+        self.uvs(texture_size, options.uv_inset, &mut c1_uv, &mut c3_uv);
+        let [c1_uv, c2_uv, c3_uv, c4_uv] = corner_uvs(c1_uv, c3_uv, self.flip());
         let (c1, c2, c3, c4) = make_vertices(
-            self.get_color(),
+            self.corner_colors(),
+            options.alpha_mode,
             c1_position,
             c2_position,
             c3_position,
@@ -97,55 +518,113 @@ pub trait QuadDrawParams {
             c4_uv,
         );
 
-        if use_indices {
-            vertices[vertex_offset] = c1;
-            vertices[vertex_offset + 1] = c2;
-            vertices[vertex_offset + 2] = c3;
-            vertices[vertex_offset + 3] = c4;
+        // Indexed meshes get their winding from the index pattern, so only a differing quad winding
+        // needs its vertices swapped; unindexed meshes rely on the vertex order alone:
+        let quad_winding = self.winding().unwrap_or(options.winding);
+        let swap_sides = if options.use_indices {
+            quad_winding != options.winding
+        } else {
+            quad_winding == WindingOrder::CounterClockwise
+        };
+        let (c2, c4) = if swap_sides { (c4, c2) } else { (c2, c4) };
+        (c1, c2, c3, c4)
+    }
+
+    /// Calculates vertices and sets them in the given vertex buffer starting at the specified offset.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `options` - UV inset, indexing, color conversion and winding of the quad vertices, see `VertexOptions`.
+    /// * `vertex_offset` - Index at which quad vertices will be set in `vertices` buffer.
+    /// * `vertices` - Vertices buffer, must be pre-allocated.
+    fn set_vertices<TVertex>(
+        &self,
+        texture_size: Vec2,
+        options: VertexOptions,
+        vertex_offset: usize,
+        vertices: &mut [TVertex],
+    ) where
+        TVertex: Clone + From<PosUvColor>,
+    {
+        if options.use_indices {
+            self.write_vertices::<TVertex, true>(texture_size, options, vertex_offset, vertices);
         } else {
-            vertices[vertex_offset] = c1.clone();
-            vertices[vertex_offset + 1] = c2;
-            vertices[vertex_offset + 2] = c3.clone();
-            vertices[vertex_offset + 3] = c3;
-            vertices[vertex_offset + 4] = c4;
-            vertices[vertex_offset + 5] = c1;
+            self.write_vertices::<TVertex, false>(texture_size, options, vertex_offset, vertices);
         }
     }
 
-    /// Calculates and returns ordered vertices.
+    /// Same as `set_vertices`, but whether the quad is indexed is resolved at compile time.
+    /// Prefer it in hot loops where indexing is known upfront: quad consists of 4 vertices if `INDEXED`
+    /// is true, or 6 vertices otherwise.
     ///
-    /// * `texture_size` - Texture dimensions.
-    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
-    /// (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
-    /// It is better to use padded texture atlas with this fix,
-    /// otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
-    /// but keep it in mind.
-    /// If set to false, expects end users to deal with texture bleeding themselves,
-    /// e.g. with correct texture sampling or shifting viewport by half a pixel.
-    /// * `use_indices` - If set to true, quad will consist of 4 vertices; otherwise, 6 vertices will be used.
-    fn to_vertices<TVertex>(
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `options` - Vertex options, see `set_vertices`. Their `use_indices` is ignored in favor of `INDEXED`.
+    /// * `vertex_offset` - Index at which quad vertices will be set in `vertices` buffer.
+    /// * `vertices` - Vertices buffer, must be pre-allocated.
+    fn write_vertices<TVertex, const INDEXED: bool>(
         &self,
         texture_size: Vec2,
-        use_half_pixel_offset: bool,
-        use_indices: bool,
-    ) -> Vec<TVertex>
-    where
+        options: VertexOptions,
+        vertex_offset: usize,
+        vertices: &mut [TVertex],
+    ) where
         TVertex: Clone + From<PosUvColor>,
     {
-        let mut vertices = Vec::with_capacity(vertices_per_quad(use_indices) as usize);
-        self.set_vertices(
-            texture_size,
-            use_half_pixel_offset,
-            use_indices,
-            0,
-            &mut vertices,
+        let options = VertexOptions {
+            use_indices: INDEXED,
+            ..options
+        };
+        place_quad_vertices::<TVertex, INDEXED>(
+            self.corner_vertices(texture_size, options),
+            vertex_offset,
+            vertices,
         );
+    }
+
+    /// Calculates and returns ordered vertices, see `to_vertices_into`.
+    ///
+    /// * `texture_size` - Texture dimensions.
+    /// * `options` - UV inset, indexing, color conversion and winding of the quad vertices, see `VertexOptions`.
+    fn to_vertices<TVertex>(&self, texture_size: Vec2, options: VertexOptions) -> Vec<TVertex>
+    where
+        TVertex: Clone + From<PosUvColor>,
+    {
+        let mut vertices = Vec::with_capacity(vertices_per_quad(options.use_indices) as usize);
+        self.to_vertices_into(texture_size, options, &mut vertices);
         vertices
     }
+
+    /// Same as `to_vertices`, but replaces contents of the given buffer with ordered vertices
+    /// instead of allocating a new one, e.g. to reuse a scratch buffer when generating many standalone quads.
+    /// Half pixel offset and indexing are passed as `options`, use `VertexOptions::new` to build them from bools.
+    ///
+    /// * `texture_size` - Texture dimensions.
+    /// * `options` - UV inset, indexing, color conversion and winding of the quad vertices, see `VertexOptions`.
+    /// * `out` - Buffer to be cleared and filled with quad vertices.
+    fn to_vertices_into<TVertex>(
+        &self,
+        texture_size: Vec2,
+        options: VertexOptions,
+        out: &mut Vec<TVertex>,
+    ) where
+        TVertex: Clone + From<PosUvColor>,
+    {
+        out.clear();
+        out.resize(
+            vertices_per_quad(options.use_indices) as usize,
+            TVertex::from(PosUvColor::new(VEC2_ZERO, VEC2_ZERO, self.get_color())),
+        );
+        self.set_vertices(texture_size, options, 0, out);
+    }
 }
 
 /// Represents a standard, run-of-the-mill quad.
+///
+/// Quad size is taken from the source rectangle, where zero width or height means the whole texture dimension,
+/// i.e. a zero-dimension source draws the entire atlas along that axis, both in geometry and UVs.
+/// Negative width or height mirrors the region along that axis, like in many sprite tools:
+/// source of `[x + w, y, -w, h]` draws the same as `[x, y, w, h]` with `UvFlip::Horizontal`.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PosColorSource {
     /// Quad position, top-left corner.
     pub position: Vec2,
@@ -155,6 +634,8 @@ pub struct PosColorSource {
     pub source: Rectangle,
     /// UV flip mode.
     pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
 }
 
 impl PosColorSource {
@@ -176,8 +657,17 @@ impl PosColorSource {
             color: color.into(),
             source: source.into(),
             flip,
+            source_space: SourceSpace::Pixels,
         }
     }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
 }
 
 impl QuadDrawParams for PosColorSource {
@@ -193,58 +683,50 @@ impl QuadDrawParams for PosColorSource {
         c3: &mut Vec2,
         c4: &mut Vec2,
     ) {
-        let source_width = self.source.z;
-        let source_or_texture_width = if source_width > 0.0 {
-            source_width
-        } else {
-            texture_size.x
-        };
-
-        let source_height = self.source.w;
-        let source_or_texture_height = if source_height > 0.0 {
-            source_height
-        } else {
-            texture_size.y
-        };
-
-        let f2 = Vec2 {
-            x: self.position.x + source_or_texture_width,
-            y: self.position.y + source_or_texture_height,
-        };
-        c1.x = self.position.x;
-        c1.y = self.position.y;
-
-        c2.x = self.position.x;
-        c2.y = f2.y;
-
-        c3.x = f2.x;
-        c3.y = f2.y;
-
-        c4.x = f2.x;
-        c4.y = self.position.y;
+        calculate_corners_with_source(
+            texture_size,
+            self.position,
+            &self.source,
+            self.source_space,
+            c1,
+            c2,
+            c3,
+            c4,
+        );
     }
 
     #[inline]
-    fn uvs(&self, texture_size: Vec2, use_half_pixel_offset: bool, uv: &mut Vec2, uv2: &mut Vec2) {
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
         calculate_uvs_with_source(
             texture_size,
-            use_half_pixel_offset,
+            uv_inset,
             &self.source,
+            self.source_space,
             self.flip,
             uv,
             uv2,
         );
     }
 
-    fn set_vertices<TVertex>(
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::PosColorSource(self.clone()))
+    }
+
+    /// Same as the default `QuadDrawParams::corner_vertices`, minus the parts plain quads never need:
+    /// a single color is converted once and there is no quad winding of their own.
+    fn corner_vertices<TVertex>(
         &self,
         texture_size: Vec2,
-        use_half_pixel_offset: bool,
-        use_indices: bool,
-        vertex_offset: usize,
-        vertices: &mut Vec<TVertex>,
-    ) where
-        TVertex: Clone + From<PosUvColor>,
+        options: VertexOptions,
+    ) -> (TVertex, TVertex, TVertex, TVertex)
+    where
+        TVertex: From<PosUvColor>,
     {
         let mut c1_position = VEC2_ZERO;
         let mut c2_position = VEC2_ZERO;
@@ -258,85 +740,820 @@ impl QuadDrawParams for PosColorSource {
             &mut c4_position,
         );
         let mut c1_uv = VEC2_ZERO;
-        let mut c2_uv = VEC2_ZERO;
         let mut c3_uv = VEC2_ZERO;
-        let mut c4_uv = VEC2_ZERO;
-        self.uvs(texture_size, use_half_pixel_offset, &mut c1_uv, &mut c3_uv);
-        c2_uv.x = c1_uv.x;
-        c2_uv.y = c3_uv.y;
-        c4_uv.x = c3_uv.x;
-        c4_uv.y = c1_uv.y;
+        self.uvs(texture_size, options.uv_inset, &mut c1_uv, &mut c3_uv);
+        let [c1_uv, c2_uv, c3_uv, c4_uv] = corner_uvs(c1_uv, c3_uv, self.flip);
 
-        let (c1, c2, c3, c4) = make_vertices(
-            self.get_color(),
-            c1_position,
-            c2_position,
-            c3_position,
-            c4_position,
-            c1_uv,
-            c2_uv,
-            c3_uv,
-            c4_uv,
+        let color = options.alpha_mode.convert(self.color);
+        let c1 = TVertex::from(PosUvColor::new(c1_position, c1_uv, color));
+        let c2 = TVertex::from(PosUvColor::new(c2_position, c2_uv, color));
+        let c3 = TVertex::from(PosUvColor::new(c3_position, c3_uv, color));
+        let c4 = TVertex::from(PosUvColor::new(c4_position, c4_uv, color));
+        // Indexed meshes get their winding from the index pattern, unindexed ones rely on the vertex order:
+        if !options.use_indices && options.winding == WindingOrder::CounterClockwise {
+            (c1, c4, c3, c2)
+        } else {
+            (c1, c2, c3, c4)
+        }
+    }
+
+    fn to_vertices_into<TVertex>(
+        &self,
+        texture_size: Vec2,
+        options: VertexOptions,
+        out: &mut Vec<TVertex>,
+    ) where
+        TVertex: Clone + From<PosUvColor>,
+    {
+        let (c1, c2, c3, c4) = self.corner_vertices(texture_size, options);
+        out.clear();
+        if options.use_indices {
+            out.extend([c1, c2, c3, c4]);
+        } else {
+            out.extend([c1.clone(), c2, c3.clone(), c3, c4, c1]);
+        }
+    }
+}
+
+/// Represents a standard quad placed at the given depth, e.g. for layered meshes drawn with depth testing.
+///
+/// Since depth does not fit into `PosUvColor`, these params emit `PosUvColorZ` vertices through
+/// `set_vertices_z` and `to_vertices_z` instead of implementing `QuadDrawParams`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosColorSourceZ {
+    /// Quad position, top-left corner.
+    pub position: Vec2,
+    /// Depth applied to all quad vertices.
+    pub z: f32,
+    /// Quad vertices color.
+    pub color: Color,
+    /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// UV flip mode.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosColorSourceZ {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        z: f32,
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            z,
+            color: color.into(),
+            source: source.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Calculates vertices and sets them in the given vertex buffer starting at the specified offset.
+    /// Works just like `QuadDrawParams::set_vertices`, but every vertex gets `z` depth.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `options` - Vertex options, see `QuadDrawParams::set_vertices`.
+    /// * `vertex_offset` - Index at which quad vertices will be set in `vertices` buffer.
+    /// * `vertices` - Vertices buffer, must be pre-allocated.
+    pub fn set_vertices_z<TVertex>(
+        &self,
+        texture_size: Vec2,
+        options: VertexOptions,
+        vertex_offset: usize,
+        vertices: &mut [TVertex],
+    ) where
+        TVertex: From<PosUvColorZ>,
+    {
+        let flat = PosColorSource::new(self.position, self.color, self.source, self.flip)
+            .with_source_space(self.source_space);
+        let mut flat_vertices =
+            [PosUvColorZ::from(PosUvColor::new(VEC2_ZERO, VEC2_ZERO, self.color)); 6];
+        flat.set_vertices(texture_size, options, 0, &mut flat_vertices);
+        let vertex_count = vertices_per_quad(options.use_indices) as usize;
+        for (i, mut vertex) in flat_vertices.into_iter().take(vertex_count).enumerate() {
+            vertex.position.z = self.z;
+            vertices[vertex_offset + i] = TVertex::from(vertex);
+        }
+    }
+
+    /// Calculates and returns ordered vertices, see `set_vertices_z`.
+    ///
+    /// * `texture_size` - Texture dimensions.
+    /// * `options` - Vertex options, see `QuadDrawParams::set_vertices`.
+    #[must_use]
+    pub fn to_vertices_z<TVertex>(&self, texture_size: Vec2, options: VertexOptions) -> Vec<TVertex>
+    where
+        TVertex: From<PosUvColorZ>,
+    {
+        let mut vertices: Vec<PosUvColorZ> =
+            vec![
+                PosUvColorZ::from(PosUvColor::new(VEC2_ZERO, VEC2_ZERO, self.color));
+                vertices_per_quad(options.use_indices) as usize
+            ];
+        self.set_vertices_z(texture_size, options, 0, &mut vertices);
+        vertices.into_iter().map(TVertex::from).collect()
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+/// Represents a standard quad sampling the texture with the given index, e.g. a layer of an array texture.
+///
+/// Since texture index does not fit into `PosUvColor`, these params emit `PosUvColorTex` vertices through
+/// `set_vertices_tex` and `to_vertices_tex` instead of implementing `QuadDrawParams`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosColorTexSource {
+    /// Quad position, top-left corner.
+    pub position: Vec2,
+    /// Texture index applied to all quad vertices.
+    pub tex_index: f32,
+    /// Quad vertices color.
+    pub color: Color,
+    /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// UV flip mode.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosColorTexSource {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        tex_index: f32,
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            tex_index,
+            color: color.into(),
+            source: source.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Calculates vertices and sets them in the given vertex buffer starting at the specified offset.
+    /// Works just like `QuadDrawParams::set_vertices`, but every vertex gets `tex_index` texture index.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `options` - Vertex options, see `QuadDrawParams::set_vertices`.
+    /// * `vertex_offset` - Index at which quad vertices will be set in `vertices` buffer.
+    /// * `vertices` - Vertices buffer, must be pre-allocated.
+    pub fn set_vertices_tex<TVertex>(
+        &self,
+        texture_size: Vec2,
+        options: VertexOptions,
+        vertex_offset: usize,
+        vertices: &mut [TVertex],
+    ) where
+        TVertex: From<PosUvColorTex>,
+    {
+        let flat = PosColorSource::new(self.position, self.color, self.source, self.flip)
+            .with_source_space(self.source_space);
+        let mut flat_vertices =
+            [PosUvColorTex::from(PosUvColor::new(VEC2_ZERO, VEC2_ZERO, self.color)); 6];
+        flat.set_vertices(texture_size, options, 0, &mut flat_vertices);
+        let vertex_count = vertices_per_quad(options.use_indices) as usize;
+        for (i, mut vertex) in flat_vertices.into_iter().take(vertex_count).enumerate() {
+            vertex.tex_index = self.tex_index;
+            vertices[vertex_offset + i] = TVertex::from(vertex);
+        }
+    }
+
+    /// Calculates and returns ordered vertices, see `set_vertices_tex`.
+    ///
+    /// * `texture_size` - Texture dimensions.
+    /// * `options` - Vertex options, see `QuadDrawParams::set_vertices`.
+    #[must_use]
+    pub fn to_vertices_tex<TVertex>(
+        &self,
+        texture_size: Vec2,
+        options: VertexOptions,
+    ) -> Vec<TVertex>
+    where
+        TVertex: From<PosUvColorTex>,
+    {
+        let mut vertices: Vec<PosUvColorTex> =
+            vec![
+                PosUvColorTex::from(PosUvColor::new(VEC2_ZERO, VEC2_ZERO, self.color));
+                vertices_per_quad(options.use_indices) as usize
+            ];
+        self.set_vertices_tex(texture_size, options, 0, &mut vertices);
+        vertices.into_iter().map(TVertex::from).collect()
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+/// Represents a standard quad with its own color for each corner, e.g. for gradients between tiles.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosGradientSource {
+    /// Quad position, top-left corner.
+    pub position: Vec2,
+    /// Vertex colors for each corner, starting from the top-left corner and going in the same order
+    /// as `QuadDrawParams::corner_points`.
+    pub colors: [Color; 4],
+    /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// UV flip mode.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosGradientSource {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        colors: [TColor; 4],
+        source: TRect,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let [c1, c2, c3, c4] = colors;
+        Self {
+            position: position.into(),
+            colors: [c1.into(), c2.into(), c3.into(), c4.into()],
+            source: source.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+impl QuadDrawParams for PosGradientSource {
+    #[inline]
+    fn get_color(&self) -> Color {
+        self.colors[0]
+    }
+
+    #[inline]
+    fn corner_colors(&self) -> [Color; 4] {
+        self.colors
+    }
+
+    fn corner_points(
+        &self,
+        texture_size: Vec2,
+        c1: &mut Vec2,
+        c2: &mut Vec2,
+        c3: &mut Vec2,
+        c4: &mut Vec2,
+    ) {
+        calculate_corners_with_source(
+            texture_size,
+            self.position,
+            &self.source,
+            self.source_space,
+            c1,
+            c2,
+            c3,
+            c4,
+        );
+    }
+
+    #[inline]
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
+        calculate_uvs_with_source(
+            texture_size,
+            uv_inset,
+            &self.source,
+            self.source_space,
+            self.flip,
+            uv,
+            uv2,
+        );
+    }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::PosGradientSource(self.clone()))
+    }
+}
+
+/// Represetns a standard quad with additional absolute scaling.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosColorSizeSource {
+    /// Quad position, top-left corner.
+    pub position: Vec2,
+    /// Quad vertices color.
+    pub color: Color,
+    /// Destination size, used for absolute scaling.
+    pub size: Vec2,
+    /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// UV flip mode.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosColorSizeSource {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        color: TColor,
+        size: TVec2,
+        source: TRect,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            color: color.into(),
+            size: size.into(),
+            source: source.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+impl QuadDrawParams for PosColorSizeSource {
+    #[inline]
+    fn get_color(&self) -> Color {
+        self.color
+    }
+
+    fn corner_points(
+        &self,
+        _texture_size: Vec2,
+        c1: &mut Vec2,
+        c2: &mut Vec2,
+        c3: &mut Vec2,
+        c4: &mut Vec2,
+    ) {
+        let f2 = Vec2 {
+            x: self.position.x + self.size.x,
+            y: self.position.y + self.size.y,
+        };
+        c1.x = self.position.x;
+        c1.y = self.position.y;
+
+        c2.x = self.position.x;
+        c2.y = f2.y;
+
+        c3.x = f2.x;
+        c3.y = f2.y;
+
+        c4.x = f2.x;
+        c4.y = self.position.y;
+    }
+
+    #[inline]
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
+        calculate_uvs_with_source(
+            texture_size,
+            uv_inset,
+            &self.source,
+            self.source_space,
+            self.flip,
+            uv,
+            uv2,
+        );
+    }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::PosColorSizeSource(self.clone()))
+    }
+}
+
+/// Represents a sized quad repeating its texture source rectangle `tile_count` times along each axis,
+/// e.g. for a long wall covered with a small brick texture.
+///
+/// UVs span past the source rectangle and rely on the sampler to wrap them, so the texture wrap mode
+/// must be set to repeat. Since wrapping applies to the entire texture, the source rectangle should cover
+/// the whole texture rather than a part of an atlas. Non-integer tile counts produce partial tiles at
+/// the bottom-right edges.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosColorTiledSource {
+    /// Quad position, top-left corner.
+    pub position: Vec2,
+    /// Quad vertices color.
+    pub color: Color,
+    /// Destination size, used for absolute scaling.
+    pub size: Vec2,
+    /// How many times the source rectangle is repeated horizontally and vertically.
+    pub tile_count: Vec2,
+    /// Texture source rectangle of a single tile. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// UV flip mode, applied to each tile.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosColorTiledSource {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        color: TColor,
+        size: TVec2,
+        tile_count: TVec2,
+        source: TRect,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            color: color.into(),
+            size: size.into(),
+            tile_count: tile_count.into(),
+            source: source.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+impl QuadDrawParams for PosColorTiledSource {
+    #[inline]
+    fn get_color(&self) -> Color {
+        self.color
+    }
+
+    fn corner_points(
+        &self,
+        _texture_size: Vec2,
+        c1: &mut Vec2,
+        c2: &mut Vec2,
+        c3: &mut Vec2,
+        c4: &mut Vec2,
+    ) {
+        let f2 = Vec2 {
+            x: self.position.x + self.size.x,
+            y: self.position.y + self.size.y,
+        };
+        c1.x = self.position.x;
+        c1.y = self.position.y;
+
+        c2.x = self.position.x;
+        c2.y = f2.y;
+
+        c3.x = f2.x;
+        c3.y = f2.y;
+
+        c4.x = f2.x;
+        c4.y = self.position.y;
+    }
+
+    #[inline]
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
+        calculate_uvs_with_source(
+            texture_size,
+            uv_inset,
+            &self.source,
+            self.source_space,
+            self.flip,
+            uv,
+            uv2,
+        );
+        // Top-left UV stays in place, while the bottom-right one is pushed further by the tile count:
+        uv2.x = uv.x + (uv2.x - uv.x) * self.tile_count.x;
+        uv2.y = uv.y + (uv2.y - uv.y) * self.tile_count.y;
+    }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::PosColorTiledSource(self.clone()))
+    }
+}
+
+/// Represents a sized quad sheared along its axes, e.g. for fake perspective or italic-style sprites.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosColorSkewSource {
+    /// Quad position, top-left corner. Shear is applied relative to it, so it stays in place.
+    pub position: Vec2,
+    /// Quad vertices color.
+    pub color: Color,
+    /// Destination size, used for absolute scaling.
+    pub size: Vec2,
+    /// Shear factors: corners are displaced horizontally by `skew.x * y` and vertically by `skew.y * x`,
+    /// where x and y are corner offsets from the top-left corner.
+    pub skew: Vec2,
+    /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// UV flip mode.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosColorSkewSource {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        color: TColor,
+        size: TVec2,
+        skew: TVec2,
+        source: TRect,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            color: color.into(),
+            size: size.into(),
+            skew: skew.into(),
+            source: source.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+impl QuadDrawParams for PosColorSkewSource {
+    #[inline]
+    fn get_color(&self) -> Color {
+        self.color
+    }
+
+    fn corner_points(
+        &self,
+        _texture_size: Vec2,
+        c1: &mut Vec2,
+        c2: &mut Vec2,
+        c3: &mut Vec2,
+        c4: &mut Vec2,
+    ) {
+        let shear = |x: f32, y: f32| Vec2 {
+            x: self.position.x + x + self.skew.x * y,
+            y: self.position.y + y + self.skew.y * x,
+        };
+        *c1 = shear(0.0, 0.0);
+        *c2 = shear(0.0, self.size.y);
+        *c3 = shear(self.size.x, self.size.y);
+        *c4 = shear(self.size.x, 0.0);
+    }
+
+    #[inline]
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
+        calculate_uvs_with_source(
+            texture_size,
+            uv_inset,
+            &self.source,
+            self.source_space,
+            self.flip,
+            uv,
+            uv2,
+        );
+    }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::PosColorSkewSource(self.clone()))
+    }
+}
+
+/// Represents a standard quad sized by its texture source rectangle, just like `PosColorSource`,
+/// but placed so that its `anchor` point lands on `position`, e.g. for centered sprites
+/// or characters standing on their bottom-center point.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosColorAnchorSource {
+    /// Quad position, where the `anchor` point of the quad is placed.
+    pub position: Vec2,
+    /// Quad vertices color.
+    pub color: Color,
+    /// Point of the quad placed at `position`.
+    pub anchor: Anchor,
+    /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// UV flip mode.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosColorAnchorSource {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        color: TColor,
+        anchor: Anchor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            color: color.into(),
+            anchor,
+            source: source.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+impl QuadDrawParams for PosColorAnchorSource {
+    #[inline]
+    fn get_color(&self) -> Color {
+        self.color
+    }
+
+    fn corner_points(
+        &self,
+        texture_size: Vec2,
+        c1: &mut Vec2,
+        c2: &mut Vec2,
+        c3: &mut Vec2,
+        c4: &mut Vec2,
+    ) {
+        // Corners are calculated first, so zero source sizes are already replaced with the texture size:
+        calculate_corners_with_source(
+            texture_size,
+            self.position,
+            &self.source,
+            self.source_space,
+            c1,
+            c2,
+            c3,
+            c4,
         );
-
-        if use_indices {
-            vertices[vertex_offset] = c1;
-            vertices[vertex_offset + 1] = c2;
-            vertices[vertex_offset + 2] = c3;
-            vertices[vertex_offset + 3] = c4;
-        } else {
-            vertices[vertex_offset] = c1.clone();
-            vertices[vertex_offset + 1] = c2;
-            vertices[vertex_offset + 2] = c3.clone();
-            vertices[vertex_offset + 3] = c3;
-            vertices[vertex_offset + 4] = c4;
-            vertices[vertex_offset + 5] = c1;
+        let (fraction_x, fraction_y) = self.anchor.fraction();
+        let offset_x = (c3.x - c1.x) * fraction_x;
+        let offset_y = (c3.y - c1.y) * fraction_y;
+        for corner in [c1, c2, c3, c4] {
+            corner.x -= offset_x;
+            corner.y -= offset_y;
         }
     }
 
-    fn to_vertices<TVertex>(
-        &self,
-        texture_size: Vec2,
-        use_half_pixel_offset: bool,
-        use_indices: bool,
-    ) -> Vec<TVertex>
-    where
-        TVertex: Clone + From<PosUvColor>,
-    {
-        let mut vertices = Vec::with_capacity(vertices_per_quad(use_indices) as usize);
-        self.set_vertices(
+    #[inline]
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
+        calculate_uvs_with_source(
             texture_size,
-            use_half_pixel_offset,
-            use_indices,
-            0,
-            &mut vertices,
+            uv_inset,
+            &self.source,
+            self.source_space,
+            self.flip,
+            uv,
+            uv2,
         );
-        vertices
+    }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::PosColorAnchorSource(self.clone()))
     }
 }
 
-/// Represetns a standard quad with additional absolute scaling.
+/// Represents a quad with individually specified corner positions, e.g. for deformed or warped tiles.
+/// Unlike `DetailedParams`, no transform is applied: corners are used exactly as given.
 #[derive(Clone, Debug, PartialEq)]
-pub struct PosColorSizeSource {
-    /// Quad position, top-left corner.
-    pub position: Vec2,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FreeCornersSource {
+    /// Quad corner positions, starting from the top-left corner and going in the same order
+    /// as `QuadDrawParams::corner_points`.
+    pub corners: [Vec2; 4],
     /// Quad vertices color.
     pub color: Color,
-    /// Destination size, used for absolute scaling.
-    pub size: Vec2,
     /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
     pub source: Rectangle,
     /// UV flip mode.
     pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
 }
 
-impl PosColorSizeSource {
+impl FreeCornersSource {
     #[inline]
     #[must_use]
     pub fn new<TColor, TRect, TVec2>(
-        position: TVec2,
+        corners: [TVec2; 4],
         color: TColor,
-        size: TVec2,
         source: TRect,
         flip: UvFlip,
     ) -> Self
@@ -345,22 +1562,32 @@ impl PosColorSizeSource {
         TRect: Into<Rectangle>,
         TVec2: Into<Vec2>,
     {
+        let [c1, c2, c3, c4] = corners;
         Self {
-            position: position.into(),
+            corners: [c1.into(), c2.into(), c3.into(), c4.into()],
             color: color.into(),
-            size: size.into(),
             source: source.into(),
             flip,
+            source_space: SourceSpace::Pixels,
         }
     }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
 }
 
-impl QuadDrawParams for PosColorSizeSource {
+impl QuadDrawParams for FreeCornersSource {
     #[inline]
     fn get_color(&self) -> Color {
         self.color
     }
 
+    #[inline]
     fn corner_points(
         &self,
         _texture_size: Vec2,
@@ -369,38 +1596,43 @@ impl QuadDrawParams for PosColorSizeSource {
         c3: &mut Vec2,
         c4: &mut Vec2,
     ) {
-        let f2 = Vec2 {
-            x: self.position.x + self.size.x,
-            y: self.position.y + self.size.y,
-        };
-        c1.x = self.position.x;
-        c1.y = self.position.y;
-
-        c2.x = self.position.x;
-        c2.y = f2.y;
-
-        c3.x = f2.x;
-        c3.y = f2.y;
-
-        c4.x = f2.x;
-        c4.y = self.position.y;
+        *c1 = self.corners[0];
+        *c2 = self.corners[1];
+        *c3 = self.corners[2];
+        *c4 = self.corners[3];
     }
 
     #[inline]
-    fn uvs(&self, texture_size: Vec2, use_half_pixel_offset: bool, uv: &mut Vec2, uv2: &mut Vec2) {
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
         calculate_uvs_with_source(
             texture_size,
-            use_half_pixel_offset,
+            uv_inset,
             &self.source,
+            self.source_space,
             self.flip,
             uv,
             uv2,
         );
     }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::FreeCornersSource(self.clone()))
+    }
 }
 
+/// Arbitrary quadrilateral with four explicit corners, e.g. a trapezoid from a warped tile grid.
+/// Same as `FreeCornersSource`, named for users bypassing position, size and rotation math entirely.
+pub type QuadCorners = FreeCornersSource;
+
 /// Represents a quad with fully customized draw.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DetailedParams {
     /// Quad position, top-left corner.
     pub position: Vec2,
@@ -418,6 +1650,13 @@ pub struct DetailedParams {
     pub source: Rectangle,
     /// UV flip mode.
     pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+    /// Triangle winding of this quad, `None` means the builder default is used.
+    pub winding: Option<WindingOrder>,
+    /// Absolute point the quad is rotated around instead of `position + origin`, e.g. to orbit a planet.
+    /// `origin` still offsets the quad, but no longer serves as a rotation center.
+    pub world_pivot: Option<Vec2>,
 }
 
 impl DetailedParams {
@@ -448,8 +1687,95 @@ impl DetailedParams {
             rotation,
             source: source.into(),
             flip,
+            source_space: SourceSpace::Pixels,
+            winding: None,
+            world_pivot: None,
         }
     }
+
+    /// Sets triangle winding of this quad, overriding the builder default.
+    #[inline]
+    #[must_use]
+    pub fn with_winding(mut self, winding: WindingOrder) -> Self {
+        self.winding = Some(winding);
+        self
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+
+    /// Sets absolute point the quad is rotated around, see `world_pivot`.
+    #[inline]
+    #[must_use]
+    pub fn with_world_pivot<TVec2: Into<Vec2>>(mut self, world_pivot: TVec2) -> Self {
+        self.world_pivot = Some(world_pivot.into());
+        self
+    }
+}
+
+#[cfg(feature = "tetra")]
+impl DetailedParams {
+    /// Converts Tetra draw params into detailed params, so the quad looks exactly like a texture region drawn
+    /// by `Texture::draw_region` with the same params. Tetra origin is given in texels and serves as
+    /// both a placement point and a rotation center, just like `origin` here, but Tetra flips quads drawn
+    /// with negative scale in place, so such scale is converted into UV flips.
+    /// UVs are flipped vertically as well, since Tetra uses top-to-bottom texture coordinates.
+    ///
+    /// * `params` - Tetra draw params.
+    /// * `source` - Texture source rectangle in pixels, i.e. the region passed to `Texture::draw_region`.
+    #[must_use]
+    pub fn from_tetra_draw_params<TRect: Into<Rectangle>>(
+        params: &tetra::graphics::DrawParams,
+        source: TRect,
+    ) -> Self {
+        let source = source.into();
+        let size = Vec2 {
+            x: source.z.abs(),
+            y: source.w.abs(),
+        };
+        let mirror_x = params.scale.x < 0.0;
+        let mirror_y = params.scale.y < 0.0;
+        // Mirroring around the origin equals drawing a flipped quad with the origin on the opposite side:
+        let origin = Vec2 {
+            x: if mirror_x {
+                size.x - params.origin.x
+            } else {
+                params.origin.x
+            },
+            y: if mirror_y {
+                size.y - params.origin.y
+            } else {
+                params.origin.y
+            },
+        };
+        let flip = match (mirror_x, !mirror_y) {
+            (false, false) => UvFlip::None,
+            (true, false) => UvFlip::Horizontal,
+            (false, true) => UvFlip::Vertical,
+            (true, true) => UvFlip::Both,
+        };
+        Self::new(
+            Vec2 {
+                x: params.position.x - origin.x,
+                y: params.position.y - origin.y,
+            },
+            crate::common_types::color_from_tetra(params.color),
+            origin,
+            size,
+            Vec2 {
+                x: params.scale.x.abs(),
+                y: params.scale.y.abs(),
+            },
+            params.rotation,
+            source,
+            flip,
+        )
+    }
 }
 
 impl QuadDrawParams for DetailedParams {
@@ -488,7 +1814,8 @@ impl QuadDrawParams for DetailedParams {
         let p3 = Vec2 { x: f2.x, y: f2.y };
         let p4 = Vec2 { x: f2.x, y: f.y };
 
-        if self.rotation == 0.0 {
+        // Rotation around the world pivot is applied after the quad is placed:
+        if self.rotation == 0.0 || self.world_pivot.is_some() {
             c1.x = p1.x;
             c1.y = p1.y;
 
@@ -525,14 +1852,27 @@ impl QuadDrawParams for DetailedParams {
         c3.y += world_origin.y;
         c4.x += world_origin.x;
         c4.y += world_origin.y;
+
+        if let Some(pivot) = self.world_pivot {
+            if self.rotation != 0.0 {
+                let (sin, cos) = self.rotation.sin_cos();
+                for corner in [c1, c2, c3, c4] {
+                    let x = corner.x - pivot.x;
+                    let y = corner.y - pivot.y;
+                    corner.x = pivot.x + cos * x - sin * y;
+                    corner.y = pivot.y + sin.mul_add(x, cos * y);
+                }
+            }
+        }
     }
 
     #[inline]
-    fn uvs(&self, texture_size: Vec2, use_half_pixel_offset: bool, uv: &mut Vec2, uv2: &mut Vec2) {
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
         calculate_uvs_with_source(
             texture_size,
-            use_half_pixel_offset,
+            uv_inset,
             &self.source,
+            self.source_space,
             self.flip,
             uv,
             uv2,
@@ -543,39 +1883,483 @@ impl QuadDrawParams for DetailedParams {
     fn get_color(&self) -> Color {
         self.color
     }
+
+    #[inline]
+    fn winding(&self) -> Option<WindingOrder> {
+        self.winding
+    }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::DetailedParams(self.clone()))
+    }
+}
+
+/// Represents a standard quad showing only a window into its texture source rectangle,
+/// e.g. for scrolling water or parallax effects confined to a single atlas cell.
+///
+/// Window is set with `uv_offset` and `uv_scale`, both are fractions of the source rectangle UV span:
+/// offset of (0, 0) and scale of (1, 1) show the entire source rectangle, just like `PosColorSource`.
+/// They are applied after `flip` and UV inset, so the window moves in the quad's own orientation and
+/// stays inside the corrected source region as long as `uv_offset + uv_scale` does not exceed 1.
+/// Going further samples texels outside of the source rectangle.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PosColorSubUvSource {
+    /// Quad position, top-left corner.
+    pub position: Vec2,
+    /// Quad vertices color.
+    pub color: Color,
+    /// Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    pub source: Rectangle,
+    /// Window offset, as a fraction of the source rectangle.
+    pub uv_offset: Vec2,
+    /// Window size, as a fraction of the source rectangle.
+    pub uv_scale: Vec2,
+    /// UV flip mode.
+    pub flip: UvFlip,
+    /// Units of `source`.
+    pub source_space: SourceSpace,
+}
+
+impl PosColorSubUvSource {
+    #[inline]
+    #[must_use]
+    pub fn new<TColor, TRect, TVec2>(
+        position: TVec2,
+        color: TColor,
+        source: TRect,
+        uv_offset: TVec2,
+        uv_scale: TVec2,
+        flip: UvFlip,
+    ) -> Self
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        Self {
+            position: position.into(),
+            color: color.into(),
+            source: source.into(),
+            uv_offset: uv_offset.into(),
+            uv_scale: uv_scale.into(),
+            flip,
+            source_space: SourceSpace::Pixels,
+        }
+    }
+
+    /// Sets units of the texture source rectangle, pixels by default.
+    #[inline]
+    #[must_use]
+    pub fn with_source_space(mut self, source_space: SourceSpace) -> Self {
+        self.source_space = source_space;
+        self
+    }
+}
+
+impl QuadDrawParams for PosColorSubUvSource {
+    #[inline]
+    fn get_color(&self) -> Color {
+        self.color
+    }
+
+    #[inline]
+    fn corner_points(
+        &self,
+        texture_size: Vec2,
+        c1: &mut Vec2,
+        c2: &mut Vec2,
+        c3: &mut Vec2,
+        c4: &mut Vec2,
+    ) {
+        calculate_corners_with_source(
+            texture_size,
+            self.position,
+            &self.source,
+            self.source_space,
+            c1,
+            c2,
+            c3,
+            c4,
+        );
+    }
+
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
+        calculate_uvs_with_source(
+            texture_size,
+            uv_inset,
+            &self.source,
+            self.source_space,
+            self.flip,
+            uv,
+            uv2,
+        );
+        let span = Vec2 {
+            x: uv2.x - uv.x,
+            y: uv2.y - uv.y,
+        };
+        uv.x += span.x * self.uv_offset.x;
+        uv.y += span.y * self.uv_offset.y;
+        uv2.x = uv.x + span.x * self.uv_scale.x;
+        uv2.y = uv.y + span.y * self.uv_scale.y;
+    }
+
+    #[inline]
+    fn flip(&self) -> UvFlip {
+        self.flip
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(StoredParams::PosColorSubUvSource(self.clone()))
+    }
+}
+
+/// Holds a copy of the built-in draw params a quad was set with, retained by a mesh builder
+/// so quads can be read back and edited without losing e.g. rotation or scale.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StoredParams {
+    PosColorSource(PosColorSource),
+    PosGradientSource(PosGradientSource),
+    PosColorSizeSource(PosColorSizeSource),
+    FreeCornersSource(FreeCornersSource),
+    DetailedParams(DetailedParams),
+    PosColorSubUvSource(PosColorSubUvSource),
+    PosColorSkewSource(PosColorSkewSource),
+    PosColorAnchorSource(PosColorAnchorSource),
+    PosColorTiledSource(PosColorTiledSource),
+}
+
+impl QuadDrawParams for StoredParams {
+    fn get_color(&self) -> Color {
+        match self {
+            StoredParams::PosColorSource(params) => params.get_color(),
+            StoredParams::PosGradientSource(params) => params.get_color(),
+            StoredParams::PosColorSizeSource(params) => params.get_color(),
+            StoredParams::FreeCornersSource(params) => params.get_color(),
+            StoredParams::DetailedParams(params) => params.get_color(),
+            StoredParams::PosColorSubUvSource(params) => params.get_color(),
+            StoredParams::PosColorSkewSource(params) => params.get_color(),
+            StoredParams::PosColorAnchorSource(params) => params.get_color(),
+            StoredParams::PosColorTiledSource(params) => params.get_color(),
+        }
+    }
+
+    fn corner_points(
+        &self,
+        texture_size: Vec2,
+        c1: &mut Vec2,
+        c2: &mut Vec2,
+        c3: &mut Vec2,
+        c4: &mut Vec2,
+    ) {
+        match self {
+            StoredParams::PosColorSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::PosGradientSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::PosColorSizeSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::FreeCornersSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::DetailedParams(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::PosColorSubUvSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::PosColorSkewSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::PosColorAnchorSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+            StoredParams::PosColorTiledSource(params) => {
+                params.corner_points(texture_size, c1, c2, c3, c4)
+            }
+        }
+    }
+
+    fn uvs(&self, texture_size: Vec2, uv_inset: UvInset, uv: &mut Vec2, uv2: &mut Vec2) {
+        match self {
+            StoredParams::PosColorSource(params) => params.uvs(texture_size, uv_inset, uv, uv2),
+            StoredParams::PosGradientSource(params) => params.uvs(texture_size, uv_inset, uv, uv2),
+            StoredParams::PosColorSizeSource(params) => params.uvs(texture_size, uv_inset, uv, uv2),
+            StoredParams::FreeCornersSource(params) => params.uvs(texture_size, uv_inset, uv, uv2),
+            StoredParams::DetailedParams(params) => params.uvs(texture_size, uv_inset, uv, uv2),
+            StoredParams::PosColorSubUvSource(params) => {
+                params.uvs(texture_size, uv_inset, uv, uv2)
+            }
+            StoredParams::PosColorSkewSource(params) => params.uvs(texture_size, uv_inset, uv, uv2),
+            StoredParams::PosColorAnchorSource(params) => {
+                params.uvs(texture_size, uv_inset, uv, uv2)
+            }
+            StoredParams::PosColorTiledSource(params) => {
+                params.uvs(texture_size, uv_inset, uv, uv2)
+            }
+        }
+    }
+
+    fn corner_colors(&self) -> [Color; 4] {
+        match self {
+            StoredParams::PosColorSource(params) => params.corner_colors(),
+            StoredParams::PosGradientSource(params) => params.corner_colors(),
+            StoredParams::PosColorSizeSource(params) => params.corner_colors(),
+            StoredParams::FreeCornersSource(params) => params.corner_colors(),
+            StoredParams::DetailedParams(params) => params.corner_colors(),
+            StoredParams::PosColorSubUvSource(params) => params.corner_colors(),
+            StoredParams::PosColorSkewSource(params) => params.corner_colors(),
+            StoredParams::PosColorAnchorSource(params) => params.corner_colors(),
+            StoredParams::PosColorTiledSource(params) => params.corner_colors(),
+        }
+    }
+
+    fn winding(&self) -> Option<WindingOrder> {
+        match self {
+            StoredParams::PosColorSource(params) => params.winding(),
+            StoredParams::PosGradientSource(params) => params.winding(),
+            StoredParams::PosColorSizeSource(params) => params.winding(),
+            StoredParams::FreeCornersSource(params) => params.winding(),
+            StoredParams::DetailedParams(params) => params.winding(),
+            StoredParams::PosColorSubUvSource(params) => params.winding(),
+            StoredParams::PosColorSkewSource(params) => params.winding(),
+            StoredParams::PosColorAnchorSource(params) => params.winding(),
+            StoredParams::PosColorTiledSource(params) => params.winding(),
+        }
+    }
+
+    fn flip(&self) -> UvFlip {
+        match self {
+            StoredParams::PosColorSource(params) => params.flip(),
+            StoredParams::PosGradientSource(params) => params.flip(),
+            StoredParams::PosColorSizeSource(params) => params.flip(),
+            StoredParams::FreeCornersSource(params) => params.flip(),
+            StoredParams::DetailedParams(params) => params.flip(),
+            StoredParams::PosColorSubUvSource(params) => params.flip(),
+            StoredParams::PosColorSkewSource(params) => params.flip(),
+            StoredParams::PosColorAnchorSource(params) => params.flip(),
+            StoredParams::PosColorTiledSource(params) => params.flip(),
+        }
+    }
+
+    #[inline]
+    fn to_stored_params(&self) -> Option<StoredParams> {
+        Some(self.clone())
+    }
+}
+
+/// Replaces zero source dimensions with the whole texture span, so a zero-dimension source means
+/// "draw the entire atlas" along that axis. Shared by corner and UV calculations to keep them consistent.
+#[inline]
+fn source_or_texture(
+    texture_size: Vec2,
+    source: &Rectangle,
+    source_space: SourceSpace,
+) -> Rectangle {
+    let (texture_width, texture_height) = match source_space {
+        SourceSpace::Pixels => (texture_size.x, texture_size.y),
+        SourceSpace::Normalized => (1.0, 1.0),
+    };
+    let (x, z) = if source.z == 0.0 {
+        (0.0, texture_width)
+    } else {
+        (source.x, source.z)
+    };
+    let (y, w) = if source.w == 0.0 {
+        (0.0, texture_height)
+    } else {
+        (source.y, source.w)
+    };
+    Rectangle { x, y, z, w }
+}
+
+/// Calculates corner points of a quad at the given position with the size of the given source rectangle.
+/// Zero source dimensions are replaced with the texture dimensions, negative ones are used by their magnitude.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn calculate_corners_with_source(
+    texture_size: Vec2,
+    position: Vec2,
+    source: &Rectangle,
+    source_space: SourceSpace,
+    c1: &mut Vec2,
+    c2: &mut Vec2,
+    c3: &mut Vec2,
+    c4: &mut Vec2,
+) {
+    let source = source_or_texture(texture_size, source, source_space);
+    // Negative extents only mirror UVs, see `calculate_uvs_with_source`:
+    let (source_or_texture_width, source_or_texture_height) = match source_space {
+        SourceSpace::Pixels => (source.z.abs(), source.w.abs()),
+        SourceSpace::Normalized => (
+            source.z.abs() * texture_size.x,
+            source.w.abs() * texture_size.y,
+        ),
+    };
+
+    let f2 = Vec2 {
+        x: position.x + source_or_texture_width,
+        y: position.y + source_or_texture_height,
+    };
+    c1.x = position.x;
+    c1.y = position.y;
+
+    c2.x = position.x;
+    c2.y = f2.y;
+
+    c3.x = f2.x;
+    c3.y = f2.y;
+
+    c4.x = f2.x;
+    c4.y = position.y;
+}
+
+/// Calculates UVs of all four corners of a quad drawing the given texture source rectangle,
+/// without writing any vertices, e.g. to pass an atlas region to a shader.
+/// UVs are returned in `QuadDrawParams::corner_points` order: top-left, bottom-left, bottom-right, top-right.
+///
+/// * `texture_size` - Size of the texture atlas.
+/// * `uv_inset` - Offsets UV edges inwards, true is a shorthand for half pixel correction,
+///   see `QuadDrawParams::set_vertices` for details.
+/// * `source` - Texture source rectangle in pixels.
+/// * `flip` - UV flip mode.
+#[must_use]
+pub fn source_to_uvs<TRect, TVec2>(
+    texture_size: TVec2,
+    uv_inset: impl Into<UvInset>,
+    source: TRect,
+    flip: UvFlip,
+) -> [Vec2; 4]
+where
+    TRect: Into<Rectangle>,
+    TVec2: Into<Vec2>,
+{
+    let mut uv = VEC2_ZERO;
+    let mut uv2 = VEC2_ZERO;
+    calculate_uvs_with_source(
+        texture_size.into(),
+        uv_inset.into(),
+        &source.into(),
+        SourceSpace::Pixels,
+        flip,
+        &mut uv,
+        &mut uv2,
+    );
+    corner_uvs(uv, uv2, flip)
+}
+
+/// Shrinks the given texture source rectangle in pixels so it fits into texture bounds,
+/// e.g. to guard against atlas coordinate typos. Mirroring by negative width or height is kept.
+///
+/// * `texture_size` - Size of the texture atlas.
+/// * `source` - Texture source rectangle in pixels.
+#[must_use]
+pub fn clamp_source<TRect, TVec2>(texture_size: TVec2, source: TRect) -> Rectangle
+where
+    TRect: Into<Rectangle>,
+    TVec2: Into<Vec2>,
+{
+    let texture_size = texture_size.into();
+    let source = source.into();
+    let clamp_span = |start: f32, length: f32, limit: f32| {
+        let low = (start + length.min(0.0)).clamp(0.0, limit);
+        let high = (start + length.max(0.0)).clamp(0.0, limit);
+        if length < 0.0 {
+            (high, low - high)
+        } else {
+            (low, high - low)
+        }
+    };
+    let (x, z) = clamp_span(source.x, source.z, texture_size.x);
+    let (y, w) = clamp_span(source.y, source.w, texture_size.y);
+    Rectangle { x, y, z, w }
+}
+
+/// Expands UVs of the first and the third quad corners into UVs of all four corners,
+/// rearranging them according to the given flip mode.
+#[inline]
+fn corner_uvs(c1_uv: Vec2, c3_uv: Vec2, flip: UvFlip) -> [Vec2; 4] {
+    let c2_uv = Vec2 {
+        x: c1_uv.x,
+        y: c3_uv.y,
+    };
+    let c4_uv = Vec2 {
+        x: c3_uv.x,
+        y: c1_uv.y,
+    };
+    flip.arrange_corner_uvs([c1_uv, c2_uv, c3_uv, c4_uv])
 }
 
 /// Calculates UVs with using OpenGL default left-to-right bottom-to-top texcoords by default, and
 /// lets end users to flip UVs how they see fit with `flip` parameter.
+/// Normalized source rectangles are used as UVs directly, ignoring UV inset.
+/// Negative source width or height mirrors the region spanning back from source position along that axis,
+/// on top of the given `flip`.
+/// Zero source width or height spans the entire texture along that axis, matching `calculate_corners_with_source`.
 pub(crate) fn calculate_uvs_with_source(
     texture_size: Vec2,
-    use_half_pixel_offset: bool,
+    uv_inset: UvInset,
     source: &Rectangle,
+    source_space: SourceSpace,
     flip: UvFlip,
     uv: &mut Vec2,
     uv2: &mut Vec2,
 ) {
-    if texture_size.x > 0.0 && texture_size.y > 0.0 {
-        let (bottom, right) = if use_half_pixel_offset {
-            (source.y + source.w - 1.0, source.x + source.z - 1.0)
-        } else {
-            (source.y + source.w, source.x + source.z)
-        };
-        let mut u = get_texel_coord(source.x, texture_size.x, use_half_pixel_offset);
-        let mut v = get_texel_coord(bottom, texture_size.y, use_half_pixel_offset);
-        let mut u2 = get_texel_coord(right, texture_size.x, use_half_pixel_offset);
-        let mut v2 = get_texel_coord(source.y, texture_size.y, use_half_pixel_offset);
-        flip_uvs(flip, &mut u, &mut v, &mut u2, &mut v2);
-        uv.x = u;
-        uv.y = v;
-        uv2.x = u2;
-        uv2.y = v2;
+    let source = source_or_texture(texture_size, source, source_space);
+    let mirror_u = source.z < 0.0;
+    let mirror_v = source.w < 0.0;
+    let source = Rectangle {
+        x: source.x + source.z.min(0.0),
+        y: source.y + source.w.min(0.0),
+        z: source.z.abs(),
+        w: source.w.abs(),
+    };
+    let (mut u, mut v, mut u2, mut v2) = if source_space == SourceSpace::Normalized {
+        debug_assert!(
+            uv_inset == UvInset::NONE,
+            "UV inset is not applied to normalized source rectangles"
+        );
+        (source.x, source.y + source.w, source.x + source.z, source.y)
+    } else if texture_size.x > 0.0 && texture_size.y > 0.0 {
+        let uv_inset = uv_inset.fit_to(source.z, source.w);
+        let left = source.x + uv_inset.left;
+        let top = source.y + uv_inset.top;
+        let right = source.x + source.z - uv_inset.right;
+        let bottom = source.y + source.w - uv_inset.bottom;
+        (
+            left / texture_size.x,
+            bottom / texture_size.y,
+            right / texture_size.x,
+            top / texture_size.y,
+        )
     } else {
         uv.x = 0.0;
         uv.y = 1.0;
         uv2.x = 1.0;
         uv2.y = 0.0;
+        return;
+    };
+    flip_uvs(flip, &mut u, &mut v, &mut u2, &mut v2);
+    if mirror_u {
+        std::mem::swap(&mut u, &mut u2);
+    }
+    if mirror_v {
+        std::mem::swap(&mut v, &mut v2);
     }
+    uv.x = u;
+    uv.y = v;
+    uv2.x = u2;
+    uv2.y = v2;
 }
 
 #[inline]
@@ -594,11 +2378,35 @@ pub(crate) fn flip_uvs<'uvs, T>(
     }
 }
 
+/// Places quad corner vertices into the given buffer starting at the specified offset: 4 vertices for indexed quads,
+/// or 6 vertices of two triangles repeating the third and the first corners otherwise.
+#[inline]
+fn place_quad_vertices<TVertex: Clone, const INDEXED: bool>(
+    (c1, c2, c3, c4): (TVertex, TVertex, TVertex, TVertex),
+    vertex_offset: usize,
+    vertices: &mut [TVertex],
+) {
+    if INDEXED {
+        vertices[vertex_offset] = c1;
+        vertices[vertex_offset + 1] = c2;
+        vertices[vertex_offset + 2] = c3;
+        vertices[vertex_offset + 3] = c4;
+    } else {
+        vertices[vertex_offset] = c1.clone();
+        vertices[vertex_offset + 1] = c2;
+        vertices[vertex_offset + 2] = c3.clone();
+        vertices[vertex_offset + 3] = c3;
+        vertices[vertex_offset + 4] = c4;
+        vertices[vertex_offset + 5] = c1;
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[must_use]
 #[inline]
 pub(crate) fn make_vertices<TVertex>(
-    color: Color,
+    colors: [Color; 4],
+    alpha_mode: AlphaMode,
     c1_position: Vec2,
     c2_position: Vec2,
     c3_position: Vec2,
@@ -611,19 +2419,26 @@ pub(crate) fn make_vertices<TVertex>(
 where
     TVertex: From<PosUvColor>,
 {
-    let c1 = TVertex::from(PosUvColor::new(c1_position, c1_uv, color));
-    let c2 = TVertex::from(PosUvColor::new(c2_position, c2_uv, color));
-    let c3 = TVertex::from(PosUvColor::new(c3_position, c3_uv, color));
-    let c4 = TVertex::from(PosUvColor::new(c4_position, c4_uv, color));
+    let [c1_color, c2_color, c3_color, c4_color] = colors;
+    let c1 = TVertex::from(PosUvColor::new(
+        c1_position,
+        c1_uv,
+        alpha_mode.convert(c1_color),
+    ));
+    let c2 = TVertex::from(PosUvColor::new(
+        c2_position,
+        c2_uv,
+        alpha_mode.convert(c2_color),
+    ));
+    let c3 = TVertex::from(PosUvColor::new(
+        c3_position,
+        c3_uv,
+        alpha_mode.convert(c3_color),
+    ));
+    let c4 = TVertex::from(PosUvColor::new(
+        c4_position,
+        c4_uv,
+        alpha_mode.convert(c4_color),
+    ));
     (c1, c2, c3, c4)
 }
-
-#[must_use]
-#[inline]
-pub(crate) fn get_texel_coord(v: f32, tex_dim: f32, use_half_pixel_offset: bool) -> f32 {
-    if use_half_pixel_offset {
-        (v + 0.5) / tex_dim
-    } else {
-        v / tex_dim
-    }
-}
@@ -1,15 +1,28 @@
 #![crate_type = "lib"]
 
+mod atlas;
 mod common_types;
+mod config;
 mod draw_params;
+#[cfg(feature = "serde")]
+mod serialization;
 
+pub use atlas::*;
 pub use common_types::*;
+pub use config::*;
 pub use draw_params::*;
+#[cfg(feature = "glam")]
+pub use glam;
 pub use mint;
 use snafu::{ensure, Backtrace, Snafu};
+use std::ops::Range;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
+/// Errors returned by mesh builders.
+///
+/// Backtraces are only captured when `backtrace` crate feature is enabled, which enables `backtraces` feature of snafu.
+/// Otherwise `backtrace` fields hold zero-sized inert snafu backtraces and errors cost nothing to create.
 #[derive(Snafu, Debug)]
 #[non_exhaustive]
 pub enum Error {
@@ -26,8 +39,90 @@ pub enum Error {
         length
     ))]
     VertexBufferIsTooLarge { length: usize, backtrace: Backtrace },
+
+    #[snafu(display("Quad index {} is out of range, quad limit is {}", index, limit))]
+    QuadIndexOutOfRange {
+        index: u32,
+        limit: u32,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display(
+        "Indices for {} quads exceed u16 range, at most {} quads can be addressed by u16 indices",
+        quad_count,
+        MAX_U16_INDEXED_QUADS
+    ))]
+    IndicesExceedU16 {
+        quad_count: u32,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display(
+        "Texture size {}x{} differs from texture size {}x{} of the other builder",
+        size.x,
+        size.y,
+        other_size.x,
+        other_size.y
+    ))]
+    TextureSizeMismatch {
+        size: Vec2,
+        other_size: Vec2,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display(
+        "Other builder differs in indices usage, triangle winding or index topology"
+    ))]
+    VertexLayoutMismatch { backtrace: Backtrace },
+
+    #[snafu(display("Index topology {:?} is not supported by the backend", index_topology))]
+    UnsupportedIndexTopology {
+        index_topology: IndexTopology,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display(
+        "VRAM budget of {} bytes cannot fit even a single quad of {} bytes",
+        max_bytes,
+        quad_bytes
+    ))]
+    VramBudgetIsTooSmall {
+        max_bytes: u64,
+        quad_bytes: u64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Texture source of quad {} extends past texture bounds", quad_index))]
+    SourceOutOfBounds {
+        quad_index: u32,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display(
+        "Vertex buffer with length '{}' does not consist of whole quads with {} vertices each",
+        length,
+        vertices_per_quad
+    ))]
+    PartialQuadVertices {
+        length: usize,
+        vertices_per_quad: u32,
+        backtrace: Backtrace,
+    },
 }
 
+/// Tile index marking an empty map cell for `MeshFromQuads::fill_from_tile_indices`.
+pub const EMPTY_TILE: u16 = u16::MAX;
+
+/// Largest quad count whose vertices can be addressed by u16 indices, i.e. 65536 vertices in total.
+pub const MAX_U16_INDEXED_QUADS: u32 = (u16::MAX as u32 + 1) / 4;
+
+/// Recommended upper bound of VRAM consumed by a single mesh, 32 MiB. Meshes of this size are safe to allocate
+/// on pretty much any GPU, so split larger maps into several meshes, see `estimate_vram_bytes`.
+pub const RECOMMENDED_MESH_VRAM_BYTES: u64 = 32 * 1024 * 1024;
+
+/// VRAM consumed by a single mesh above which allocations are likely to fail on some drivers, 512 MiB.
+pub const MAX_MESH_VRAM_BYTES: u64 = 512 * 1024 * 1024;
+
 /// This is a wrapper for a vertex and index buffers used to build a static mesh quad by quad.
 ///
 /// It is expected to be used with a custom vertex type with implemented `From<PosUvColor>`,
@@ -36,6 +131,8 @@ pub enum Error {
 /// Just make sure that given vertex type only contains values and does not contain references,
 /// or constructor will fail spectacularly: internally, vertex buffer is inited with zeroed memory
 /// by `MaybeUninit::zeroed()`, due to ggez not having `Default` trait on its vertex type.
+/// If your vertex type implements `Default`, prefer the safe `new_default` constructor,
+/// which fills unused vertices with `TVertex::default()` instead.
 ///
 /// # Example
 ///
@@ -54,7 +151,7 @@ pub enum Error {
 /// // Let's make test map 256×256;
 /// let map_size = [256, 256];
 /// // Calculate required quad limit for the map:
-/// let quad_count = map_size[0] * map_size[1];
+/// let quad_count = quad_limit_for_map((map_size[0], map_size[1]))?;
 /// // Standard white color, means tile images will be drawn as-is.
 /// let white_color = [1.0_f32, 1.0, 1.0, 1.0];
 ///
@@ -87,18 +184,36 @@ pub enum Error {
 /// # }
 /// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "serialization::MeshFromQuadsData<TVertex>")
+)]
 pub struct MeshFromQuads<TVertex>
 where
     TVertex: From<PosUvColor>,
 {
     texture_size: Vec2,
-    use_half_pixel_offset: bool,
+    uv_inset: UvInset,
     indices: Option<Vec<u32>>,
+    indices_u16: Option<Vec<u16>>,
     vertices: Vec<TVertex>,
     quad_limit: u32,
     use_indices: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     vertices_per_quad: u32,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     max_vertices: u32,
+    written_count: u32,
+    alpha_mode: AlphaMode,
+    stored_params: Option<Vec<Option<StoredParams>>>,
+    blank_vertex: TVertex,
+    winding: WindingOrder,
+    index_topology: IndexTopology,
+    lazy: bool,
+    grid: Option<TileGrid>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    dirty_vertices: Option<Range<usize>>,
 }
 
 #[cfg(feature = "ggez")]
@@ -107,14 +222,15 @@ impl MeshFromQuads<ggez::graphics::Vertex> {
     ///
     /// # Errors
     ///
-    /// Will return `Err` if builder has no indices.
+    /// Will return `Err` if builder has no indices or its index topology is not a triangle list.
     pub fn create_mesh(
         &self,
         ctx: &mut ggez::Context,
         texture: ggez::graphics::Image,
     ) -> ggez::GameResult<ggez::graphics::Mesh> {
         use ggez::graphics::Mesh;
-        match self.indices.as_ref() {
+        self.ensure_ggez_triangle_list()?;
+        match self.mesh_indices() {
             Some(indices) => Mesh::from_raw(ctx, &self.vertices, indices, Some(texture)),
             None => Err(ggez::GameError::CustomError(
                 "Unindexed meshes are not supported".to_owned(),
@@ -122,18 +238,45 @@ impl MeshFromQuads<ggez::graphics::Vertex> {
         }
     }
 
+    /// Creates a ggez mesh from the used quads only, see `written_quads`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if builder has no indices or its index topology is not a triangle list.
+    pub fn create_mesh_used(
+        &self,
+        ctx: &mut ggez::Context,
+        texture: ggez::graphics::Image,
+    ) -> ggez::GameResult<ggez::graphics::Mesh> {
+        use ggez::graphics::Mesh;
+        self.ensure_ggez_triangle_list()?;
+        if self.indices.is_some() {
+            Mesh::from_raw(
+                ctx,
+                self.used_vertices(),
+                self.used_indices(),
+                Some(texture),
+            )
+        } else {
+            Err(ggez::GameError::CustomError(
+                "Unindexed meshes are not supported".to_owned(),
+            ))
+        }
+    }
+
     /// Changes the specified ggez mesh to use vertex and index buffers of this builder.
     /// Don't forget to set mesh's texture if needed.
     ///
     /// # Errors
     ///
-    /// Will return `Err` if builder has no indices.
+    /// Will return `Err` if builder has no indices or its index topology is not a triangle list.
     pub fn update_mesh(
         &self,
         ctx: &mut ggez::Context,
         mesh: &mut ggez::graphics::Mesh,
     ) -> ggez::GameResult<()> {
-        match self.indices.as_ref() {
+        self.ensure_ggez_triangle_list()?;
+        match self.mesh_indices() {
             Some(indices) => {
                 mesh.set_vertices(ctx, &self.vertices, indices);
                 Ok(())
@@ -143,34 +286,218 @@ impl MeshFromQuads<ggez::graphics::Vertex> {
             )),
         }
     }
+
+    fn ensure_ggez_triangle_list(&self) -> ggez::GameResult<()> {
+        match self.index_topology {
+            IndexTopology::TriangleList => Ok(()),
+            IndexTopology::TriangleStrip => Err(ggez::GameError::CustomError(
+                "Triangle strip meshes are not supported".to_owned(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "macroquad")]
+impl MeshFromQuads<macroquad::models::Vertex> {
+    /// Creates a macroquad mesh from all the added quads.
+    /// Meshes without indices get sequential indices generated.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if vertices can't be addressed by macroquad's u16 indices
+    /// or index topology is not a triangle list.
+    pub fn create_mesh(
+        &self,
+        texture: Option<macroquad::texture::Texture2D>,
+    ) -> Result<macroquad::models::Mesh> {
+        ensure!(
+            self.index_topology == IndexTopology::TriangleList,
+            UnsupportedIndexTopology {
+                index_topology: self.index_topology
+            }
+        );
+        create_macroquad_mesh(&self.vertices, self.mesh_indices(), texture)
+    }
+
+    /// Creates a macroquad mesh from the used quads only, see `written_quads`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if used vertices can't be addressed by macroquad's u16 indices
+    /// or index topology is not a triangle list.
+    pub fn create_mesh_used(
+        &self,
+        texture: Option<macroquad::texture::Texture2D>,
+    ) -> Result<macroquad::models::Mesh> {
+        ensure!(
+            self.index_topology == IndexTopology::TriangleList,
+            UnsupportedIndexTopology {
+                index_topology: self.index_topology
+            }
+        );
+        let indices = self.indices.as_ref().map(|_| self.used_indices());
+        create_macroquad_mesh(self.used_vertices(), indices, texture)
+    }
+}
+
+#[cfg(feature = "macroquad")]
+fn create_macroquad_mesh(
+    vertices: &[macroquad::models::Vertex],
+    indices: Option<&[u32]>,
+    texture: Option<macroquad::texture::Texture2D>,
+) -> Result<macroquad::models::Mesh> {
+    // The last vertex must be addressable by an u16 index:
+    ensure!(
+        vertices.len() <= u16::MAX as usize + 1,
+        VertexBufferIsTooLarge {
+            length: vertices.len()
+        }
+    );
+    let indices = match indices {
+        Some(indices) => indices.iter().map(|&index| index as u16).collect(),
+        None => (0..vertices.len()).map(|index| index as u16).collect(),
+    };
+    Ok(macroquad::models::Mesh {
+        vertices: vertices.to_vec(),
+        indices,
+        texture,
+    })
+}
+
+/// Tetra mesh created by a mesh builder, along with its buffers.
+///
+/// Keep it around if you plan on changing mesh quads later: vertex buffer is needed to upload the changes.
+#[cfg(feature = "tetra")]
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct BuiltMesh {
+    /// Created mesh, ready to be drawn.
+    pub mesh: tetra::graphics::mesh::Mesh,
+    /// Vertex buffer used by the mesh. Use its `set_data` to change mesh quads.
+    pub vertex_buffer: tetra::graphics::mesh::VertexBuffer,
+    /// Amount of vertices in the vertex buffer.
+    pub vertex_count: usize,
+    /// Amount of indices in the index buffer, 0 if mesh has no indices.
+    pub index_count: usize,
+    /// Size of the texture atlas mesh UVs were calculated for, i.e. texture size of the mesh builder.
+    pub texture_size: mint::Vector2<f32>,
+}
+
+#[cfg(feature = "tetra")]
+impl BuiltMesh {
+    /// Swaps mesh texture for the given one without rebuilding the mesh,
+    /// e.g. to switch to a recolored or seasonal version of the texture atlas.
+    ///
+    /// New texture must have exactly the same size as the one the mesh was built for, see `texture_size`,
+    /// since UVs depend on texture size.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if texture size differs from `texture_size`. Rebuild the mesh in this case.
+    pub fn set_texture(&mut self, texture: tetra::graphics::Texture) -> tetra::Result<()> {
+        let (width, height) = texture.size();
+        if width as f32 != self.texture_size.x || height as f32 != self.texture_size.y {
+            return Err(tetra::TetraError::PlatformError(format!(
+                "Texture size {}x{} differs from the mesh texture size {}x{}, \
+                mesh needs to be rebuilt to have correct UVs",
+                width, height, self.texture_size.x, self.texture_size.y
+            )));
+        }
+        self.mesh.set_texture(texture);
+        Ok(())
+    }
+
+    /// Uploads all vertices and indices of the given builder to this mesh.
+    ///
+    /// Existing buffers are reused if builder buffer lengths match them, otherwise new buffers are allocated.
+    /// Mesh `texture_size` is taken from the builder as well.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if index topology is not a triangle list
+    /// or the underlying graphics API encounters an error when allocating vertex or index buffer.
+    pub fn update(
+        &mut self,
+        ctx: &mut tetra::Context,
+        builder: &MeshFromQuads<tetra::graphics::mesh::Vertex>,
+    ) -> tetra::Result<()> {
+        use tetra::graphics::mesh::{IndexBuffer, VertexBuffer};
+        builder.ensure_tetra_triangle_list()?;
+        self.texture_size = builder.texture_size;
+        let vertices = builder.vertices();
+        if vertices.len() == self.vertex_count {
+            self.vertex_buffer.set_data(ctx, vertices, 0);
+        } else {
+            self.vertex_buffer = VertexBuffer::new(ctx, vertices)?;
+            self.vertex_count = vertices.len();
+            self.mesh.set_vertex_buffer(self.vertex_buffer.clone());
+        }
+
+        match (builder.mesh_indices(), self.mesh.index_buffer()) {
+            (Some(indices), Some(index_buffer)) if indices.len() == self.index_count => {
+                index_buffer.set_data(ctx, indices, 0);
+            }
+            (Some(indices), _) => {
+                self.mesh.set_index_buffer(IndexBuffer::new(ctx, indices)?);
+                self.index_count = indices.len();
+            }
+            (None, _) => {
+                self.mesh.reset_index_buffer();
+                self.index_count = 0;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(feature = "tetra")]
 impl MeshFromQuads<tetra::graphics::mesh::Vertex> {
     /// Creates a Tetra mesh from all the added quads.
     ///
-    /// Returns both the mesh and its new vertex buffer. You can use its `set_data` if an update is needed later.
+    /// Returns the mesh along with its new vertex buffer. You can use its `set_data` if an update is needed later.
     ///
     /// # Errors
     ///
-    /// Will return `Err` if the underlying graphics API encounters an error when allocating vertex or index buffer.
+    /// Will return `Err` if index topology is not a triangle list
+    /// or the underlying graphics API encounters an error when allocating vertex or index buffer.
     pub fn create_mesh(
         &self,
         ctx: &mut tetra::Context,
         texture: tetra::graphics::Texture,
-    ) -> tetra::Result<(
-        tetra::graphics::mesh::Mesh,
-        tetra::graphics::mesh::VertexBuffer,
-    )> {
-        use tetra::graphics::mesh::{IndexBuffer, Mesh, VertexBuffer};
-        let vertex_buffer = VertexBuffer::new(ctx, &self.vertices)?;
-        let mut mesh = if let Some(index_buffer) = &self.indices {
-            Mesh::indexed(vertex_buffer.clone(), IndexBuffer::new(ctx, index_buffer)?)
-        } else {
-            Mesh::new(vertex_buffer.clone())
-        };
-        mesh.set_texture(texture);
-        Ok((mesh, vertex_buffer))
+    ) -> tetra::Result<BuiltMesh> {
+        self.ensure_tetra_triangle_list()?;
+        create_tetra_mesh(
+            ctx,
+            texture,
+            self.texture_size,
+            &self.vertices,
+            self.mesh_indices(),
+        )
+    }
+
+    /// Creates a Tetra mesh from the used quads only, see `written_quads`.
+    /// Use it for sparsely filled builders to avoid uploading unused tail of vertex and index buffers.
+    ///
+    /// Returns the mesh along with its new vertex buffer. You can use its `set_data` if an update is needed later.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if index topology is not a triangle list
+    /// or the underlying graphics API encounters an error when allocating vertex or index buffer.
+    pub fn create_mesh_used(
+        &self,
+        ctx: &mut tetra::Context,
+        texture: tetra::graphics::Texture,
+    ) -> tetra::Result<BuiltMesh> {
+        self.ensure_tetra_triangle_list()?;
+        let indices = self.indices.as_ref().map(|_| self.used_indices());
+        create_tetra_mesh(
+            ctx,
+            texture,
+            self.texture_size,
+            self.used_vertices(),
+            indices,
+        )
     }
 
     /// Changes the specified Tetra mesh to use texture, vertex and index buffers of this builder.
@@ -180,15 +507,17 @@ impl MeshFromQuads<tetra::graphics::mesh::Vertex> {
     ///
     /// # Errors
     ///
-    /// Will return `Err` if the underlying graphics API encounters an error when allocating vertex or index buffer.
+    /// Will return `Err` if index topology is not a triangle list
+    /// or the underlying graphics API encounters an error when allocating vertex or index buffer.
     pub fn update_mesh(
         &self,
         ctx: &mut tetra::Context,
         mesh: &mut tetra::graphics::mesh::Mesh,
     ) -> tetra::Result<tetra::graphics::mesh::VertexBuffer> {
         use tetra::graphics::mesh::{IndexBuffer, VertexBuffer};
+        self.ensure_tetra_triangle_list()?;
         let vertex_buffer = VertexBuffer::new(ctx, &self.vertices)?;
-        if let Some(index_buffer) = &self.indices {
+        if let Some(index_buffer) = self.mesh_indices() {
             mesh.set_index_buffer(IndexBuffer::new(ctx, index_buffer)?);
         } else {
             mesh.reset_index_buffer();
@@ -196,6 +525,52 @@ impl MeshFromQuads<tetra::graphics::mesh::Vertex> {
         mesh.set_vertex_buffer(vertex_buffer.clone());
         Ok(vertex_buffer)
     }
+
+    /// Changes the specified Tetra mesh to use index buffer of this builder, keeping its vertex buffer intact,
+    /// e.g. after hiding quads with `set_draw_order`. This saves a full vertex upload when geometry is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if index topology is not a triangle list
+    /// or the underlying graphics API encounters an error when allocating index buffer.
+    pub fn update_indices_only(
+        &self,
+        ctx: &mut tetra::Context,
+        mesh: &mut tetra::graphics::mesh::Mesh,
+    ) -> tetra::Result<()> {
+        use tetra::graphics::mesh::IndexBuffer;
+        self.ensure_tetra_triangle_list()?;
+        if let Some(index_buffer) = self.mesh_indices() {
+            mesh.set_index_buffer(IndexBuffer::new(ctx, index_buffer)?);
+        } else {
+            mesh.reset_index_buffer();
+        }
+        Ok(())
+    }
+
+    /// Uploads only the modified vertices, see `dirty_vertex_range`, into the given vertex buffer
+    /// and resets modified range. Does nothing if no vertices were modified.
+    ///
+    /// * `vertex_buffer` - Vertex buffer created from this builder, e.g. `BuiltMesh::vertex_buffer`.
+    ///   It must be large enough to hold all vertices, so recreate it after growing the builder with `resize`.
+    pub fn update_mesh_dirty(
+        &mut self,
+        ctx: &mut tetra::Context,
+        vertex_buffer: &tetra::graphics::mesh::VertexBuffer,
+    ) {
+        if let Some(dirty) = self.dirty_vertices.take() {
+            vertex_buffer.set_data(ctx, &self.vertices[dirty.clone()], dirty.start);
+        }
+    }
+
+    fn ensure_tetra_triangle_list(&self) -> tetra::Result<()> {
+        match self.index_topology {
+            IndexTopology::TriangleList => Ok(()),
+            IndexTopology::TriangleStrip => Err(tetra::TetraError::PlatformError(
+                "Triangle strip meshes are not supported".to_owned(),
+            )),
+        }
+    }
 }
 
 impl<TVertex> MeshFromQuads<TVertex>
@@ -209,14 +584,14 @@ where
     ///
     /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
     /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
-    /// (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
-    /// It is better to use padded texture atlas with this fix,
-    /// otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
-    /// but keep it in mind.
-    /// If set to false, expects end users to deal with texture bleeding themselves,
-    /// e.g. with correct texture sampling or shifting viewport by half a pixel.
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    ///   It is better to use padded texture atlas with this fix,
+    ///   otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
+    ///   but keep it in mind.
+    ///   If set to false, expects end users to deal with texture bleeding themselves,
+    ///   e.g. with correct texture sampling or shifting viewport by half a pixel.
     /// * `quad_limit` - Amount of quads in the built static mesh. For safest allocations,
-    /// try not to go over 32 MB of needed VRAM for a single mesh.
+    ///   try not to go over 32 MB of needed VRAM for a single mesh, see `estimate_vram_bytes`.
     ///
     /// # Errors
     ///
@@ -237,14 +612,14 @@ where
     ///
     /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
     /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
-    /// (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
-    /// It is better to use padded texture atlas with this fix,
-    /// otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
-    /// but keep it in mind.
-    /// If set to false, expects end users to deal with texture bleeding themselves,
-    /// e.g. with correct texture sampling or shifting viewport by half a pixel.
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    ///   It is better to use padded texture atlas with this fix,
+    ///   otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
+    ///   but keep it in mind.
+    ///   If set to false, expects end users to deal with texture bleeding themselves,
+    ///   e.g. with correct texture sampling or shifting viewport by half a pixel.
     /// * `quad_limit` - Amount of quads in the built static mesh. For safest allocations,
-    /// try not to go over 32 MB of needed VRAM for a single mesh.
+    ///   try not to go over 32 MB of needed VRAM for a single mesh, see `estimate_vram_bytes`.
     ///
     /// # Errors
     ///
@@ -258,17 +633,173 @@ where
         Self::create(texture_size, use_half_pixel_offset, quad_limit, false)
     }
 
+    /// Creates a mesh builder for an indexed mesh capable of holding exactly `quad_limit` quads,
+    /// but unlike `new`, vertices are not allocated up front: vertex vec starts empty and grows
+    /// with blank vertex data up to the furthest quad being set. Mesh creation uploads only these populated vertices
+    /// along with `used_indices`.
+    ///
+    /// Use it for sparse maps where most of the quad limit stays unused. Pre-allocated builders are still
+    /// preferable when most quads get set anyway, since growing the vertex vec reallocates and copies it.
+    /// Indices are generated for the entire `quad_limit` regardless.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `quad_limit` - Maximum amount of quads in the built static mesh.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or `quad_limit` is too high.
+    pub fn new_lazy<T: Into<Vec2>>(
+        texture_size: T,
+        use_half_pixel_offset: bool,
+        quad_limit: u32,
+    ) -> Result<Self> {
+        let mut builder = Self::create(texture_size, use_half_pixel_offset, 0, true)?;
+        builder.lazy = true;
+        builder.resize(quad_limit)?;
+        Ok(builder)
+    }
+
+    /// Creates a mesh builder holding exactly as many quads as the given draw params, and sets them in order,
+    /// so the first params describe quad 0. Useful for iterator-based generation, e.g. `map` over map cells.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `use_indices` - If set to true, quad will consist of 4 vertices and indices will be used;
+    ///   otherwise, 6 vertices will be used.
+    /// * `quads` - Draw params of all quads, their count determines `quad_limit`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or there are too many quads.
+    pub fn from_quads<T, I>(
+        texture_size: T,
+        use_half_pixel_offset: bool,
+        use_indices: bool,
+        quads: I,
+    ) -> Result<Self>
+    where
+        T: Into<Vec2>,
+        I: IntoIterator<Item = PosColorSource>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let quads = quads.into_iter();
+        let quad_limit = match u32::try_from(quads.len()) {
+            Ok(quad_limit) => quad_limit,
+            Err(_) => return QuadCountIsTooLarge {}.fail(),
+        };
+        let mut builder =
+            Self::create(texture_size, use_half_pixel_offset, quad_limit, use_indices)?;
+        builder.set_each((0..quad_limit).zip(quads));
+        Ok(builder)
+    }
+
+    /// Creates a mesh builder for an indexed tile map with the given amount of columns and rows,
+    /// holding exactly `columns` * `rows` quads. Grid layout is stored, see `grid`,
+    /// so tiles can be addressed by their 2D coordinates, see `tile_quad_index`.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `columns` - Amount of tiles in a single row.
+    /// * `rows` - Amount of tile rows.
+    /// * `tile_size` - Destination size of a single tile.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or tile count is too high.
+    pub fn for_grid<TSize, TVec2>(
+        texture_size: TSize,
+        use_half_pixel_offset: bool,
+        columns: u32,
+        rows: u32,
+        tile_size: TVec2,
+    ) -> Result<Self>
+    where
+        TSize: Into<Vec2>,
+        TVec2: Into<Vec2>,
+    {
+        let quad_limit = quad_limit_for_map((columns, rows))?;
+        let mut builder = Self::create(texture_size, use_half_pixel_offset, quad_limit, true)?;
+        builder.grid = Some(TileGrid {
+            columns,
+            rows,
+            tile_size: tile_size.into(),
+        });
+        Ok(builder)
+    }
+
+    /// Creates a mesh builder capable of holding exactly `quad_limit` quads with the given triangle winding.
+    /// Use it for backends expecting counter-clockwise front faces with back-face culling enabled.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `quad_limit` - Amount of quads in the built static mesh. For safest allocations,
+    ///   try not to go over 32 MB of needed VRAM for a single mesh, see `estimate_vram_bytes`.
+    /// * `use_indices` - If set to true, quad will consist of 4 vertices and indices will be used;
+    ///   otherwise, 6 vertices will be used.
+    /// * `winding` - Triangle winding of all quads, applied to index pattern for indexed meshes
+    ///   and to vertex order otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or `quad_limit` is too high.
+    pub fn new_with_winding<T: Into<Vec2>>(
+        texture_size: T,
+        use_half_pixel_offset: bool,
+        quad_limit: u32,
+        use_indices: bool,
+        winding: WindingOrder,
+    ) -> Result<Self> {
+        let zeroed_vertex = unsafe { std::mem::MaybeUninit::zeroed().assume_init() };
+        Self::create_with_blank(
+            texture_size,
+            use_half_pixel_offset,
+            quad_limit,
+            use_indices,
+            Vec::new(),
+            None,
+            zeroed_vertex,
+            winding,
+        )
+    }
+
+    /// Creates a mesh builder for an indexed mesh which additionally keeps its indices as u16,
+    /// for backends and GPUs requiring 16-bit index buffers. See `indices_u16`.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `quad_limit` - Amount of quads in the built static mesh, at most `MAX_U16_INDEXED_QUADS`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or `quad_limit` is greater than `MAX_U16_INDEXED_QUADS`.
+    pub fn new_u16_indexed<T: Into<Vec2>>(
+        texture_size: T,
+        use_half_pixel_offset: bool,
+        quad_limit: u32,
+    ) -> Result<Self> {
+        let indices_u16 = generate_quad_indices_u16(quad_limit)?;
+        let mut builder = Self::create(texture_size, use_half_pixel_offset, quad_limit, true)?;
+        builder.indices_u16 = Some(indices_u16);
+        Ok(builder)
+    }
+
     /// Creates a mesh builder from the existing vertices and indices.
     ///
     /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
     /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
-    /// (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
-    /// It is better to use padded texture atlas with this fix,
-    /// otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
-    /// but keep it in mind.
-    /// If set to false, expects end users to deal with texture bleeding themselves,
-    /// e.g. with correct texture sampling or shifting viewport by half a pixel.
-    /// * `vertices` - Existing vertices to modify.
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    ///   It is better to use padded texture atlas with this fix,
+    ///   otherwise only half of border pixels will be displayed. This is often imperceptible, unlike bleeding,
+    ///   but keep it in mind.
+    ///   If set to false, expects end users to deal with texture bleeding themselves,
+    ///   e.g. with correct texture sampling or shifting viewport by half a pixel.
+    /// * `vertices` - Existing vertices to modify. Every whole quad in them counts as written, see `written_quads`.
     /// * `indices` - Indices for the given existing vertices.
     ///
     /// # Errors
@@ -297,16 +828,31 @@ where
         let use_indices = indices.is_some();
         let vertices_per_quad = vertices_per_quad(use_indices);
         let max_vertices = vertices.len() as u32;
-        let quad_limit = max_vertices / vertices_per_quad;
+        // Every whole quad of the supplied vertices is considered written, a trailing partial quad is not.
+        // Supplied buffer has no spare room, so written quads are the limit as well:
+        let written_count = max_vertices / vertices_per_quad;
+        let quad_limit = written_count;
+        let blank_vertex = unsafe { std::mem::MaybeUninit::zeroed().assume_init() };
+        let winding = WindingOrder::Clockwise;
         Ok(Self {
             texture_size: texture_size_vec,
-            use_half_pixel_offset,
+            uv_inset: use_half_pixel_offset.into(),
             indices,
+            indices_u16: None,
             vertices,
             quad_limit,
             use_indices,
             vertices_per_quad,
             max_vertices,
+            written_count,
+            alpha_mode: AlphaMode::default(),
+            stored_params: None,
+            blank_vertex,
+            winding,
+            index_topology: IndexTopology::TriangleList,
+            lazy: false,
+            grid: None,
+            dirty_vertices: None,
         })
     }
 
@@ -315,6 +861,66 @@ where
         use_half_pixel_offset: bool,
         quad_limit: u32,
         use_indices: bool,
+    ) -> Result<Self> {
+        Self::fill_into(
+            texture_size,
+            use_half_pixel_offset,
+            quad_limit,
+            use_indices,
+            Vec::new(),
+            None,
+        )
+    }
+
+    /// Creates a new mesh builder reusing the given, possibly recycled, vertex and index vecs.
+    /// Vecs are cleared and resized to fit the quad limit, so their previous allocations are reused
+    /// when they are large enough. Useful for pooling buffers across frames or map chunks.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `quad_limit` - Maximum amount of quads mesh builder will be able to hold.
+    /// * `use_indices` - If set to true, quad will consist of 4 vertices and indices will be used;
+    ///   otherwise, 6 vertices will be used and `indices` vec is dropped.
+    /// * `vertices` - Vertex vec to reuse, its contents are discarded.
+    /// * `indices` - Index vec to reuse, its contents are discarded. If `None`, a new one is allocated when needed.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1, if `quad_limit` is too high
+    /// or if given vecs cannot be grown to the required size.
+    pub fn fill_into<T: Into<Vec2>>(
+        texture_size: T,
+        use_half_pixel_offset: bool,
+        quad_limit: u32,
+        use_indices: bool,
+        vertices: Vec<TVertex>,
+        indices: Option<Vec<u32>>,
+    ) -> Result<Self> {
+        let zeroed_vertex = unsafe { std::mem::MaybeUninit::zeroed().assume_init() };
+        Self::create_with_blank(
+            texture_size,
+            use_half_pixel_offset,
+            quad_limit,
+            use_indices,
+            vertices,
+            indices,
+            zeroed_vertex,
+            WindingOrder::Clockwise,
+        )
+    }
+
+    /// Does the actual work of `fill_into`, filling unused vertices with the given blank vertex.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn create_with_blank<T: Into<Vec2>>(
+        texture_size: T,
+        use_half_pixel_offset: bool,
+        quad_limit: u32,
+        use_indices: bool,
+        mut vertices: Vec<TVertex>,
+        indices: Option<Vec<u32>>,
+        blank_vertex: TVertex,
+        winding: WindingOrder,
     ) -> Result<Self> {
         let texture_size_vec: Vec2 = texture_size.into();
         ensure!(
@@ -325,26 +931,65 @@ where
         );
 
         let indices = if use_indices {
-            Some(generate_quad_indices(quad_limit)?)
+            let mut indices = indices.unwrap_or_default();
+            fill_quad_indices(
+                &mut indices,
+                quad_limit,
+                0,
+                winding,
+                IndexTopology::TriangleList,
+            )?;
+            Some(indices)
         } else {
             None
         };
         let vertices_per_quad = vertices_per_quad(use_indices);
         let max_vertices = total_vertices_in_quads(quad_limit, use_indices)?;
-        let zeroed_vertex = unsafe { std::mem::MaybeUninit::zeroed().assume_init() };
-        let vertices: Vec<TVertex> = vec![zeroed_vertex; max_vertices as usize];
+        vertices.clear();
+        ensure!(
+            vertices.try_reserve(max_vertices as usize).is_ok(),
+            VertexBufferIsTooLarge {
+                length: max_vertices as usize
+            }
+        );
+        vertices.resize(max_vertices as usize, blank_vertex.clone());
         Ok(Self {
             texture_size: texture_size_vec,
-            use_half_pixel_offset,
+            uv_inset: use_half_pixel_offset.into(),
             indices,
+            indices_u16: None,
             vertices,
             quad_limit,
             use_indices,
             vertices_per_quad,
             max_vertices,
+            written_count: 0,
+            alpha_mode: AlphaMode::default(),
+            stored_params: None,
+            blank_vertex,
+            winding,
+            index_topology: IndexTopology::TriangleList,
+            lazy: false,
+            grid: None,
+            dirty_vertices: None,
         })
     }
 
+    /// Gets tile map layout of a builder created with `for_grid`, or `None` for other builders.
+    #[inline]
+    #[must_use]
+    pub fn grid(&self) -> Option<TileGrid> {
+        self.grid
+    }
+
+    /// Gets index of the quad used for the tile at the given column and row of a builder created with `for_grid`.
+    /// Returns `None` if the builder has no grid layout or the tile is out of grid bounds.
+    #[inline]
+    #[must_use]
+    pub fn tile_quad_index(&self, column: u32, row: u32) -> Option<u32> {
+        self.grid?.quad_index(column, row)
+    }
+
     /// Gets the reference to the indices which will be stored in an index buffer after a `create_mesh` call.
     ///
     /// Indices draw the vertices in clockwise order.
@@ -355,24 +1000,201 @@ where
         self.indices.as_ref()
     }
 
-    /// Gets the total amount of quads in the vertex buffer.
+    /// Gets the u16 copy of `indices`, kept in sync with them.
+    /// Only builders created by `new_u16_indexed` have it, returns `None` otherwise.
     #[inline]
     #[must_use]
-    pub fn quad_limit(&self) -> u32 {
-        self.quad_limit
+    pub fn indices_u16(&self) -> Option<&Vec<u16>> {
+        self.indices_u16.as_ref()
     }
 
-    /// Gets the reference to the vertices which will be stored in a vertex buffer after a `create_mesh` call.
-    ///
+    /// Gets the triangle winding of the quads.
+    #[inline]
+    #[must_use]
+    pub fn winding(&self) -> WindingOrder {
+        self.winding
+    }
+
+    /// Gets whether vertices of this builder are allocated on demand, see `new_lazy`.
+    #[inline]
+    #[must_use]
+    pub fn is_lazy(&self) -> bool {
+        self.lazy
+    }
+
+    /// Gets the topology of indices, so backends know how to draw them.
+    #[inline]
+    #[must_use]
+    pub fn index_topology(&self) -> IndexTopology {
+        self.index_topology
+    }
+
+    /// Changes the topology of indices, regenerating them for indexed builders,
+    /// so custom draw order set by `set_draw_order` is reset. Builders without indices only store the topology.
+    /// Does nothing if the topology is unchanged.
+    ///
+    /// * `index_topology` - New topology of indices.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if indices for the entire `quad_limit` can't be allocated, or if u16 indices of a triangle strip
+    /// would collide with the restart index, in which case nothing is changed.
+    pub fn set_index_topology(&mut self, index_topology: IndexTopology) -> Result<()> {
+        if index_topology == self.index_topology {
+            return Ok(());
+        }
+        if self.indices_u16.is_some() {
+            ensure_u16_indexable_as(self.quad_limit, index_topology)?;
+        }
+        if let Some(indices) = self.indices.as_mut() {
+            fill_quad_indices(indices, self.quad_limit, 0, self.winding, index_topology)?;
+        }
+        self.index_topology = index_topology;
+        self.sync_u16_indices();
+        Ok(())
+    }
+
+    /// Gets the inset applied to UV edges of the quads being set.
+    #[inline]
+    #[must_use]
+    pub fn uv_inset(&self) -> UvInset {
+        self.uv_inset
+    }
+
+    /// Sets the inset applied to UV edges of the quads being set, replacing the half pixel offset
+    /// this builder was created with. Already set quads are not affected.
+    ///
+    /// * `uv_inset` - New inset in texel units, true and false are shorthands for half pixel offset and no inset.
+    #[inline]
+    pub fn set_uv_inset<T: Into<UvInset>>(&mut self, uv_inset: T) {
+        self.uv_inset = uv_inset.into();
+    }
+
+    /// Gets the conversion applied to colors of the quads being set.
+    #[inline]
+    #[must_use]
+    pub fn alpha_mode(&self) -> AlphaMode {
+        self.alpha_mode
+    }
+
+    /// Sets the conversion applied to colors of the quads being set, e.g. to un-premultiply colors
+    /// for premultiplied atlases. Already set quads are not affected.
+    #[inline]
+    pub fn set_alpha_mode(&mut self, alpha_mode: AlphaMode) {
+        self.alpha_mode = alpha_mode;
+    }
+
+    /// Gets the options used to calculate vertices of the quads being set, e.g. to get matching vertices
+    /// from `QuadDrawParams::to_vertices` for a standalone quad.
+    #[inline]
+    #[must_use]
+    pub fn vertex_options(&self) -> VertexOptions {
+        VertexOptions {
+            uv_inset: self.uv_inset,
+            use_indices: self.use_indices,
+            alpha_mode: self.alpha_mode,
+            winding: self.winding,
+        }
+    }
+
+    /// Gets the indices covering only the used quads, see `written_quads`.
+    ///
+    /// This is a prefix of the full `indices`, since indices for the entire `quad_limit` are pre-allocated.
+    /// After `set_draw_order` it covers the first `written_quads` quads in the new draw order.
+    /// Returns an empty slice if this builder has no indices.
+    #[inline]
+    #[must_use]
+    pub fn used_indices(&self) -> &[u32] {
+        match self.indices.as_ref() {
+            Some(indices) => {
+                let used_length = (self.written_count as usize
+                    * self.index_topology.indices_per_quad() as usize)
+                    .min(indices.len());
+                &indices[..used_length]
+            }
+            None => &[],
+        }
+    }
+
+    /// Builds indices drawing only the given quads in the given order, e.g. for a partial indexed draw
+    /// of quads found by `visible_quad_indices`. Index pattern follows the builder winding and index topology.
+    /// Returns an empty vec if this builder has no indices; out of range quad indices are skipped.
+    ///
+    /// * `quad_indices` - Indices of the quads to draw.
+    #[must_use]
+    pub fn collect_indices_for(&self, quad_indices: &[u32]) -> Vec<u32> {
+        if !self.use_indices {
+            return Vec::new();
+        }
+        let mut indices = Vec::with_capacity(
+            quad_indices.len() * self.index_topology.indices_per_quad() as usize,
+        );
+        for &quad_index in quad_indices {
+            if quad_index < self.quad_limit {
+                self.index_topology
+                    .extend_quad_indices(self.winding, quad_index * 4, &mut indices);
+            }
+        }
+        indices
+    }
+
+    /// Gets the total amount of quads in the vertex buffer.
+    #[inline]
+    #[must_use]
+    pub fn quad_limit(&self) -> u32 {
+        self.quad_limit
+    }
+
+    /// Gets the amount of written quads: index of the last set quad plus one.
+    ///
+    /// Quads before the last set one are counted as written even if they were never set.
+    /// Builder created from existing vertices considers all of its quads written.
+    #[inline]
+    #[must_use]
+    pub fn written_quads(&self) -> u32 {
+        self.written_count
+    }
+
+    /// Gets the amount of quads after the last set one, i.e. `quad_limit - written_quads`.
+    #[inline]
+    #[must_use]
+    pub fn quads_remaining(&self) -> u32 {
+        self.quad_limit.saturating_sub(self.written_count)
+    }
+
+    /// Gets the ratio of used quads to the quad limit, from 0 to 1. Useful for progress reporting.
+    /// Builder with zero quad limit is considered full.
+    #[inline]
+    #[must_use]
+    pub fn fill_ratio(&self) -> f32 {
+        if self.quad_limit == 0 {
+            1.0
+        } else {
+            self.written_count as f32 / self.quad_limit as f32
+        }
+    }
+
+    /// Gets the reference to the vertices which will be stored in a vertex buffer after a `create_mesh` call.
+    ///
     /// Vertices are in clockwise order.
     /// Vertex vec is pre-allocated for the entire `quad_limit` of quads,
     /// with currently unused vertices set to `Vertex::default`.
+    /// Lazy builders, see `new_lazy`, only have vertices up to the furthest set quad.
     #[inline]
     #[must_use]
     pub fn vertices(&self) -> &Vec<TVertex> {
         &self.vertices
     }
 
+    /// Gets the vertices covering only the used quads, see `written_quads`.
+    ///
+    /// This is a prefix of the full `vertices`, so it can be uploaded instead of them to skip unused quads.
+    #[inline]
+    #[must_use]
+    pub fn used_vertices(&self) -> &[TVertex] {
+        &self.vertices[..(self.written_count * self.vertices_per_quad) as usize]
+    }
+
     /// Gets the total amount of vertices in the vertex buffer.
     #[inline]
     #[must_use]
@@ -380,6 +1202,32 @@ where
         self.max_vertices
     }
 
+    /// Gets the amount of indices uploaded by `create_mesh` and similar methods, or 0 for unindexed builders.
+    /// Lazy builders, see `new_lazy`, only count indices of populated quads.
+    #[inline]
+    #[must_use]
+    pub fn index_count(&self) -> u32 {
+        self.mesh_indices()
+            .map_or(0, |indices| indices.len() as u32)
+    }
+
+    /// Gets the amount of triangles drawn by the built mesh, e.g. for profiling or LOD decisions.
+    ///
+    /// Every quad is 2 triangles, no matter whether it uses 4 indexed vertices or 6 unindexed ones.
+    /// All quads up to `quad_limit` are drawn, including unwritten ones, unless omitted by `set_draw_order`
+    /// or not populated yet in lazy builders.
+    #[inline]
+    #[must_use]
+    pub fn triangle_count(&self) -> u32 {
+        match self.mesh_indices() {
+            Some(indices) => {
+                let quad_count = indices.len() as u32 / self.index_topology.indices_per_quad();
+                quad_count * 2
+            }
+            None => self.vertices.len() as u32 / 3,
+        }
+    }
+
     /// Gets the amount of vertices used per single quad: 4 if this builder uses indices, 6 otherwise.
     #[inline]
     #[must_use]
@@ -387,12 +1235,400 @@ where
         self.vertices_per_quad
     }
 
+    /// Gets the range of vertices belonging to the quad at the given index, e.g. for a partial upload
+    /// or direct access to quad vertices. Works for both indexed and unindexed builders.
+    /// Returns `None` if the given quad index is out of range.
+    ///
+    /// Note that lazy builders, see `new_lazy`, may not have vertices in this range populated yet.
+    ///
+    /// * `quad_index` - Index of the quad.
+    #[inline]
+    #[must_use]
+    pub fn vertex_range_of<TIndex: Into<QuadIndex>>(
+        &self,
+        quad_index: TIndex,
+    ) -> Option<Range<usize>> {
+        let quad_index = quad_index.into().0;
+        if quad_index >= self.quad_limit {
+            return None;
+        }
+        let vertices_per_quad = self.vertices_per_quad as usize;
+        let start = quad_index as usize * vertices_per_quad;
+        Some(start..start + vertices_per_quad)
+    }
+
+    /// Gets mutable vertices of the quad at the given index, 4 for indexed builders and 6 otherwise,
+    /// or `None` if the index is out of range. This is an escape hatch for tweaking vertex data
+    /// stabilkon does not model, e.g. custom fields of your own vertex type.
+    ///
+    /// Quad vertices are marked as modified, see `dirty_vertex_range`, and lazy builders populate them first.
+    /// Retained params of the quad may no longer describe it afterwards, so they are reset to `None`.
+    ///
+    /// * `quad_index` - Index of the quad.
+    pub fn quad_vertices_mut<TIndex: Into<QuadIndex>>(
+        &mut self,
+        quad_index: TIndex,
+    ) -> Option<&mut [TVertex]> {
+        let quad_index = quad_index.into().0;
+        let range = self.vertex_range_of(quad_index)?;
+        self.populate_vertices(range.end);
+        self.mark_dirty(range.clone());
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params[quad_index as usize] = None;
+        }
+        Some(&mut self.vertices[range])
+    }
+
     #[inline]
-    /// Sets all added quad vertices to a default vertex data.
+    /// Sets all added quad vertices to a blank vertex data and resets written quad count.
     pub fn clear(&mut self) {
         for item in &mut self.vertices {
-            *item = unsafe { std::mem::MaybeUninit::zeroed().assume_init() };
+            *item = self.blank_vertex.clone();
+        }
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params.fill(None);
+        }
+        self.written_count = 0;
+        self.mark_dirty(0..self.vertices.len());
+    }
+
+    /// Brings the builder back to its freshly constructed state without reallocating, e.g. to refill it
+    /// with `push` every frame. Unlike `clear`, the modified vertices range is emptied as well,
+    /// see `dirty_vertex_range`, and a lazy builder drops its populated vertices while keeping their capacity.
+    pub fn reset(&mut self) {
+        if self.lazy {
+            self.vertices.clear();
+        } else {
+            self.vertices.fill(self.blank_vertex.clone());
+        }
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params.fill(None);
+        }
+        self.written_count = 0;
+        self.dirty_vertices = None;
+    }
+
+    /// Sets vertices of the quad at the given index to a blank vertex data, so it becomes a degenerate
+    /// invisible quad, e.g. when a tile is removed. Written quad count is kept as is.
+    /// Returns true if the quad was cleared; false if the given quad index was out of range.
+    ///
+    /// * `quad_index` - Index of the quad to clear.
+    #[inline]
+    pub fn clear_quad<TIndex: Into<QuadIndex>>(&mut self, quad_index: TIndex) -> bool {
+        self.clear_range(quad_index, 1)
+    }
+
+    /// Sets vertices of `count` quads starting at the given index to a blank vertex data, see `clear_quad`.
+    /// Returns true if the quads were cleared; false if the range exceeds `quad_limit`, in which case
+    /// nothing is changed.
+    ///
+    /// * `start_quad_index` - Index of the first quad to clear.
+    /// * `count` - Amount of quads to clear.
+    pub fn clear_range<TIndex: Into<QuadIndex>>(
+        &mut self,
+        start_quad_index: TIndex,
+        count: u32,
+    ) -> bool {
+        let start_quad_index = start_quad_index.into().0;
+        match start_quad_index.checked_add(count) {
+            Some(end_quad_index) if end_quad_index <= self.quad_limit => {
+                let vertices_per_quad = self.vertices_per_quad as usize;
+                // Quads of a lazy builder which are not populated yet are blank already:
+                let range = (start_quad_index as usize * vertices_per_quad).min(self.vertices.len())
+                    ..(end_quad_index as usize * vertices_per_quad).min(self.vertices.len());
+                self.vertices[range.clone()].fill(self.blank_vertex.clone());
+                self.mark_dirty(range);
+                if let Some(stored_params) = self.stored_params.as_mut() {
+                    stored_params[start_quad_index as usize..end_quad_index as usize].fill(None);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the range of vertices modified since the builder creation or the last `clear_dirty` call,
+    /// or `None` if nothing was modified. Upload just these vertices to update an existing mesh cheaply.
+    ///
+    /// Only vertex changes are tracked: indices changed by `set_draw_order` are not reflected.
+    /// Range is clamped by `resize`, but vertex buffer itself needs to be recreated after growing.
+    #[inline]
+    #[must_use]
+    pub fn dirty_vertex_range(&self) -> Option<Range<usize>> {
+        self.dirty_vertices.clone()
+    }
+
+    /// Resets modified vertices range, e.g. after uploading them to GPU.
+    #[inline]
+    pub fn clear_dirty(&mut self) {
+        self.dirty_vertices = None;
+    }
+
+    /// Widens modified vertices range to include the given vertices.
+    pub(crate) fn mark_dirty(&mut self, vertices: Range<usize>) {
+        if vertices.is_empty() {
+            return;
+        }
+        self.dirty_vertices = Some(match self.dirty_vertices.take() {
+            Some(dirty) => dirty.start.min(vertices.start)..dirty.end.max(vertices.end),
+            None => vertices,
+        });
+    }
+
+    /// Gets offset of the first vertex of the quad at the given index, or `None` if its vertices do not fit
+    /// into `max_vertices`. Arithmetic is checked, so huge indices cannot wrap around into valid offsets.
+    fn checked_vertex_offset(&self, quad_index: u32) -> Option<u32> {
+        let vertices_per_quad = self.vertices_per_quad;
+        let target_offset = quad_index.checked_mul(vertices_per_quad)?;
+        let end = target_offset.checked_add(vertices_per_quad)?;
+        (end <= self.max_vertices).then_some(target_offset)
+    }
+
+    /// Grows vertices of a lazy builder with blank vertex data, so that vertices up to `end` can be written.
+    /// Does nothing for pre-allocated builders, since all their vertices are populated already.
+    fn populate_vertices(&mut self, end: usize) {
+        let start = self.vertices.len();
+        if end > start {
+            self.vertices.resize(end, self.blank_vertex.clone());
+            self.mark_dirty(start..end);
+        }
+    }
+
+    /// Gets indices to be uploaded along with `vertices`: lazy builders use only `used_indices`,
+    /// since the rest of indices refers to vertices which are not populated yet.
+    fn mesh_indices(&self) -> Option<&[u32]> {
+        match self.indices.as_deref() {
+            Some(_) if self.lazy => Some(self.used_indices()),
+            indices => indices,
+        }
+    }
+
+    /// Sets all added quad vertices to a blank vertex data, but keeps written quad count,
+    /// so quads pushed afterwards are still appended after the previously used ones.
+    pub fn clear_keeping_cursor(&mut self) {
+        let written_count = self.written_count;
+        self.clear();
+        self.written_count = written_count;
+    }
+
+    /// Gets whether this builder retains draw params of the set quads.
+    #[inline]
+    #[must_use]
+    pub fn retains_params(&self) -> bool {
+        self.stored_params.is_some()
+    }
+
+    /// Enables or disables retaining of draw params the quads are set with.
+    /// Retained params can be read back with `get_quad_params`, which is required for a faithful
+    /// round-trip of e.g. rotated `DetailedParams`: vertices alone lose the original transform.
+    /// Params of quads set before enabling are not known; disabling drops all retained params.
+    /// Only built-in draw params are retained, see `QuadDrawParams::to_stored_params`.
+    pub fn set_retain_params(&mut self, retain: bool) {
+        if !retain {
+            self.stored_params = None;
+        } else if self.stored_params.is_none() {
+            self.stored_params = Some(vec![None; self.quad_limit as usize]);
+        }
+    }
+
+    /// Gets the draw params the quad at the given index was set with.
+    /// Returns `None` if params are not retained, quad was not set since retaining was enabled,
+    /// or quad index is out of range.
+    #[inline]
+    #[must_use]
+    pub fn get_quad_params<TIndex: Into<QuadIndex>>(
+        &self,
+        quad_index: TIndex,
+    ) -> Option<&StoredParams> {
+        let quad_index = quad_index.into().0;
+        self.stored_params
+            .as_ref()
+            .and_then(|stored_params| stored_params.get(quad_index as usize))
+            .and_then(Option::as_ref)
+    }
+
+    /// Changes the quad limit, keeping texture size and other settings.
+    /// Quads up to the smaller of old and new limits are preserved, new quads are set to a blank vertex data.
+    /// Lazy builders, see `new_lazy`, only drop populated vertices past the new limit and do not grow.
+    /// Indices are regenerated for indexed builders, so custom draw order set by `set_draw_order` is reset.
+    ///
+    /// * `new_quad_limit` - New amount of quads in the built static mesh.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `new_quad_limit` is too high, including u16 indices of a triangle strip colliding
+    /// with the restart index, in which case nothing is changed.
+    pub fn resize(&mut self, new_quad_limit: u32) -> Result<()> {
+        let max_vertices = total_vertices_in_quads(new_quad_limit, self.use_indices)?;
+        if self.indices_u16.is_some() {
+            ensure_u16_indexable_as(new_quad_limit, self.index_topology)?;
+        }
+        if let Some(indices) = self.indices.as_mut() {
+            fill_quad_indices(
+                indices,
+                new_quad_limit,
+                0,
+                self.winding,
+                self.index_topology,
+            )?;
+        }
+        self.sync_u16_indices();
+        if self.lazy {
+            self.vertices.truncate(max_vertices as usize);
+        } else {
+            self.vertices
+                .resize(max_vertices as usize, self.blank_vertex.clone());
+        }
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params.resize(new_quad_limit as usize, None);
+        }
+        self.quad_limit = new_quad_limit;
+        self.max_vertices = max_vertices;
+        self.written_count = self.written_count.min(new_quad_limit);
+        self.dirty_vertices = self
+            .dirty_vertices
+            .take()
+            .map(|dirty| dirty.start..dirty.end.min(max_vertices as usize))
+            .filter(|dirty| !dirty.is_empty());
+        Ok(())
+    }
+
+    /// Converts an indexed builder into an unindexed one, expanding 4 vertices of every quad into 6
+    /// by `WindingOrder::quad_index_pattern`, so every triangle owns its vertices.
+    /// This avoids interpolation artifacts at quad seams in renderers computing lighting from vertex data.
+    /// Unindexed builders are returned as is.
+    ///
+    /// Quad indices, retained params and written quads are kept, but custom draw order set by `set_draw_order`
+    /// is not carried over, so set it again if needed. All vertices are marked as modified.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if expanded vertices of all quads do not fit into u32.
+    pub fn to_unindexed(mut self) -> Result<Self> {
+        if !self.use_indices {
+            return Ok(self);
+        }
+        let max_vertices = total_vertices_in_quads(self.quad_limit, false)?;
+        let pattern = self.winding.quad_index_pattern();
+        // Lazy builders expand only populated quads:
+        let mut vertices = Vec::with_capacity(self.vertices.len() / 4 * 6);
+        for quad_vertices in self.vertices.chunks_exact(4) {
+            vertices.extend(
+                pattern
+                    .iter()
+                    .map(|&offset| quad_vertices[offset as usize].clone()),
+            );
+        }
+        self.vertices = vertices;
+        self.indices = None;
+        self.indices_u16 = None;
+        self.use_indices = false;
+        self.vertices_per_quad = vertices_per_quad(false);
+        self.max_vertices = max_vertices;
+        self.index_topology = IndexTopology::TriangleList;
+        self.dirty_vertices = None;
+        self.mark_dirty(0..self.vertices.len());
+        Ok(self)
+    }
+
+    /// Converts an unindexed builder into an indexed one, the inverse of `to_unindexed`.
+    /// Every unindexed quad repeats two of its corners, so they are collapsed back into 4 vertices per quad
+    /// and indices are regenerated, reducing vertex memory by a third. Indexed builders are returned as is.
+    ///
+    /// Quad indices, retained params and written quads are kept. All vertices are marked as modified.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if indices of all quads do not fit into u32.
+    pub fn to_indexed(mut self) -> Result<Self> {
+        if self.use_indices {
+            return Ok(self);
+        }
+        let mut indices = Vec::new();
+        fill_quad_indices(
+            &mut indices,
+            self.quad_limit,
+            0,
+            self.winding,
+            IndexTopology::TriangleList,
+        )?;
+        let max_vertices = total_vertices_in_quads(self.quad_limit, true)?;
+        // Each corner is taken from its first occurrence in the unindexed quad:
+        let pattern = self.winding.quad_index_pattern();
+        let corner_offsets: [usize; 4] = std::array::from_fn(|corner| {
+            pattern
+                .iter()
+                .position(|&offset| offset as usize == corner)
+                .unwrap_or(corner)
+        });
+        // Lazy builders collapse only populated quads:
+        let mut vertices = Vec::with_capacity(self.vertices.len() / 6 * 4);
+        for quad_vertices in self.vertices.chunks_exact(6) {
+            vertices.extend(
+                corner_offsets
+                    .iter()
+                    .map(|&offset| quad_vertices[offset].clone()),
+            );
+        }
+        self.vertices = vertices;
+        self.indices = Some(indices);
+        self.use_indices = true;
+        self.vertices_per_quad = vertices_per_quad(true);
+        self.max_vertices = max_vertices;
+        self.index_topology = IndexTopology::TriangleList;
+        self.dirty_vertices = None;
+        self.mark_dirty(0..self.vertices.len());
+        Ok(self)
+    }
+
+    /// Copies written quads of the other builder right after written quads of this one,
+    /// so both can be drawn with a single mesh. Both builders must share the same texture atlas.
+    ///
+    /// If combined written quads do not fit into `quad_limit`, this builder is grown with `resize`,
+    /// so its indices are regenerated and custom draw order set by `set_draw_order` is reset.
+    /// Retained params of the other builder are copied as well if this builder retains params.
+    ///
+    /// * `other` - Builder to copy written quads from.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if texture sizes differ, since UVs are texture-relative; if builders differ in indices usage
+    /// winding or index topology; or if the combined quad count is too high. Nothing is changed in this case.
+    pub fn append(&mut self, other: &MeshFromQuads<TVertex>) -> Result<()> {
+        ensure!(
+            self.texture_size == other.texture_size,
+            TextureSizeMismatch {
+                size: self.texture_size,
+                other_size: other.texture_size,
+            }
+        );
+        ensure!(
+            self.use_indices == other.use_indices
+                && self.winding == other.winding
+                && self.index_topology == other.index_topology,
+            VertexLayoutMismatch
+        );
+        let combined_count = match self.written_count.checked_add(other.written_count) {
+            Some(combined_count) => combined_count,
+            None => return QuadCountIsTooLarge {}.fail(),
+        };
+        if combined_count > self.quad_limit {
+            self.resize(combined_count)?;
+        }
+
+        let offset = (self.written_count * self.vertices_per_quad) as usize;
+        let appended_vertices = other.used_vertices();
+        self.populate_vertices(offset + appended_vertices.len());
+        self.vertices[offset..offset + appended_vertices.len()].clone_from_slice(appended_vertices);
+        self.mark_dirty(offset..offset + appended_vertices.len());
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            for quad_index in 0..other.written_count {
+                stored_params[(self.written_count + quad_index) as usize] =
+                    other.get_quad_params(quad_index).cloned();
+            }
         }
+        self.written_count = combined_count;
+        Ok(())
     }
 
     /// Consumes this builder and returns its vertices and indices.
@@ -406,75 +1642,1846 @@ where
 
     /// Changes quad at the given index to use the specified draw params.
     /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
-    pub fn set<T: QuadDrawParams>(&mut self, quad_index: u32, draw_params: &T) -> bool {
-        let vertices_per_quad = self.vertices_per_quad();
-        let target_offset = quad_index * vertices_per_quad;
-        if target_offset + vertices_per_quad <= self.max_vertices {
-            draw_params.set_vertices(
+    ///
+    /// * `quad_index` - Index of the quad to set, either plain `u32` or `QuadIndex`.
+    ///   Quads start at 0 and end at `limit` - 1.
+    /// * `draw_params` - Quad draw params.
+    pub fn set<TIndex, T>(&mut self, quad_index: TIndex, draw_params: &T) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        T: QuadDrawParams,
+    {
+        let quad_index = quad_index.into().0;
+        if self.use_indices {
+            self.write_quad::<T, true>(quad_index, draw_params)
+        } else {
+            self.write_quad::<T, false>(quad_index, draw_params)
+        }
+    }
+
+    /// Same as `set`, but the quad vertex layout is resolved at compile time: `INDEXED` must match `use_indices`.
+    /// Public setters check indexing once and then call this, so loops over many quads stay branch-free.
+    fn write_quad<T: QuadDrawParams, const INDEXED: bool>(
+        &mut self,
+        quad_index: u32,
+        draw_params: &T,
+    ) -> bool {
+        debug_assert_eq!(INDEXED, self.use_indices);
+        let vertices_per_quad = vertices_per_quad(INDEXED);
+        if let Some(target_offset) = self.checked_vertex_offset(quad_index) {
+            let target = target_offset as usize..(target_offset + vertices_per_quad) as usize;
+            self.populate_vertices(target.end);
+            draw_params.write_vertices::<TVertex, INDEXED>(
                 self.texture_size,
-                self.use_half_pixel_offset,
-                self.use_indices,
-                target_offset as usize,
+                self.vertex_options(),
+                target.start,
                 &mut self.vertices,
             );
+            self.mark_dirty(target);
+            if let Some(stored_params) = self.stored_params.as_mut() {
+                stored_params[quad_index as usize] = draw_params.to_stored_params();
+            }
+            self.written_count = self.written_count.max(quad_index + 1);
             true
         } else {
             false
         }
     }
 
-    /// Changes quad at the given index to use the specified position, color and texture source rectangle.
-    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
-    ///
-    /// * `quad_index` - Infex of the quad to set. Quads start at 0 and end at `limit` - 1.
-    /// * `position` - Quad position, top-left corner.
-    /// * `color` - Quad vertices color.
-    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
-    /// * `flip` - UV flip mode.
-    #[inline]
-    pub fn set_pos_color_source<TColor, TRect, TVec2>(
-        &mut self,
-        quad_index: u32,
-        position: TVec2,
-        color: TColor,
-        source: TRect,
-        flip: UvFlip,
-    ) -> bool
+    /// Sets quads yielded by the given iterator as (quad index, draw params) pairs, see `set`.
+    /// Indexing is checked once for all quads rather than for each one.
+    /// Returns the amount of quads which were in vertices range and set.
+    fn set_each<T, I>(&mut self, quads: I) -> u32
     where
-        TColor: Into<Color>,
-        TRect: Into<Rectangle>,
-        TVec2: Into<Vec2>,
+        T: QuadDrawParams,
+        I: IntoIterator<Item = (u32, T)>,
     {
-        let draw_info = PosColorSource::new(position, color, source, flip);
-        self.set(quad_index, &draw_info)
+        if self.use_indices {
+            self.write_each::<T, I, true>(quads)
+        } else {
+            self.write_each::<T, I, false>(quads)
+        }
     }
 
-    /// Changes quad at the given index to use the specified position, color, size and texture source rectangle.
-    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    /// Same as `set_each`, with indexing resolved at compile time, see `write_quad`.
+    fn write_each<T, I, const INDEXED: bool>(&mut self, quads: I) -> u32
+    where
+        T: QuadDrawParams,
+        I: IntoIterator<Item = (u32, T)>,
+    {
+        let mut set_quads = 0;
+        for (quad_index, draw_params) in quads {
+            if self.write_quad::<T, INDEXED>(quad_index, &draw_params) {
+                set_quads += 1;
+            }
+        }
+        set_quads
+    }
+
+    /// Same as `set`, but reports an out of range quad index as an error instead of a bare `false`,
+    /// so mistakes in fill loops can be propagated with `?`.
     ///
-    /// * `quad_index` - Infex of the quad to set. Quads start at 0 and end at `limit` - 1.
-    /// * `position` - Quad position, top-left corner.
-    /// * `color` - Quad vertices color.
-    /// * `size` - Destination size, used for absolute scaling.
-    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `draw_params` - Quad draw params.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `quad_index` is not less than `quad_limit`, in which case nothing is changed.
+    pub fn try_set<TIndex, T>(&mut self, quad_index: TIndex, draw_params: &T) -> Result<()>
+    where
+        TIndex: Into<QuadIndex>,
+        T: QuadDrawParams,
+    {
+        let quad_index = quad_index.into().0;
+        ensure!(
+            quad_index < self.quad_limit,
+            QuadIndexOutOfRange {
+                index: quad_index,
+                limit: self.quad_limit
+            }
+        );
+        self.set(quad_index, draw_params);
+        Ok(())
+    }
+
+    /// Sets quads at consecutive indices starting from `start_quad_index` to the given draw params,
+    /// e.g. to append quads generated elsewhere without an indexed loop.
+    /// Returns true if all quads were set; false if they do not fit into `quad_limit`, in which case nothing is changed.
+    ///
+    /// * `start_quad_index` - Index of the quad to set the first params to.
+    /// * `params` - Draw params of the quads, in order.
+    pub fn set_slice<TIndex, T>(&mut self, start_quad_index: TIndex, params: &[T]) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        T: QuadDrawParams,
+    {
+        let start_quad_index = start_quad_index.into().0;
+        let fits = u32::try_from(params.len())
+            .ok()
+            .and_then(|count| start_quad_index.checked_add(count))
+            .is_some_and(|end_quad_index| end_quad_index <= self.quad_limit);
+        if !fits {
+            return false;
+        }
+        if self.use_indices {
+            self.write_slice::<T, true>(start_quad_index, params);
+        } else {
+            self.write_slice::<T, false>(start_quad_index, params);
+        }
+        true
+    }
+
+    /// Sets consecutive quads starting at the given index, with indexing resolved at compile time, see `write_quad`.
+    fn write_slice<T: QuadDrawParams, const INDEXED: bool>(
+        &mut self,
+        start_quad_index: u32,
+        params: &[T],
+    ) {
+        for (quad_index, draw_params) in (start_quad_index..).zip(params) {
+            self.write_quad::<T, INDEXED>(quad_index, draw_params);
+        }
+    }
+
+    /// Same as `try_set`, but also reports a texture source extending past texture bounds as an error,
+    /// since such quads silently sample neighboring atlas regions. Use `clamp_source` to fit sources instead.
+    /// Keep in mind that params repeating the texture, e.g. `PosColorTiledSource`, always exceed its bounds.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `draw_params` - Quad draw params.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `quad_index` is not less than `quad_limit` or texture source is out of texture bounds,
+    /// in which case nothing is changed.
+    pub fn checked_set<TIndex, T>(&mut self, quad_index: TIndex, draw_params: &T) -> Result<()>
+    where
+        TIndex: Into<QuadIndex>,
+        T: QuadDrawParams,
+    {
+        const UV_EPSILON: f32 = 1e-6;
+        let quad_index = quad_index.into().0;
+        let mut uv = Vec2 { x: 0.0, y: 0.0 };
+        let mut uv2 = Vec2 { x: 0.0, y: 0.0 };
+        draw_params.uvs(self.texture_size, UvInset::NONE, &mut uv, &mut uv2);
+        let in_bounds = [uv.x, uv.y, uv2.x, uv2.y]
+            .iter()
+            .all(|coordinate| (-UV_EPSILON..=1.0 + UV_EPSILON).contains(coordinate));
+        ensure!(in_bounds, SourceOutOfBounds { quad_index });
+        self.try_set(quad_index, draw_params)
+    }
+
+    /// Appends a quad with the specified draw params right after the last used quad.
+    /// Returns index of the set quad, or `None` if builder is already full.
+    pub fn push_quad<T: QuadDrawParams>(&mut self, draw_params: &T) -> Option<u32> {
+        let quad_index = self.written_count;
+        if self.set(quad_index, draw_params) {
+            Some(quad_index)
+        } else {
+            None
+        }
+    }
+
+    /// Same as `push_quad`, but reports a full builder as an error, so fill loops can propagate it with `?`.
+    /// Quads are pushed at the cursor right after the last used quad, see `written_quads`,
+    /// which advances with every push.
+    /// Returns index of the set quad.
+    ///
+    /// * `draw_params` - Quad draw params.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if all quads up to `quad_limit` are used already, in which case nothing is changed.
+    pub fn push<T: QuadDrawParams>(&mut self, draw_params: &T) -> Result<u32> {
+        let quad_index = self.written_count;
+        self.try_set(quad_index, draw_params)?;
+        Ok(quad_index)
+    }
+
+    /// Moves push cursor back to the first quad, so subsequent pushes overwrite quads from the start.
+    /// Unlike `clear`, vertices are kept as is, but they no longer count as written, see `written_quads`.
+    #[inline]
+    pub fn reset_cursor(&mut self) {
+        self.written_count = 0;
+    }
+
+    /// Changes quad at the given index to use the specified position, color and texture source rectangle.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Infex of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `color` - Quad vertices color.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    #[inline]
+    pub fn set_pos_color_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info = PosColorSource::new(position, color, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes quad at the given index to use the specified position, corner colors and texture source rectangle.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `colors` - Vertex colors for each corner, starting from the top-left corner and going clockwise.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    #[inline]
+    pub fn set_pos_gradient_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        colors: [TColor; 4],
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info = PosGradientSource::new(position, colors, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes quad at the given index to use the specified position, color, size and texture source rectangle.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Infex of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `color` - Quad vertices color.
+    /// * `size` - Destination size, used for absolute scaling.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    #[inline]
+    pub fn set_pos_color_size_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        color: TColor,
+        size: TVec2,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info = PosColorSizeSource::new(position, color, size, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes quad at the given index to use the specified detailed draw params,
+    /// e.g. for rotated or scaled quads. Same as `set`, but takes params by value for convenience.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `params` - Quad draw params.
+    #[inline]
+    pub fn set_detailed<TIndex>(&mut self, quad_index: TIndex, params: DetailedParams) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+    {
+        self.set(quad_index, &params)
+    }
+
+    /// Changes quad at the given index to use the specified position, color, origin, size, scale, rotation
+    /// and texture source rectangle.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `color` - Quad vertices color.
+    /// * `origin` - Offsets position and serves as a rotation center.
+    /// * `size` - Destination size, used for absolute scaling.
+    /// * `scale` - Scale, used for relative scaling.
+    /// * `rotation` - Rotation angle in radians.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn set_pos_color_origin_size_scale_rotation_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        color: TColor,
+        origin: TVec2,
+        size: TVec2,
+        scale: TVec2,
+        rotation: f32,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info =
+            DetailedParams::new(position, color, origin, size, scale, rotation, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes quad at the given index to use the specified corner positions, color and texture source rectangle.
+    /// Corners are used verbatim, so the quad can be any quadrilateral.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `corners` - Quad corner positions, clockwise from the top-left corner.
+    /// * `color` - Quad vertices color.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    #[inline]
+    pub fn set_corners<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        corners: [TVec2; 4],
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info = QuadCorners::new(corners, color, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes quad at the given index to use the specified position, color, size, shear and texture source rectangle.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `color` - Quad vertices color.
+    /// * `size` - Destination size, used for absolute scaling.
+    /// * `skew` - Horizontal and vertical shear factors, see `PosColorSkewSource::skew`.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn set_pos_color_skew_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        color: TColor,
+        size: TVec2,
+        skew: TVec2,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info = PosColorSkewSource::new(position, color, size, skew, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes quad at the given index to use the specified position, color, anchor and texture source rectangle.
+    /// Quad is sized by the source rectangle and placed so that its anchor point lands on `position`.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, where the `anchor` point of the quad is placed.
+    /// * `color` - Quad vertices color.
+    /// * `anchor` - Point of the quad placed at `position`.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    #[inline]
+    pub fn set_pos_color_anchor_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        color: TColor,
+        anchor: Anchor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info = PosColorAnchorSource::new(position, color, anchor, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes quad at the given index to use the specified position, color, size and texture source rectangle
+    /// repeated `tile_count` times along each axis. Texture wrap mode must be set to repeat, see `PosColorTiledSource`.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `color` - Quad vertices color.
+    /// * `size` - Destination size, used for absolute scaling.
+    /// * `tile_count` - How many times the source rectangle is repeated horizontally and vertically.
+    /// * `source` - Texture source rectangle of a single tile.
+    /// * `flip` - UV flip mode, applied to each tile.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn set_pos_color_tiled_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        color: TColor,
+        size: TVec2,
+        tile_count: TVec2,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let draw_info = PosColorTiledSource::new(position, color, size, tile_count, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Changes the tile at the given column and row of a builder created with `for_grid`.
+    /// Tile quad is placed at its grid cell, column and row multiplied by the grid tile size, and has the tile size.
+    /// Returns true if the tile was set; false if the builder has no grid layout or the tile is out of grid bounds.
+    ///
+    /// * `column` - Tile column, starting at 0.
+    /// * `row` - Tile row, starting at 0.
+    /// * `color` - Tile vertices color.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    pub fn set_tile<TColor, TRect>(
+        &mut self,
+        column: u32,
+        row: u32,
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+    {
+        let (grid, quad_index) = match self.grid {
+            Some(grid) => match grid.quad_index(column, row) {
+                Some(quad_index) => (grid, quad_index),
+                None => return false,
+            },
+            None => return false,
+        };
+        let position = Vec2 {
+            x: column as f32 * grid.tile_size.x,
+            y: row as f32 * grid.tile_size.y,
+        };
+        let draw_info = PosColorSizeSource::new(position, color, grid.tile_size, source, flip);
+        self.set(quad_index, &draw_info)
+    }
+
+    /// Sets nine consecutive quads to a nine-slice sprite, which scales without distorting its borders:
+    /// corners keep their source size, edges are stretched along one axis and the center is stretched both ways.
+    /// Quads are set row by row, starting from the top-left corner. Vertices use white color.
+    /// Returns true if all nine quads were set; false if they do not fit into quad limit, in which case nothing is set.
+    ///
+    /// * `first_quad_index` - Index of the quad used for the top-left corner slice.
+    /// * `position` - Nine-slice position, top-left corner.
+    /// * `size` - Destination size of the entire nine-slice. If it is smaller than the borders, edges and center
+    ///   collapse to zero size and corners overlap.
+    /// * `source` - Texture source rectangle of the entire nine-slice sprite.
+    /// * `border` - Left, top, right and bottom insets in source pixels, stored as x, y, z and w respectively.
+    /// * `flip` - UV flip mode, applied to the nine-slice as a whole. Diagonal flip modes are not supported,
+    ///   since they would rotate each slice separately, so false is returned for them.
+    pub fn set_nine_slice<TIndex, TRect, TVec2, TVec4>(
+        &mut self,
+        first_quad_index: TIndex,
+        position: TVec2,
+        size: TVec2,
+        source: TRect,
+        border: TVec4,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+        TVec4: Into<Vec4>,
+    {
+        let first_quad_index = first_quad_index.into().0;
+        match first_quad_index.checked_add(9) {
+            Some(end_quad) if end_quad <= self.quad_limit && !flip.is_diagonal() => {}
+            _ => return false,
+        }
+
+        let position: Vec2 = position.into();
+        let size: Vec2 = size.into();
+        let source: Rectangle = source.into();
+        let border: Vec4 = border.into();
+        let source_x = [
+            source.x,
+            source.x + border.x,
+            source.x + source.z - border.z,
+        ];
+        let source_y = [
+            source.y,
+            source.y + border.y,
+            source.y + source.w - border.w,
+        ];
+        let source_width = [border.x, source.z - border.x - border.z, border.z];
+        let source_height = [border.y, source.w - border.y - border.w, border.w];
+        // Flipped UVs mirror the entire sprite, so slices are taken from the mirrored side of the source:
+        let flip_columns = flip == UvFlip::Horizontal || flip == UvFlip::Both;
+        let flip_rows = flip == UvFlip::None || flip == UvFlip::Horizontal;
+        let source_column = |column: usize| if flip_columns { 2 - column } else { column };
+        let source_row = |row: usize| if flip_rows { 2 - row } else { row };
+
+        let mut width = [
+            source_width[source_column(0)],
+            0.0,
+            source_width[source_column(2)],
+        ];
+        width[1] = (size.x - width[0] - width[2]).max(0.0);
+        let mut height = [
+            source_height[source_row(0)],
+            0.0,
+            source_height[source_row(2)],
+        ];
+        height[1] = (size.y - height[0] - height[2]).max(0.0);
+
+        let white_color = [1.0_f32, 1.0, 1.0, 1.0];
+        let mut quad_index = first_quad_index;
+        let mut y = position.y;
+        for (row, &slice_height) in height.iter().enumerate() {
+            let mut x = position.x;
+            for (column, &slice_width) in width.iter().enumerate() {
+                let slice_column = source_column(column);
+                let slice_row = source_row(row);
+                let slice_source = Rectangle {
+                    x: source_x[slice_column],
+                    y: source_y[slice_row],
+                    z: source_width[slice_column],
+                    w: source_height[slice_row],
+                };
+                let draw_info = PosColorSizeSource::new(
+                    [x, y],
+                    white_color,
+                    [slice_width, slice_height],
+                    slice_source,
+                    flip,
+                );
+                self.set(quad_index, &draw_info);
+                quad_index += 1;
+                x += slice_width;
+            }
+            y += slice_height;
+        }
+        true
+    }
+
+    /// Fills a grid of quads, asking the given closure for the contents of each cell.
+    /// Cell at (`column`, `row`) is set to quad `start_quad + row * columns + column`,
+    /// cells are visited row by row.
+    ///
+    /// Returns the amount of set quads, or `None` if the grid does not fit into `quad_limit`,
+    /// in which case nothing is set.
+    ///
+    /// * `start_quad` - Index of the quad used for the very first cell.
+    /// * `columns` - Amount of grid columns.
+    /// * `rows` - Amount of grid rows.
+    /// * `origin` - Position of the grid top-left corner.
+    /// * `tile_size` - Size of a single grid cell, each quad is scaled to it.
+    /// * `f` - Called with column and row of each cell, should return texture source rectangle, color and UV flip
+    ///   for the cell quad, or `None` to leave the cell quad as is.
+    pub fn fill_grid<TIndex, TVec2, F>(
+        &mut self,
+        start_quad: TIndex,
+        columns: u32,
+        rows: u32,
+        origin: TVec2,
+        tile_size: TVec2,
+        mut f: F,
+    ) -> Option<u32>
+    where
+        TIndex: Into<QuadIndex>,
+        TVec2: Into<Vec2>,
+        F: FnMut(u32, u32) -> Option<(Rectangle, Color, UvFlip)>,
+    {
+        let start_quad = start_quad.into().0;
+        let end_quad = columns
+            .checked_mul(rows)
+            .and_then(|cell_count| start_quad.checked_add(cell_count))?;
+        if end_quad > self.quad_limit {
+            return None;
+        }
+
+        let origin: Vec2 = origin.into();
+        let tile_size: Vec2 = tile_size.into();
+        let cells = (0..rows).flat_map(|row| (0..columns).map(move |column| (column, row)));
+        let quads = (start_quad..)
+            .zip(cells)
+            .filter_map(|(quad_index, (column, row))| {
+                let (source, color, flip) = f(column, row)?;
+                let position = Vec2 {
+                    x: origin.x + column as f32 * tile_size.x,
+                    y: origin.y + row as f32 * tile_size.y,
+                };
+                let draw_info = PosColorSizeSource::new(position, color, tile_size, source, flip);
+                Some((quad_index, draw_info))
+            });
+        Some(self.set_each(quads))
+    }
+
+    /// Fills a grid of quads with the same texture source rectangle and color, e.g. for a base terrain layer.
+    /// Cell at (`column`, `row`) is set to quad `origin_quad_index + row * columns + column`
+    /// and positioned at `world_origin + (column, row) * tile_size`.
+    /// Returns true if all quads were set; false if the grid does not fit into quad limit, in which case nothing is set.
+    ///
+    /// * `origin_quad_index` - Index of the quad used for the very first cell.
+    /// * `columns` - Amount of grid columns.
+    /// * `rows` - Amount of grid rows.
+    /// * `tile_size` - Size of a single grid cell, each quad is scaled to it.
+    /// * `world_origin` - Position of the grid top-left corner.
+    /// * `color` - Vertices color of all quads.
+    /// * `source` - Texture source rectangle of all quads.
     /// * `flip` - UV flip mode.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_grid_uniform<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        origin_quad_index: TIndex,
+        columns: u32,
+        rows: u32,
+        tile_size: TVec2,
+        world_origin: TVec2,
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let color: Color = color.into();
+        let source: Rectangle = source.into();
+        self.fill_grid(
+            origin_quad_index,
+            columns,
+            rows,
+            world_origin,
+            tile_size,
+            |_, _| Some((source, color, flip)),
+        )
+        .is_some()
+    }
+
+    /// Fills quads from a tile map stored as atlas tile indices, e.g. a layer loaded from a map editor.
+    /// Tile at `i` in `tiles` is set to quad `i` and positioned at `world_origin + (i % columns, i / columns) * tile_size`,
+    /// its texture source rectangle is looked up in the given atlas grid.
+    /// Tiles equal to `EMPTY_TILE` or missing from the atlas grid are cleared, see `clear_quad`.
+    /// Returns true if all tiles were set; false if `columns` is 0 or tiles do not fit into quad limit,
+    /// in which case nothing is set.
+    ///
+    /// * `tiles` - Atlas tile indices, row by row.
+    /// * `columns` - Amount of tiles in a single map row.
+    /// * `grid` - Atlas grid resolving tile indices into texture source rectangles.
+    /// * `tile_size` - Size of a single map cell, each quad is scaled to it.
+    /// * `world_origin` - Position of the map top-left corner.
+    /// * `color` - Vertices color of all quads.
+    /// * `flip` - UV flip mode.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_from_tile_indices<TColor, TVec2>(
+        &mut self,
+        tiles: &[u16],
+        columns: u32,
+        grid: &AtlasGrid,
+        tile_size: TVec2,
+        world_origin: TVec2,
+        color: TColor,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TColor: Into<Color>,
+        TVec2: Into<Vec2>,
+    {
+        if columns == 0 || tiles.len() > self.quad_limit as usize {
+            return false;
+        }
+
+        let tile_size: Vec2 = tile_size.into();
+        let world_origin: Vec2 = world_origin.into();
+        let color: Color = color.into();
+        let source_of = |tile: u16| {
+            if tile == EMPTY_TILE {
+                None
+            } else {
+                grid.source_of(u32::from(tile))
+            }
+        };
+        let quads = (0..).zip(tiles).filter_map(|(quad_index, &tile)| {
+            let source = source_of(tile)?;
+            let position = Vec2 {
+                x: world_origin.x + (quad_index % columns) as f32 * tile_size.x,
+                y: world_origin.y + (quad_index / columns) as f32 * tile_size.y,
+            };
+            let draw_info = PosColorSizeSource::new(position, color, tile_size, source, flip);
+            Some((quad_index, draw_info))
+        });
+        self.set_each(quads);
+        // Tiles are disjoint quads, so empty ones can be cleared after the rest were set:
+        for (quad_index, &tile) in (0_u32..).zip(tiles) {
+            if source_of(tile).is_none() {
+                self.clear_quad(quad_index);
+            }
+        }
+        true
+    }
+
+    /// Sets consecutive quads to a filled regular polygon, e.g. a circle approximation for a range indicator.
+    ///
+    /// Polygon is drawn as a triangle fan around its center, and each quad packs two fan triangles:
+    /// its corners are the center and three consecutive polygon points.
+    /// So it consumes exactly `regular_polygon_quad_count(sides)` quads, i.e. `ceil(sides / 2)`.
+    /// For an odd amount of sides, the last quad repeats its third corner as the fourth one,
+    /// padding it with a degenerate triangle which is not rasterized.
+    ///
+    /// UVs are assigned per quad corner just like for any other quad rather than projected onto the polygon,
+    /// so `source` is expected to be a solid-color texture region.
+    ///
+    /// Returns the amount of set quads, or `None` if `sides` is less than 3 or quads do not fit into `quad_limit`,
+    /// in which case nothing is set.
+    ///
+    /// * `start_quad_index` - Index of the first quad used for the polygon.
+    /// * `center` - Polygon center.
+    /// * `radius` - Distance from the center to polygon points.
+    /// * `sides` - Amount of polygon sides, at least 3.
+    /// * `color` - Vertices color.
+    /// * `source` - Texture source rectangle, preferably of a solid color.
+    pub fn set_regular_polygon<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        start_quad_index: TIndex,
+        center: TVec2,
+        radius: f32,
+        sides: u32,
+        color: TColor,
+        source: TRect,
+    ) -> Option<u32>
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let start_quad_index = start_quad_index.into().0;
+        if sides < 3 {
+            return None;
+        }
+        let quad_count = regular_polygon_quad_count(sides);
+        match start_quad_index.checked_add(quad_count) {
+            Some(end_quad_index) if end_quad_index <= self.quad_limit => {}
+            _ => return None,
+        }
+
+        let center: Vec2 = center.into();
+        let color: Color = color.into();
+        let source: Rectangle = source.into();
+        // Points go in the same rotational direction as quad corners, so fan triangles keep builder winding:
+        let step = -std::f32::consts::TAU / sides as f32;
+        let point = |index: u32| {
+            let angle = step * (index % sides) as f32;
+            Vec2 {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        };
+        for quad in 0..quad_count {
+            let first_point = quad * 2;
+            let last_point = (first_point + 2).min(sides);
+            let corners = [
+                center,
+                point(first_point),
+                point(first_point + 1),
+                point(last_point),
+            ];
+            let draw_info = QuadCorners::new(corners, color, source, UvFlip::None);
+            self.set(start_quad_index + quad, &draw_info);
+        }
+        Some(quad_count)
+    }
+
+    /// Changes the order in which quads are drawn.
+    ///
+    /// For indexed meshes, index buffer is rebuilt so quads are drawn in the given order, vertices stay as is.
+    /// For meshes without indices, quad vertices themselves are moved, so the quad at position `i` in `order`
+    /// will have index `i` afterwards.
+    ///
+    /// * `order` - Quad indices in the desired draw order. Repeated indices are ignored.
+    /// * `append_unlisted` - If set to true, quads missing from `order` are drawn after the listed ones
+    ///   in their natural order. If set to false, they are not drawn at all: indices omit them,
+    ///   or their vertices are set to a default vertex data for meshes without indices.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the given quad indices is not less than `quad_limit`, in which case nothing is changed.
+    pub fn set_draw_order<TIndex>(&mut self, order: &[TIndex], append_unlisted: bool) -> Result<()>
+    where
+        TIndex: Into<QuadIndex> + Copy,
+    {
+        let order: Vec<u32> = order.iter().map(|&index| index.into().0).collect();
+        if let Some(&index) = order.iter().find(|&&index| index >= self.quad_limit) {
+            return QuadIndexOutOfRange {
+                index,
+                limit: self.quad_limit,
+            }
+            .fail();
+        }
+
+        let mut listed = vec![false; self.quad_limit as usize];
+        let mut draw_order = Vec::with_capacity(self.quad_limit as usize);
+        for index in order {
+            if !listed[index as usize] {
+                listed[index as usize] = true;
+                draw_order.push(index);
+            }
+        }
+        let listed_count = draw_order.len() as u32;
+        if append_unlisted {
+            draw_order.extend((0..self.quad_limit).filter(|&index| !listed[index as usize]));
+        }
+
+        if self.indices.is_some() {
+            // Lazy builders upload only used indices, so quads drawn by them must be populated:
+            let drawn_quads = draw_order
+                .iter()
+                .take(self.written_count as usize)
+                .max()
+                .map_or(0, |&quad_index| quad_index + 1);
+            self.populate_vertices((drawn_quads * self.vertices_per_quad) as usize);
+            if let Some(indices) = self.indices.as_mut() {
+                indices.clear();
+                for quad_index in draw_order {
+                    self.index_topology
+                        .extend_quad_indices(self.winding, quad_index * 4, indices);
+                }
+            }
+            self.sync_u16_indices();
+        } else {
+            // Any quad can be moved around, so lazy builders need all of them populated:
+            self.populate_vertices((self.quad_limit * self.vertices_per_quad) as usize);
+            let vertices_per_quad = self.vertices_per_quad as usize;
+            let mut vertices = vec![self.blank_vertex.clone(); self.vertices.len()];
+            for (target_index, &quad_index) in draw_order.iter().enumerate() {
+                let source_offset = quad_index as usize * vertices_per_quad;
+                let target_offset = target_index * vertices_per_quad;
+                vertices[target_offset..target_offset + vertices_per_quad].clone_from_slice(
+                    &self.vertices[source_offset..source_offset + vertices_per_quad],
+                );
+            }
+            self.vertices = vertices;
+            self.mark_dirty(0..self.vertices.len());
+            if let Some(stored_params) = self.stored_params.as_mut() {
+                let mut reordered = vec![None; stored_params.len()];
+                for (target_index, &quad_index) in draw_order.iter().enumerate() {
+                    reordered[target_index] = stored_params[quad_index as usize].take();
+                }
+                *stored_params = reordered;
+            }
+            self.written_count = if append_unlisted {
+                self.quad_limit
+            } else {
+                listed_count
+            };
+        }
+        Ok(())
+    }
+
+    /// Reorders written quads, see `written_quads`, by the key computed from their vertices,
+    /// e.g. to draw doodads further down the screen over the ones above them.
+    ///
+    /// Quad vertices themselves are moved, so the quad with the smallest key will have index 0 afterwards.
+    /// Sort is stable, quads with equal keys keep their relative order. Indexed meshes get their indices
+    /// rebuilt in natural order, discarding any order set by `set_draw_order`, so quads are drawn in key order
+    /// regardless of the mesh having indices.
+    ///
+    /// * `key` - Called once with vertices of each written quad, should return its sort key.
+    pub fn sort_quads_by<F, O>(&mut self, mut key: F)
+    where
+        F: FnMut(&[TVertex]) -> O,
+        O: Ord,
+    {
+        let vertices_per_quad = self.vertices_per_quad as usize;
+        let used_vertex_count = self.written_count as usize * vertices_per_quad;
+        let mut sorted_order: Vec<usize> = (0..self.written_count as usize).collect();
+        sorted_order.sort_by_cached_key(|&quad_index| {
+            let offset = quad_index * vertices_per_quad;
+            key(&self.vertices[offset..offset + vertices_per_quad])
+        });
+
+        let mut vertices = Vec::with_capacity(used_vertex_count);
+        for &quad_index in &sorted_order {
+            let offset = quad_index * vertices_per_quad;
+            vertices.extend_from_slice(&self.vertices[offset..offset + vertices_per_quad]);
+        }
+        self.vertices[..used_vertex_count].clone_from_slice(&vertices);
+        self.mark_dirty(0..used_vertex_count);
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            let reordered: Vec<_> = sorted_order
+                .iter()
+                .map(|&quad_index| stored_params[quad_index].take())
+                .collect();
+            stored_params[..reordered.len()].clone_from_slice(&reordered);
+        }
+
+        if let Some(indices) = self.indices.as_mut() {
+            indices.clear();
+            for quad_index in 0..self.quad_limit {
+                self.index_topology
+                    .extend_quad_indices(self.winding, quad_index * 4, indices);
+            }
+            self.sync_u16_indices();
+        }
+    }
+
+    /// Copies `indices` into `indices_u16`, if this builder keeps them.
+    /// Callers must make sure indices fit into u16 beforehand.
+    fn sync_u16_indices(&mut self) {
+        if let (Some(indices), Some(indices_u16)) =
+            (self.indices.as_ref(), self.indices_u16.as_mut())
+        {
+            indices_u16.clear();
+            indices_u16.extend(indices.iter().map(|&index| index as u16));
+        }
+    }
+}
+
+impl<TVertex> MeshFromQuads<TVertex>
+where
+    TVertex: Clone + Default + From<PosUvColor>,
+{
+    /// Creates a mesh builder for an indexed mesh capable of holding exactly `quad_limit` quads.
+    /// Unlike `new`, vertices are filled with `TVertex::default()` instead of zeroed memory,
+    /// so it is safe for any vertex type. Prefer it whenever your vertex type implements `Default`.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `quad_limit` - Amount of quads in the built static mesh. For safest allocations,
+    ///   try not to go over 32 MB of needed VRAM for a single mesh, see `estimate_vram_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or `quad_limit` is too high.
     #[inline]
-    pub fn set_pos_color_size_source<TColor, TRect, TVec2>(
+    pub fn new_default<T: Into<Vec2>>(
+        texture_size: T,
+        use_half_pixel_offset: bool,
+        quad_limit: u32,
+    ) -> Result<Self> {
+        Self::create_with_blank(
+            texture_size,
+            use_half_pixel_offset,
+            quad_limit,
+            true,
+            Vec::new(),
+            None,
+            TVertex::default(),
+            WindingOrder::Clockwise,
+        )
+    }
+}
+
+impl<TVertex> MeshFromQuads<TVertex>
+where
+    TVertex: Clone + PartialEq + From<PosUvColor>,
+{
+    /// Same as `set`, but vertices are only written if they differ from the current quad vertices,
+    /// so re-setting an unchanged quad every frame does not mark it dirty, see `dirty_vertex_range`.
+    /// Returns true if the quad vertices were changed; false if they were already the same
+    /// or the given quad index was out of range.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `draw_params` - Quad draw params.
+    pub fn set_if_changed<TIndex, T>(&mut self, quad_index: TIndex, draw_params: &T) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        T: QuadDrawParams,
+    {
+        let quad_index = quad_index.into().0;
+        if self.use_indices {
+            self.write_quad_if_changed::<T, true>(quad_index, draw_params)
+        } else {
+            self.write_quad_if_changed::<T, false>(quad_index, draw_params)
+        }
+    }
+
+    /// Same as `set_if_changed`, with indexing resolved at compile time, see `write_quad`.
+    fn write_quad_if_changed<T: QuadDrawParams, const INDEXED: bool>(
+        &mut self,
+        quad_index: u32,
+        draw_params: &T,
+    ) -> bool {
+        let vertices_per_quad = vertices_per_quad(INDEXED);
+        let target_offset = match self.checked_vertex_offset(quad_index) {
+            Some(target_offset) => target_offset,
+            None => return false,
+        };
+        self.populate_vertices((target_offset + vertices_per_quad) as usize);
+
+        let mut quad_vertices: [TVertex; 6] = std::array::from_fn(|_| self.blank_vertex.clone());
+        let quad_vertices = &mut quad_vertices[..vertices_per_quad as usize];
+        draw_params.write_vertices::<TVertex, INDEXED>(
+            self.texture_size,
+            self.vertex_options(),
+            0,
+            quad_vertices,
+        );
+        let target = target_offset as usize..(target_offset + vertices_per_quad) as usize;
+        let changed = self.vertices[target.clone()] != *quad_vertices;
+        if changed {
+            self.vertices[target.clone()].clone_from_slice(quad_vertices);
+            self.mark_dirty(target);
+        }
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params[quad_index as usize] = draw_params.to_stored_params();
+        }
+        self.written_count = self.written_count.max(quad_index + 1);
+        changed
+    }
+
+    /// Finds quads which were never written, i.e. all their vertices are still blank, e.g. to catch
+    /// missing tiles left by complex fill logic before uploading the mesh. Blank quads are zeroed, so they
+    /// collapse into a degenerate quad at the origin instead of failing visibly.
+    /// Returns `Ok` if every quad within `quad_limit` was written, or indices of blank quads in ascending order otherwise.
+    ///
+    /// Note that a quad deliberately set to blank vertices, e.g. with `clear_quad`, is reported as well.
+    ///
+    /// This is a debugging aid scanning every vertex, so it is only available in builds with debug assertions.
+    /// Requires `TVertex: PartialEq` to compare vertices with the blank vertex data.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` with indices of blank quads if there are any.
+    #[cfg(debug_assertions)]
+    pub fn assert_fully_written(&self) -> Result<(), Vec<u32>> {
+        let blank_quads: Vec<u32> = self
+            .vertices
+            .chunks_exact(self.vertices_per_quad as usize)
+            .enumerate()
+            .filter(|(_, quad_vertices)| {
+                quad_vertices
+                    .iter()
+                    .all(|vertex| *vertex == self.blank_vertex)
+            })
+            .map(|(quad_index, _)| quad_index as u32)
+            // Quads of a lazy builder which are not populated yet are blank as well:
+            .chain(self.vertices.len() as u32 / self.vertices_per_quad..self.quad_limit)
+            .collect();
+        if blank_quads.is_empty() {
+            Ok(())
+        } else {
+            Err(blank_quads)
+        }
+    }
+}
+
+impl<TVertex> MeshFromQuads<TVertex>
+where
+    TVertex: Clone + From<PosUvColor> + From<PosUvColorZ>,
+{
+    /// Changes quad at the given index to use the specified position, depth, color and texture source rectangle.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// Depth params cannot be retained, so retained params of the quad are reset to `None`.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `z` - Depth applied to all quad vertices.
+    /// * `color` - Quad vertices color.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    pub fn set_pos_color_source_z<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        z: f32,
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let quad_index = quad_index.into().0;
+        let vertices_per_quad = self.vertices_per_quad();
+        if let Some(target_offset) = self.checked_vertex_offset(quad_index) {
+            self.populate_vertices((target_offset + vertices_per_quad) as usize);
+            PosColorSourceZ::new(position, z, color, source, flip).set_vertices_z(
+                self.texture_size,
+                self.vertex_options(),
+                target_offset as usize,
+                &mut self.vertices,
+            );
+            self.mark_dirty(target_offset as usize..(target_offset + vertices_per_quad) as usize);
+            if let Some(stored_params) = self.stored_params.as_mut() {
+                stored_params[quad_index as usize] = None;
+            }
+            self.written_count = self.written_count.max(quad_index + 1);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<TVertex> MeshFromQuads<TVertex>
+where
+    TVertex: Clone + From<PosUvColor> + From<PosUvColorTex>,
+{
+    /// Changes quad at the given index to use the specified position, texture index, color and texture source rectangle.
+    /// Returns true if the given quad index was in vertices range and vertices were set correctly; false otherwise.
+    ///
+    /// Texture index params cannot be retained, so retained params of the quad are reset to `None`.
+    ///
+    /// * `quad_index` - Index of the quad to set. Quads start at 0 and end at `limit` - 1.
+    /// * `position` - Quad position, top-left corner.
+    /// * `tex_index` - Index of the texture to sample, applied to all quad vertices.
+    /// * `color` - Quad vertices color.
+    /// * `source` - Texture source rectangle. Along with `flip`, determines which part of the texture will drawn.
+    /// * `flip` - UV flip mode.
+    pub fn set_pos_color_tex_source<TIndex, TColor, TRect, TVec2>(
+        &mut self,
+        quad_index: TIndex,
+        position: TVec2,
+        tex_index: f32,
+        color: TColor,
+        source: TRect,
+        flip: UvFlip,
+    ) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+        TRect: Into<Rectangle>,
+        TVec2: Into<Vec2>,
+    {
+        let quad_index = quad_index.into().0;
+        let vertices_per_quad = self.vertices_per_quad();
+        if let Some(target_offset) = self.checked_vertex_offset(quad_index) {
+            self.populate_vertices((target_offset + vertices_per_quad) as usize);
+            PosColorTexSource::new(position, tex_index, color, source, flip).set_vertices_tex(
+                self.texture_size,
+                self.vertex_options(),
+                target_offset as usize,
+                &mut self.vertices,
+            );
+            self.mark_dirty(target_offset as usize..(target_offset + vertices_per_quad) as usize);
+            if let Some(stored_params) = self.stored_params.as_mut() {
+                stored_params[quad_index as usize] = None;
+            }
+            self.written_count = self.written_count.max(quad_index + 1);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<TVertex> MeshFromQuads<TVertex>
+where
+    TVertex: Clone + From<PosUvColor>,
+    PosUvColor: From<TVertex>,
+{
+    /// Reconstructs position, color, texture source rectangle and UV flip of the quad at the given index
+    /// from its vertices. Returns `None` if the given quad index is out of range
+    /// or the quad is not populated yet in a lazy builder, see `new_lazy`.
+    ///
+    /// Only axis-aligned quads can be reconstructed faithfully: position is taken from the top-left corner
+    /// and color from its vertex, so e.g. rotation or per-corner data is lost.
+    /// Diagonal UV flip modes are not recognized either, such quads are read back with a mirroring flip mode.
+    /// Use `get_quad_params` with retained params when exact draw params are needed.
+    #[must_use]
+    pub fn get_quad<TIndex: Into<QuadIndex>>(&self, quad_index: TIndex) -> Option<PosColorSource> {
+        let quad_index = quad_index.into().0;
+        if quad_index >= self.quad_limit {
+            return None;
+        }
+        let offset = (quad_index * self.vertices_per_quad) as usize;
+        let quad_vertices = self
+            .vertices
+            .get(offset..offset + self.vertices_per_quad as usize)?;
+        Some(self.read_quad(quad_vertices))
+    }
+
+    /// Iterates over all written quads, see `written_quads`, reconstructing each one just like `get_quad`.
+    /// Quads are yielded in their index order, so the first item describes quad 0.
+    pub fn quads(&self) -> impl Iterator<Item = PosColorSource> + '_ {
+        self.used_vertices()
+            .chunks_exact(self.vertices_per_quad as usize)
+            .map(|quad_vertices| self.read_quad(quad_vertices))
+    }
+
+    /// Reconstructs draw params of a quad from its vertices, see `get_quad`.
+    fn read_quad(&self, quad_vertices: &[TVertex]) -> PosColorSource {
+        // Both indexed and unindexed layouts start with the top-left corner and have
+        // the bottom-right corner as their third vertex.
+        let top_left = PosUvColor::from(quad_vertices[0].clone());
+        let bottom_right = PosUvColor::from(quad_vertices[2].clone());
+
+        let horizontal = top_left.uv.x > bottom_right.uv.x;
+        let vertical = top_left.uv.y < bottom_right.uv.y;
+        let flip = match (horizontal, vertical) {
+            (false, false) => UvFlip::None,
+            (true, false) => UvFlip::Horizontal,
+            (false, true) => UvFlip::Vertical,
+            (true, true) => UvFlip::Both,
+        };
+
+        let inset = self.uv_inset;
+        let (x, width) = source_side_from_uv_span(
+            top_left.uv.x.min(bottom_right.uv.x) * self.texture_size.x,
+            top_left.uv.x.max(bottom_right.uv.x) * self.texture_size.x,
+            inset.left,
+            inset.right,
+        );
+        let (y, height) = source_side_from_uv_span(
+            top_left.uv.y.min(bottom_right.uv.y) * self.texture_size.y,
+            top_left.uv.y.max(bottom_right.uv.y) * self.texture_size.y,
+            inset.top,
+            inset.bottom,
+        );
+        let source = Rectangle {
+            x,
+            y,
+            z: width,
+            w: height,
+        };
+        PosColorSource::new(top_left.position, top_left.color, source, flip)
+    }
+
+    /// Changes color of all vertices of an already written quad, see `written_quads`,
+    /// keeping their positions and UVs intact. Color is converted according to `alpha_mode`.
+    /// Returns true if the quad was changed; false if it was not written yet.
+    ///
+    /// Retained params of the quad no longer describe it afterwards, so they are reset to `None`.
+    ///
+    /// * `quad_index` - Index of the quad to recolor.
+    /// * `color` - New vertices color.
+    pub fn set_quad_color<TIndex, TColor>(&mut self, quad_index: TIndex, color: TColor) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TColor: Into<Color>,
+    {
+        let color = self.alpha_mode.convert(color.into());
+        self.update_written_quad(quad_index.into().0, |vertex| vertex.color = color)
+    }
+
+    /// Scales alpha of all vertices of an already written quad, e.g. to fade a tile in or out,
+    /// keeping their RGB, positions and UVs intact. Unlike `set_quad_color`, per-vertex colors are preserved.
+    /// Note that premultiplied RGB is not scaled along, see `alpha_mode`.
+    /// Returns true if the quad was changed; false if it was not written yet.
+    ///
+    /// Retained params of the quad no longer describe it afterwards, so they are reset to `None`.
+    ///
+    /// * `quad_index` - Index of the quad to fade.
+    /// * `alpha` - Factor the current alpha is multiplied by, clamped to 0..1.
+    pub fn set_quad_alpha<TIndex: Into<QuadIndex>>(
         &mut self,
-        quad_index: u32,
-        position: TVec2,
-        color: TColor,
-        size: TVec2,
-        source: TRect,
-        flip: UvFlip,
+        quad_index: TIndex,
+        alpha: f32,
+    ) -> bool {
+        let alpha = alpha.clamp(0.0, 1.0);
+        self.update_written_quad(quad_index.into().0, |vertex| vertex.color.w *= alpha)
+    }
+
+    /// Translates all vertices of an already written quad by the given offset, keeping their UVs and colors intact.
+    /// Returns true if the quad was moved; false if it was not written yet.
+    ///
+    /// Retained params of the quad no longer describe it afterwards, so they are reset to `None`.
+    ///
+    /// * `quad_index` - Index of the quad to move.
+    /// * `delta` - Offset added to every vertex position.
+    pub fn move_quad<TIndex, TVec2>(&mut self, quad_index: TIndex, delta: TVec2) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TVec2: Into<Vec2>,
+    {
+        let delta: Vec2 = delta.into();
+        self.update_written_quad(quad_index.into().0, |vertex| {
+            vertex.position.x += delta.x;
+            vertex.position.y += delta.y;
+        })
+    }
+
+    /// Moves an already written quad so its first corner, usually the top-left one, ends up at the given position.
+    /// Quad shape, UVs and colors are kept intact, see `move_quad`.
+    /// Returns true if the quad was moved; false if it was not written yet.
+    ///
+    /// * `quad_index` - Index of the quad to move.
+    /// * `position` - New position of the first quad corner.
+    pub fn set_quad_position<TIndex, TVec2>(&mut self, quad_index: TIndex, position: TVec2) -> bool
+    where
+        TIndex: Into<QuadIndex>,
+        TVec2: Into<Vec2>,
+    {
+        let quad_index = quad_index.into().0;
+        if quad_index >= self.written_count {
+            return false;
+        }
+        // Both indexed and unindexed layouts start with the first corner:
+        let offset = (quad_index * self.vertices_per_quad) as usize;
+        let current = PosUvColor::from(self.vertices[offset].clone()).position;
+        let position: Vec2 = position.into();
+        self.move_quad(
+            quad_index,
+            Vec2 {
+                x: position.x - current.x,
+                y: position.y - current.y,
+            },
+        )
+    }
+
+    /// Copies vertices of the quad at `source_index` into the quad at `target_index`, translated so that
+    /// the first corner, usually the top-left one, ends up at the given position. UVs and colors are kept intact,
+    /// e.g. to scatter identical decorations without recalculating them.
+    /// Returns true if the quad was copied; false if either quad index is out of range
+    /// or the source quad is not populated yet in a lazy builder, see `new_lazy`.
+    ///
+    /// Retained params of the target quad are reset to `None`, since they would not describe the copy.
+    ///
+    /// * `source_index` - Index of the quad to copy.
+    /// * `target_index` - Index of the quad to overwrite with the copy. Quads start at 0 and end at `limit` - 1.
+    /// * `new_position` - Position of the first copied quad corner.
+    pub fn clone_quad<TIndex, TVec2>(
+        &mut self,
+        source_index: TIndex,
+        target_index: TIndex,
+        new_position: TVec2,
     ) -> bool
     where
-        TColor: Into<Color>,
-        TRect: Into<Rectangle>,
+        TIndex: Into<QuadIndex>,
         TVec2: Into<Vec2>,
     {
-        let draw_info = PosColorSizeSource::new(position, color, size, source, flip);
-        self.set(quad_index, &draw_info)
+        let source_index = source_index.into().0;
+        let target_index = target_index.into().0;
+        if source_index >= self.quad_limit || target_index >= self.quad_limit {
+            return false;
+        }
+        let vertices_per_quad = self.vertices_per_quad as usize;
+        let source_offset = source_index as usize * vertices_per_quad;
+        let target_offset = target_index as usize * vertices_per_quad;
+        if source_offset + vertices_per_quad > self.vertices.len() {
+            return false;
+        }
+        self.populate_vertices(target_offset + vertices_per_quad);
+        // Both indexed and unindexed layouts start with the first corner:
+        let current = PosUvColor::from(self.vertices[source_offset].clone()).position;
+        let new_position: Vec2 = new_position.into();
+        let delta = Vec2 {
+            x: new_position.x - current.x,
+            y: new_position.y - current.y,
+        };
+        for vertex_index in 0..vertices_per_quad {
+            let mut pos_uv_color =
+                PosUvColor::from(self.vertices[source_offset + vertex_index].clone());
+            pos_uv_color.position.x += delta.x;
+            pos_uv_color.position.y += delta.y;
+            self.vertices[target_offset + vertex_index] = TVertex::from(pos_uv_color);
+        }
+        self.mark_dirty(target_offset..target_offset + vertices_per_quad);
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params[target_index as usize] = None;
+        }
+        self.written_count = self.written_count.max(target_index + 1);
+        true
+    }
+
+    /// Applies the given change to every vertex of an already written quad, going through `PosUvColor`.
+    /// Returns false if the quad was not written yet.
+    fn update_written_quad<F>(&mut self, quad_index: u32, mut change: F) -> bool
+    where
+        F: FnMut(&mut PosUvColor),
+    {
+        if quad_index >= self.written_count {
+            return false;
+        }
+        let vertices_per_quad = self.vertices_per_quad as usize;
+        let offset = quad_index as usize * vertices_per_quad;
+        for vertex in &mut self.vertices[offset..offset + vertices_per_quad] {
+            let mut pos_uv_color = PosUvColor::from(vertex.clone());
+            change(&mut pos_uv_color);
+            *vertex = TVertex::from(pos_uv_color);
+        }
+        self.mark_dirty(offset..offset + vertices_per_quad);
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params[quad_index as usize] = None;
+        }
+        true
+    }
+
+    /// Calculates the axis-aligned bounding box of all written quads, see `written_quads`.
+    /// Returns minimum and maximum corners, or `None` if no quads were written.
+    ///
+    /// Every vertex position is accounted for, so rotated quads are enclosed entirely.
+    #[must_use]
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        positions_bounds(
+            self.used_vertices()
+                .iter()
+                .map(|vertex| PosUvColor::from(vertex.clone()).position),
+        )
+    }
+
+    /// Finds written quads, see `written_quads`, whose bounding boxes intersect the given view rectangle,
+    /// e.g. to draw only the on-screen part of a huge mesh with `collect_indices_for`.
+    /// Returns quad indices in ascending order.
+    ///
+    /// * `view_min` - Minimum corner of the view rectangle.
+    /// * `view_max` - Maximum corner of the view rectangle. Quads touching view edges are considered visible.
+    #[must_use]
+    pub fn visible_quad_indices<TVec2: Into<Vec2>>(
+        &self,
+        view_min: TVec2,
+        view_max: TVec2,
+    ) -> Vec<u32> {
+        let view_min: Vec2 = view_min.into();
+        let view_max: Vec2 = view_max.into();
+        self.used_vertices()
+            .chunks_exact(self.vertices_per_quad as usize)
+            .enumerate()
+            .filter_map(|(quad_index, quad_vertices)| {
+                let (min, max) = positions_bounds(
+                    quad_vertices
+                        .iter()
+                        .map(|vertex| PosUvColor::from(vertex.clone()).position),
+                )?;
+                let visible = min.x <= view_max.x
+                    && max.x >= view_min.x
+                    && min.y <= view_max.y
+                    && max.y >= view_min.y;
+                visible.then_some(quad_index as u32)
+            })
+            .collect()
+    }
+
+    /// Finds the topmost written quad, see `written_quads`, containing the given point, e.g. for mouse picking.
+    /// Quads written at later indices are drawn on top, so the highest matching quad index is returned.
+    ///
+    /// Point is tested against actual quad corners, so rotated and sheared quads are picked precisely.
+    /// Quads are expected to be convex, which holds for all built-in params except for arbitrary free corners.
+    ///
+    /// * `point` - Point in the same space as vertex positions.
+    #[must_use]
+    pub fn quad_at_point<TVec2: Into<Vec2>>(&self, point: TVec2) -> Option<u32> {
+        let point: Vec2 = point.into();
+        // Unindexed quads repeat the third and the first corners, see `QuadDrawParams::set_vertices`:
+        let corner_offsets = if self.use_indices {
+            [0, 1, 2, 3]
+        } else {
+            [0, 1, 2, 4]
+        };
+        self.used_vertices()
+            .chunks_exact(self.vertices_per_quad as usize)
+            .enumerate()
+            .rev()
+            .find(|(_, quad_vertices)| {
+                let corners = corner_offsets
+                    .map(|offset| PosUvColor::from(quad_vertices[offset].clone()).position);
+                quad_contains_point(corners, point)
+            })
+            .map(|(quad_index, _)| quad_index as u32)
+    }
+
+    /// Multiplies positions of all vertices by the given 3×3 affine matrix in place,
+    /// treating them as homogeneous `(x, y, 1)`. UVs and colors are left untouched.
+    ///
+    /// This mutates stored vertices, so a subsequent `create_mesh` reflects the change.
+    /// Vertices are converted through `PosUvColor`, so vertex data it does not carry is reset.
+    ///
+    /// * `matrix` - Affine transformation, e.g. translation, scale or rotation.
+    pub fn transform_all(&mut self, matrix: mint::ColumnMatrix3<f32>) {
+        for vertex in &mut self.vertices {
+            let mut pos_uv_color = PosUvColor::from(vertex.clone());
+            let position = pos_uv_color.position;
+            pos_uv_color.position = Vec2 {
+                x: matrix.x.x * position.x + matrix.y.x * position.y + matrix.z.x,
+                y: matrix.x.y * position.x + matrix.y.y * position.y + matrix.z.y,
+            };
+            *vertex = TVertex::from(pos_uv_color);
+        }
+        self.mark_dirty(0..self.vertices.len());
+    }
+
+    /// Mirrors positions of all vertices along the given axis in place, e.g. to draw a reflection of an already
+    /// built mesh. UVs and colors are left untouched, so the texture is mirrored along with the quads.
+    ///
+    /// Mirroring reverses triangle winding, so the second and the last distinct corners of every quad are swapped
+    /// to keep front faces consistent with `winding`. Retained params no longer describe quads afterwards,
+    /// so they are reset to `None`. Vertices are converted through `PosUvColor`, see `transform_all`.
+    ///
+    /// * `axis` - Axis along which coordinates are flipped: `Axis::X` flips x coordinates, `Axis::Y` flips y ones.
+    /// * `pivot` - Coordinate of the mirror line, e.g. x of the vertical line for `Axis::X`.
+    pub fn mirror(&mut self, axis: Axis, pivot: f32) {
+        for vertex in &mut self.vertices {
+            let mut pos_uv_color = PosUvColor::from(vertex.clone());
+            match axis {
+                Axis::X => pos_uv_color.position.x = 2.0 * pivot - pos_uv_color.position.x,
+                Axis::Y => pos_uv_color.position.y = 2.0 * pivot - pos_uv_color.position.y,
+            }
+            *vertex = TVertex::from(pos_uv_color);
+        }
+        // Indexed quads are [c1, c2, c3, c4] and unindexed ones are [c1, c2, c3, c3, c4, c1],
+        // so swapping c2 with c4 reverses every triangle:
+        let last_corner = if self.use_indices { 3 } else { 4 };
+        for quad_vertices in self
+            .vertices
+            .chunks_exact_mut(self.vertices_per_quad as usize)
+        {
+            quad_vertices.swap(1, last_corner);
+        }
+        if let Some(stored_params) = self.stored_params.as_mut() {
+            stored_params.fill(None);
+        }
+        self.mark_dirty(0..self.vertices.len());
+    }
+
+    /// Writes all vertices and indices as a Wavefront OBJ mesh, e.g. to inspect generated geometry
+    /// and UVs in a 3D editor while debugging. Positions are written with z = 0.
+    ///
+    /// Every vertex gets both `v` and `vt` lines, so faces refer to positions and UVs by the same index.
+    /// Faces of an indexed mesh are taken from its indices, with triangle strips split into separate triangles;
+    /// otherwise consecutive triples of populated vertices are used, see `new_lazy`.
+    ///
+    /// * `w` - Destination of OBJ text.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if writing to `w` fails.
+    pub fn write_obj<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        for vertex in &self.vertices {
+            let position = PosUvColor::from(vertex.clone()).position;
+            writeln!(w, "v {} {} 0", position.x, position.y)?;
+        }
+        for vertex in &self.vertices {
+            let uv = PosUvColor::from(vertex.clone()).uv;
+            writeln!(w, "vt {} {}", uv.x, uv.y)?;
+        }
+        let write_face = |w: &mut W, triangle: &[u32]| {
+            // OBJ indices are 1-based:
+            let (a, b, c) = (triangle[0] + 1, triangle[1] + 1, triangle[2] + 1);
+            writeln!(w, "f {a}/{a} {b}/{b} {c}/{c}")
+        };
+        match (self.mesh_indices(), self.index_topology) {
+            (Some(indices), IndexTopology::TriangleList) => {
+                for triangle in indices.chunks_exact(3) {
+                    write_face(w, triangle)?;
+                }
+            }
+            (Some(indices), IndexTopology::TriangleStrip) => {
+                // Every quad strip is 4 indices and a restart, odd strip triangle has its first two vertices swapped:
+                for strip in indices.chunks_exact(5) {
+                    write_face(w, &strip[0..3])?;
+                    write_face(w, &[strip[2], strip[1], strip[3]])?;
+                }
+            }
+            (None, _) => {
+                for first_vertex in (0..self.vertices.len() as u32).step_by(3) {
+                    write_face(w, &[first_vertex, first_vertex + 1, first_vertex + 2])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<TVertex> MeshFromQuads<TVertex>
+where
+    TVertex: Clone + From<PosUvColor> + bytemuck::Pod,
+{
+    /// Gets vertices reinterpreted as tightly packed bytes, ready to be uploaded into a raw GPU buffer.
+    ///
+    /// Caller is responsible for matching the vertex layout in their shader.
+    #[inline]
+    #[must_use]
+    pub fn vertices_as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.vertices)
+    }
+
+    /// Gets indices reinterpreted as bytes, each index is a native-endian u32.
+    #[inline]
+    #[must_use]
+    pub fn indices_as_bytes(&self) -> Option<&[u8]> {
+        self.indices
+            .as_ref()
+            .map(|indices| bytemuck::cast_slice(indices.as_slice()))
+    }
+
+    /// Consumes this builder and returns its vertices and indices as bytes,
+    /// see `vertices_as_bytes` and `indices_as_bytes`.
+    #[must_use]
+    pub fn into_bytes(self) -> (Vec<u8>, Option<Vec<u8>>) {
+        (
+            self.vertices_as_bytes().to_vec(),
+            self.indices_as_bytes().map(<[u8]>::to_vec),
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<TVertex> MeshFromQuads<TVertex>
+where
+    TVertex: Clone + From<PosUvColor> + Send + Sync,
+{
+    /// Creates a mesh builder for an indexed mesh holding a grid of quads, filling its cells in parallel.
+    /// This is a parallel version of `fill_grid`, see it for details.
+    /// Cell at (`column`, `row`) is set to quad `row * columns + column`.
+    ///
+    /// * `texture_size` - Size of the texture atlas which will be used by the resulting mesh.
+    /// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+    ///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+    /// * `columns` - Amount of grid columns.
+    /// * `rows` - Amount of grid rows.
+    /// * `origin` - Position of the grid top-left corner.
+    /// * `tile_size` - Size of a single grid cell, each quad is scaled to it.
+    /// * `f` - Called with column and row of each cell, should return texture source rectangle, color and UV flip
+    ///   for the cell quad, or `None` to leave the cell quad unset. Called from several threads.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `texture_size` is < 1 or grid cell count is too high.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_grid_parallel<TSize, TVec2, F>(
+        texture_size: TSize,
+        use_half_pixel_offset: bool,
+        columns: u32,
+        rows: u32,
+        origin: TVec2,
+        tile_size: TVec2,
+        f: F,
+    ) -> Result<Self>
+    where
+        TSize: Into<Vec2>,
+        TVec2: Into<Vec2>,
+        F: Fn(u32, u32) -> Option<(Rectangle, Color, UvFlip)> + Sync,
+    {
+        use rayon::prelude::*;
+
+        let quad_limit = quad_limit_for_map((columns, rows))?;
+        let mut builder = Self::create(texture_size, use_half_pixel_offset, quad_limit, true)?;
+        let origin: Vec2 = origin.into();
+        let tile_size: Vec2 = tile_size.into();
+        let texture_size = builder.texture_size;
+        let options = builder.vertex_options();
+        let last_set_quad = builder
+            .vertices
+            .par_chunks_mut(builder.vertices_per_quad as usize)
+            .enumerate()
+            .filter_map(|(quad_index, quad_vertices)| {
+                let column = quad_index as u32 % columns;
+                let row = quad_index as u32 / columns;
+                let (source, color, flip) = f(column, row)?;
+                let position = Vec2 {
+                    x: origin.x + column as f32 * tile_size.x,
+                    y: origin.y + row as f32 * tile_size.y,
+                };
+                PosColorSizeSource::new(position, color, tile_size, source, flip)
+                    .write_vertices::<TVertex, true>(texture_size, options, 0, quad_vertices);
+                Some(quad_index as u32)
+            })
+            .max();
+        // Same as `fill_grid`, written quads end right after the last set cell:
+        builder.written_count = last_set_quad.map_or(0, |last_set_quad| last_set_quad + 1);
+        Ok(builder)
+    }
+
+    /// Sets every quad in parallel, asking the given closure for its draw params.
+    /// Each quad maps to its own vertex range, so quads are written into disjoint slices without locking.
+    /// Lazy builders, see `new_lazy`, get all their vertices populated beforehand.
+    ///
+    /// * `f` - Called once with the index of each quad, should return its draw params,
+    ///   or `None` to leave the quad as is. Called from several threads.
+    pub fn par_set_each<F>(&mut self, f: F)
+    where
+        F: Fn(u32) -> Option<PosColorSource> + Sync,
+    {
+        if self.use_indices {
+            self.par_write_each::<F, true>(f);
+        } else {
+            self.par_write_each::<F, false>(f);
+        }
+    }
+
+    /// Same as `par_set_each`, with indexing resolved at compile time, see `write_quad`.
+    fn par_write_each<F, const INDEXED: bool>(&mut self, f: F)
+    where
+        F: Fn(u32) -> Option<PosColorSource> + Sync,
+    {
+        use rayon::prelude::*;
+
+        let texture_size = self.texture_size;
+        let options = self.vertex_options();
+        let write_quad = |quad_index: usize, quad_vertices: &mut [TVertex]| {
+            let draw_params = f(quad_index as u32)?;
+            draw_params.write_vertices::<TVertex, INDEXED>(texture_size, options, 0, quad_vertices);
+            Some(draw_params)
+        };
+
+        self.populate_vertices(self.max_vertices as usize);
+        let quad_vertices = self
+            .vertices
+            .par_chunks_mut(vertices_per_quad(INDEXED) as usize)
+            .enumerate();
+        let last_set_quad = if let Some(stored_params) = self.stored_params.as_mut() {
+            quad_vertices
+                .zip(stored_params.par_iter_mut())
+                .filter_map(|((quad_index, quad_vertices), stored_params)| {
+                    let draw_params = write_quad(quad_index, quad_vertices)?;
+                    *stored_params = draw_params.to_stored_params();
+                    Some(quad_index)
+                })
+                .max()
+        } else {
+            quad_vertices
+                .filter_map(|(quad_index, quad_vertices)| {
+                    write_quad(quad_index, quad_vertices).map(|_| quad_index)
+                })
+                .max()
+        };
+        if let Some(last_set_quad) = last_set_quad {
+            self.written_count = self.written_count.max(last_set_quad as u32 + 1);
+            self.mark_dirty(0..(last_set_quad + 1) * self.vertices_per_quad as usize);
+        }
+    }
+}
+
+#[cfg(feature = "tetra")]
+fn create_tetra_mesh(
+    ctx: &mut tetra::Context,
+    texture: tetra::graphics::Texture,
+    texture_size: Vec2,
+    vertices: &[tetra::graphics::mesh::Vertex],
+    indices: Option<&[u32]>,
+) -> tetra::Result<BuiltMesh> {
+    use tetra::graphics::mesh::{IndexBuffer, Mesh, VertexBuffer};
+    let vertex_buffer = VertexBuffer::new(ctx, vertices)?;
+    let mut mesh = if let Some(indices) = indices {
+        Mesh::indexed(vertex_buffer.clone(), IndexBuffer::new(ctx, indices)?)
+    } else {
+        Mesh::new(vertex_buffer.clone())
+    };
+    mesh.set_texture(texture);
+    Ok(BuiltMesh {
+        mesh,
+        vertex_buffer,
+        vertex_count: vertices.len(),
+        index_count: indices.map_or(0, <[u32]>::len),
+        texture_size,
+    })
+}
+
+/// Calculates the axis-aligned bounding box of the given positions as minimum and maximum corners.
+/// Returns `None` if there are no positions.
+fn positions_bounds(mut positions: impl Iterator<Item = Vec2>) -> Option<(Vec2, Vec2)> {
+    let first = positions.next()?;
+    Some(positions.fold((first, first), |(min, max), position| {
+        (
+            Vec2 {
+                x: min.x.min(position.x),
+                y: min.y.min(position.y),
+            },
+            Vec2 {
+                x: max.x.max(position.x),
+                y: max.y.max(position.y),
+            },
+        )
+    }))
+}
+
+/// Checks whether the given point lies within a convex quad given by its corners in either winding.
+/// Points on quad edges are considered inside, while degenerate quads contain no points at all.
+fn quad_contains_point(corners: [Vec2; 4], point: Vec2) -> bool {
+    let mut has_positive = false;
+    let mut has_negative = false;
+    for (corner_index, start) in corners.iter().enumerate() {
+        let end = corners[(corner_index + 1) % corners.len()];
+        let cross =
+            (end.x - start.x) * (point.y - start.y) - (end.y - start.y) * (point.x - start.x);
+        has_positive |= cross > 0.0;
+        has_negative |= cross < 0.0;
+    }
+    has_positive != has_negative
+}
+
+/// Calculates the quad limit needed for a tile map with the given amount of columns and rows.
+///
+/// # Errors
+///
+/// Will return `Err` if tile count overflows u32.
+pub fn quad_limit_for_map(map_tiles: (u32, u32)) -> Result<u32> {
+    match map_tiles.0.checked_mul(map_tiles.1) {
+        Some(quad_limit) => Ok(quad_limit),
+        None => QuadCountIsTooLarge {}.fail(),
     }
 }
 
@@ -484,24 +3491,142 @@ where
 ///
 /// Will return `Err` if `quad_count` multiplied by 6 overflows u32.
 pub fn generate_quad_indices(quad_count: u32) -> Result<Vec<u32>> {
-    let length = match quad_count.checked_mul(6) {
+    generate_quad_indices_offset(quad_count, 0)
+}
+
+/// Generates u16 indices for the given amount of quads, for backends requiring 16-bit index buffers.
+///
+/// # Errors
+///
+/// Will return `Err` if `quad_count` is greater than `MAX_U16_INDEXED_QUADS`,
+/// i.e. the highest index would exceed `u16::MAX`.
+pub fn generate_quad_indices_u16(quad_count: u32) -> Result<Vec<u16>> {
+    ensure_u16_indexable(quad_count)?;
+    let indices = generate_quad_indices(quad_count)?;
+    Ok(indices.into_iter().map(|index| index as u16).collect())
+}
+
+#[inline]
+fn ensure_u16_indexable(quad_count: u32) -> Result<()> {
+    ensure_u16_indexable_as(quad_count, IndexTopology::TriangleList)
+}
+
+/// Same as `ensure_u16_indexable`, but for indices of the given topology: triangle strip restart index
+/// becomes `u16::MAX`, so the very last u16-addressable vertex is off limits for strips.
+#[inline]
+fn ensure_u16_indexable_as(quad_count: u32, index_topology: IndexTopology) -> Result<()> {
+    let max_quads = match index_topology {
+        IndexTopology::TriangleList => MAX_U16_INDEXED_QUADS,
+        IndexTopology::TriangleStrip => MAX_U16_INDEXED_QUADS - 1,
+    };
+    ensure!(quad_count <= max_quads, IndicesExceedU16 { quad_count });
+    Ok(())
+}
+
+/// Generates indices for the given amount of quads, referencing vertices starting at `start_vertex`.
+/// Useful when several quad batches are concatenated into one shared vertex buffer.
+///
+/// * `quad_count` - Amount of quads to generate indices for.
+/// * `start_vertex` - Vertex index of the first vertex of the first quad.
+///
+/// # Errors
+///
+/// Will return `Err` if `quad_count` multiplied by 6 overflows u32
+/// or if `start_vertex` plus vertices of all quads overflows u32.
+pub fn generate_quad_indices_offset(quad_count: u32, start_vertex: u32) -> Result<Vec<u32>> {
+    generate_quad_indices_with_winding(quad_count, start_vertex, WindingOrder::Clockwise)
+}
+
+/// Generates indices for the given amount of quads with the given triangle winding,
+/// referencing vertices starting at `start_vertex`.
+///
+/// * `quad_count` - Amount of quads to generate indices for.
+/// * `start_vertex` - Vertex index of the first vertex of the first quad.
+/// * `winding` - Triangle winding, see `WindingOrder::quad_index_pattern`.
+///
+/// # Errors
+///
+/// Will return `Err` if `quad_count` multiplied by 6 overflows u32
+/// or if `start_vertex` plus vertices of all quads overflows u32.
+pub fn generate_quad_indices_with_winding(
+    quad_count: u32,
+    start_vertex: u32,
+    winding: WindingOrder,
+) -> Result<Vec<u32>> {
+    let mut indices = Vec::new();
+    fill_quad_indices(
+        &mut indices,
+        quad_count,
+        start_vertex,
+        winding,
+        IndexTopology::TriangleList,
+    )?;
+    Ok(indices)
+}
+
+/// Generates triangle strip indices for the given amount of quads: 4 indices per quad
+/// followed by `STRIP_RESTART_INDEX`, so the strip of every quad is separated by primitive restart.
+/// Strip triangles are clockwise, just like the ones from `generate_quad_indices`.
+///
+/// # Errors
+///
+/// Will return `Err` if `quad_count` multiplied by 5 overflows u32
+/// or if vertices of all quads can't be addressed by u32 indices.
+pub fn generate_quad_indices_strip(quad_count: u32) -> Result<Vec<u32>> {
+    let mut indices = Vec::new();
+    fill_quad_indices(
+        &mut indices,
+        quad_count,
+        0,
+        WindingOrder::Clockwise,
+        IndexTopology::TriangleStrip,
+    )?;
+    Ok(indices)
+}
+
+/// Replaces contents of the given index vec with indices for the given amount of quads,
+/// reusing its allocation when possible.
+///
+/// # Errors
+///
+/// Will return `Err` if index count or the last referenced vertex overflows u32,
+/// or if index vec cannot be grown to fit all indices.
+pub(crate) fn fill_quad_indices(
+    indices: &mut Vec<u32>,
+    quad_count: u32,
+    start_vertex: u32,
+    winding: WindingOrder,
+    topology: IndexTopology,
+) -> Result<()> {
+    let length = match quad_count.checked_mul(topology.indices_per_quad()) {
+        Some(length) => length,
+        None => return QuadCountIsTooLarge {}.fail(),
+    };
+    let vertex_count = total_vertices_in_quads(quad_count, true)?;
+    if start_vertex.checked_add(vertex_count).is_none() {
+        return QuadCountIsTooLarge {}.fail();
+    }
+    indices.clear();
+    if indices.try_reserve(length as usize).is_err() {
+        return QuadCountIsTooLarge {}.fail();
+    }
+    for quad_index in 0..quad_count {
+        topology.extend_quad_indices(winding, start_vertex + quad_index * 4, indices);
+    }
+    Ok(())
+}
+
+/// Gets the amount of indices needed to draw given quad count, i.e. 6 per quad.
+///
+/// # Errors
+///
+/// Will return `Err` if `quad_count` multiplied by 6 overflows u32.
+#[inline]
+pub fn total_indices_in_quads(quad_count: u32) -> Result<u32> {
+    match quad_count.checked_mul(6) {
         Some(total_indices) => Ok(total_indices),
         None => QuadCountIsTooLarge {}.fail(),
-    }?;
-    let mut indices = vec![0_u32; length as usize];
-    let mut offset: usize = 0;
-    let mut index_value: u32 = 0;
-    while offset < length as usize {
-        indices[offset] = index_value;
-        indices[offset + 1] = index_value + 1;
-        indices[offset + 2] = index_value + 2;
-        indices[offset + 3] = index_value + 2;
-        indices[offset + 4] = index_value + 3;
-        indices[offset + 5] = index_value;
-        index_value += 4;
-        offset += 6;
     }
-    Ok(indices)
 }
 
 /// Gets the amount of vertices used per single quad: 4 when using indices, 6 otherwise.
@@ -515,6 +3640,78 @@ pub const fn vertices_per_quad(use_indices: bool) -> u32 {
     }
 }
 
+/// Estimates VRAM consumed by a mesh with the given quad limit: its vertex buffer plus u32 index buffer, if any.
+/// Compare the result to `RECOMMENDED_MESH_VRAM_BYTES` to decide whether a map should be split into several meshes.
+///
+/// * `quad_limit` - Amount of quads in the mesh.
+/// * `use_indices` - Whether the mesh is indexed: 4 vertices and 6 indices per quad if true, 6 vertices otherwise.
+#[inline]
+#[must_use]
+pub const fn estimate_vram_bytes<TVertex>(quad_limit: u32, use_indices: bool) -> u64 {
+    let quad_limit = quad_limit as u64;
+    let vertex_bytes =
+        quad_limit * vertices_per_quad(use_indices) as u64 * std::mem::size_of::<TVertex>() as u64;
+    let index_bytes = if use_indices {
+        quad_limit * 6 * std::mem::size_of::<u32>() as u64
+    } else {
+        0
+    };
+    vertex_bytes + index_bytes
+}
+
+/// Splits the given quads into several indexed meshes, each one consuming at most `max_bytes` of VRAM,
+/// see `estimate_vram_bytes`. Builders are filled greedily in order, so only the last one can be partially full.
+/// This automates subdividing large maps, e.g. pass `RECOMMENDED_MESH_VRAM_BYTES` as the budget.
+///
+/// * `all` - Draw params of all quads, in the order they are set.
+/// * `texture_size` - Size of the texture atlas which will be used by the resulting meshes.
+/// * `use_half_pixel_offset` - If set to true, applies [half pixel correction]
+///   (https://docs.microsoft.com/en-us/windows/win32/direct3d9/directly-mapping-texels-to-pixels) directly to UVs.
+/// * `max_bytes` - VRAM budget of a single mesh.
+///
+/// # Errors
+///
+/// Will return `Err` if `texture_size` is < 1 or `max_bytes` cannot fit a single quad.
+pub fn chunk_quads<TVertex, T>(
+    all: Vec<PosColorSource>,
+    texture_size: T,
+    use_half_pixel_offset: bool,
+    max_bytes: u64,
+) -> Result<Vec<MeshFromQuads<TVertex>>>
+where
+    TVertex: Clone + From<PosUvColor>,
+    T: Into<Vec2>,
+{
+    let texture_size = texture_size.into();
+    let quad_bytes = estimate_vram_bytes::<TVertex>(1, true);
+    let quads_per_mesh = (max_bytes / quad_bytes).min(u32::MAX as u64) as usize;
+    ensure!(
+        quads_per_mesh > 0,
+        VramBudgetIsTooSmall {
+            max_bytes,
+            quad_bytes
+        }
+    );
+    all.chunks(quads_per_mesh)
+        .map(|chunk| {
+            MeshFromQuads::from_quads(
+                texture_size,
+                use_half_pixel_offset,
+                true,
+                chunk.iter().cloned(),
+            )
+        })
+        .collect()
+}
+
+/// Gets the amount of quads consumed by `MeshFromQuads::set_regular_polygon` with the given amount of sides,
+/// i.e. `ceil(sides / 2)`.
+#[inline]
+#[must_use]
+pub const fn regular_polygon_quad_count(sides: u32) -> u32 {
+    sides / 2 + sides % 2
+}
+
 /// Gets the amount of vertices needed to draw given quad count.
 ///
 /// # Errors
@@ -0,0 +1,98 @@
+use snafu::ensure;
+
+use crate::{
+    common_types::{PosUvColor, TileGrid, Vec2},
+    draw_params::{AlphaMode, IndexTopology, StoredParams, UvInset, WindingOrder},
+    total_vertices_in_quads, vertices_per_quad, Error, InvalidTextureSize, MeshFromQuads,
+    PartialQuadVertices, VertexBufferIsTooLarge,
+};
+
+/// Serialized state of `MeshFromQuads`, without the values derived from vertex buffer length.
+#[derive(serde::Deserialize)]
+pub(crate) struct MeshFromQuadsData<TVertex> {
+    texture_size: Vec2,
+    uv_inset: UvInset,
+    indices: Option<Vec<u32>>,
+    indices_u16: Option<Vec<u16>>,
+    vertices: Vec<TVertex>,
+    #[serde(default)]
+    quad_limit: u32,
+    use_indices: bool,
+    written_count: u32,
+    alpha_mode: AlphaMode,
+    stored_params: Option<Vec<Option<StoredParams>>>,
+    blank_vertex: TVertex,
+    winding: WindingOrder,
+    #[serde(default)]
+    index_topology: IndexTopology,
+    #[serde(default)]
+    lazy: bool,
+    #[serde(default)]
+    grid: Option<TileGrid>,
+}
+
+impl<TVertex> TryFrom<MeshFromQuadsData<TVertex>> for MeshFromQuads<TVertex>
+where
+    TVertex: From<PosUvColor>,
+{
+    type Error = Error;
+
+    /// Rebuilds quad limit and vertex counts from the loaded vertices, so they are always consistent.
+    /// Lazy builders keep their stored quad limit unless populated vertices exceed it.
+    fn try_from(data: MeshFromQuadsData<TVertex>) -> Result<Self, Self::Error> {
+        ensure!(
+            data.texture_size.x >= 1.0 && data.texture_size.y >= 1.0,
+            InvalidTextureSize {
+                size: data.texture_size
+            }
+        );
+        let vertices_per_quad = vertices_per_quad(data.use_indices);
+        ensure!(
+            u32::try_from(data.vertices.len()).is_ok(),
+            VertexBufferIsTooLarge {
+                length: data.vertices.len()
+            }
+        );
+        ensure!(
+            data.vertices
+                .len()
+                .is_multiple_of(vertices_per_quad as usize),
+            PartialQuadVertices {
+                length: data.vertices.len(),
+                vertices_per_quad
+            }
+        );
+
+        let populated_quads = data.vertices.len() as u32 / vertices_per_quad;
+        let quad_limit = if data.lazy {
+            data.quad_limit.max(populated_quads)
+        } else {
+            populated_quads
+        };
+        let max_vertices = total_vertices_in_quads(quad_limit, data.use_indices)?;
+        let mut stored_params = data.stored_params;
+        if let Some(stored_params) = stored_params.as_mut() {
+            stored_params.resize(quad_limit as usize, None);
+        }
+        Ok(Self {
+            texture_size: data.texture_size,
+            uv_inset: data.uv_inset,
+            indices: data.indices.filter(|_| data.use_indices),
+            indices_u16: data.indices_u16.filter(|_| data.use_indices),
+            vertices: data.vertices,
+            quad_limit,
+            use_indices: data.use_indices,
+            vertices_per_quad,
+            max_vertices,
+            written_count: data.written_count.min(populated_quads),
+            alpha_mode: data.alpha_mode,
+            stored_params,
+            blank_vertex: data.blank_vertex,
+            winding: data.winding,
+            index_topology: data.index_topology,
+            lazy: data.lazy,
+            grid: data.grid,
+            dirty_vertices: None,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const HALF_TRANSPARENT: [f32; 4] = [1.0, 0.5, 0.2, 0.5];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+fn written_color(alpha_mode: AlphaMode) -> Vec<mint::Vector4<f32>> {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 1).unwrap();
+    builder.set_alpha_mode(alpha_mode);
+    builder.set_pos_color_source(0, [0.0, 0.0], HALF_TRANSPARENT, SOURCE, UvFlip::None);
+    builder
+        .vertices()
+        .iter()
+        .map(|vertex| vertex.color)
+        .collect()
+}
+
+#[test]
+fn premultiply_scales_rgb_by_alpha() {
+    for color in written_color(AlphaMode::PremultiplyColor) {
+        assert_eq!(color, [0.5, 0.25, 0.1, 0.5].into());
+    }
+}
+
+#[test]
+fn colors_are_untouched_without_premultiplying() {
+    for color in written_color(AlphaMode::AsIs) {
+        assert_eq!(color, HALF_TRANSPARENT.into());
+    }
+}
+
+#[test]
+fn premultiplied_color_converts_back() {
+    let premultiplied = AlphaMode::PremultiplyColor.convert(HALF_TRANSPARENT);
+    assert_eq!(
+        AlphaMode::UnpremultiplyColor.convert(premultiplied),
+        HALF_TRANSPARENT.into()
+    );
+}
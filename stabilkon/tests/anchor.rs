@@ -0,0 +1,73 @@
+mod common;
+
+use common::{emitted, WHITE};
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [128.0, 64.0];
+const SOURCE: [f32; 4] = [16.0, 16.0, 32.0, 16.0];
+
+#[test]
+fn center_anchor_matches_manual_offset() {
+    let anchored = PosColorAnchorSource::new(
+        [100.0, 50.0],
+        WHITE,
+        Anchor::Center,
+        SOURCE,
+        UvFlip::Vertical,
+    );
+    let manual = PosColorSource::new([84.0, 42.0], WHITE, SOURCE, UvFlip::Vertical);
+    assert_eq!(
+        emitted(TEXTURE_SIZE, true, &anchored),
+        emitted(TEXTURE_SIZE, true, &manual)
+    );
+}
+
+#[test]
+fn top_left_anchor_matches_pos_color_source() {
+    let anchored = PosColorAnchorSource::new(
+        [100.0, 50.0],
+        WHITE,
+        Anchor::default(),
+        SOURCE,
+        UvFlip::None,
+    );
+    let manual = PosColorSource::new([100.0, 50.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(
+        emitted(TEXTURE_SIZE, true, &anchored),
+        emitted(TEXTURE_SIZE, true, &manual)
+    );
+}
+
+#[test]
+fn bottom_center_anchor_places_quad_above_position() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 1).unwrap();
+    assert!(builder.set_pos_color_anchor_source(
+        0,
+        [100.0, 50.0],
+        WHITE,
+        Anchor::BottomCenter,
+        SOURCE,
+        UvFlip::None,
+    ));
+    let (min, max) = builder.bounds().unwrap();
+    assert_eq!((min.x, min.y), (84.0, 34.0));
+    assert_eq!((max.x, max.y), (116.0, 50.0));
+}
+
+#[test]
+fn zero_source_size_falls_back_to_texture_size_before_anchoring() {
+    let anchored = PosColorAnchorSource::new(
+        [0.0, 0.0],
+        WHITE,
+        Anchor::BottomRight,
+        [0.0, 0.0, 0.0, 0.0],
+        UvFlip::None,
+    );
+    let vertices = emitted(TEXTURE_SIZE, true, &anchored);
+    assert_eq!(
+        (vertices[0].position.x, vertices[0].position.y),
+        (-128.0, -64.0)
+    );
+    assert_eq!((vertices[2].position.x, vertices[2].position.y), (0.0, 0.0));
+}
@@ -0,0 +1,43 @@
+use stabilkon::*;
+
+#[test]
+fn frames_wrap_to_next_row_after_columns() {
+    let frames = animation_frames([0.0, 0.0, 16.0, 16.0], 5, 2, [16.0, 20.0]).unwrap();
+    let expected: Vec<mint::Vector4<f32>> = vec![
+        [0.0, 0.0, 16.0, 16.0].into(),
+        [16.0, 0.0, 16.0, 16.0].into(),
+        [0.0, 20.0, 16.0, 16.0].into(),
+        [16.0, 20.0, 16.0, 16.0].into(),
+        [0.0, 40.0, 16.0, 16.0].into(),
+    ];
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn frames_start_at_first_source() {
+    let frames = animation_frames([8.0, 4.0, 10.0, 12.0], 3, 3, [10.0, 12.0]).unwrap();
+    let expected: Vec<mint::Vector4<f32>> = vec![
+        [8.0, 4.0, 10.0, 12.0].into(),
+        [18.0, 4.0, 10.0, 12.0].into(),
+        [28.0, 4.0, 10.0, 12.0].into(),
+    ];
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn zero_frames_give_empty_animation() {
+    let frames = animation_frames([0.0, 0.0, 16.0, 16.0], 0, 4, [16.0, 16.0]).unwrap();
+    assert!(frames.is_empty());
+}
+
+#[test]
+fn zero_columns_are_rejected() {
+    assert_eq!(
+        animation_frames([0.0, 0.0, 16.0, 16.0], 4, 0, [16.0, 16.0]),
+        None
+    );
+    assert_eq!(
+        animation_frames([0.0, 0.0, 16.0, 16.0], 0, 0, [16.0, 16.0]),
+        None
+    );
+}
@@ -0,0 +1,64 @@
+use stabilkon::*;
+
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+fn builder_with_quads(quad_limit: u32, positions: &[[f32; 2]]) -> MeshFromQuads<PosUvColor> {
+    let mut builder = MeshFromQuads::new([64.0, 64.0], false, quad_limit).unwrap();
+    for position in positions {
+        builder.push_quad(&PosColorSource::new(
+            *position,
+            WHITE,
+            [0.0, 0.0, 16.0, 16.0],
+            UvFlip::None,
+        ));
+    }
+    builder
+}
+
+#[test]
+fn appended_quads_keep_their_geometry() {
+    let mut terrain = builder_with_quads(2, &[[0.0, 0.0], [16.0, 0.0]]);
+    let doodads = builder_with_quads(8, &[[100.0, 100.0], [200.0, 100.0], [300.0, 100.0]]);
+
+    terrain.append(&doodads).unwrap();
+
+    assert_eq!(terrain.written_quads(), 5);
+    assert_eq!(terrain.quad_limit(), 5);
+    assert_eq!(terrain.used_vertices().len(), 20);
+    assert_eq!(terrain.used_indices().len(), 30);
+    assert_eq!(
+        terrain.indices().unwrap(),
+        &generate_quad_indices(5).unwrap()
+    );
+    assert_eq!(&terrain.used_vertices()[8..], doodads.used_vertices());
+    assert_eq!(terrain.get_quad(0).unwrap().position, [0.0, 0.0].into());
+}
+
+#[test]
+fn append_reuses_free_quads_without_growing() {
+    let mut builder = builder_with_quads(4, &[[0.0, 0.0]]);
+    let other = builder_with_quads(2, &[[50.0, 50.0]]);
+    builder.append(&other).unwrap();
+    assert_eq!(builder.quad_limit(), 4);
+    assert_eq!(builder.written_quads(), 2);
+    assert_eq!(&builder.used_vertices()[4..], other.used_vertices());
+}
+
+#[test]
+fn append_rejects_different_atlases_and_layouts() {
+    let mut builder = builder_with_quads(1, &[[0.0, 0.0]]);
+    let other_atlas: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([32.0, 64.0], false, 1).unwrap();
+    assert!(matches!(
+        builder.append(&other_atlas),
+        Err(Error::TextureSizeMismatch { .. })
+    ));
+    let unindexed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 1).unwrap();
+    assert!(matches!(
+        builder.append(&unindexed),
+        Err(Error::VertexLayoutMismatch { .. })
+    ));
+    assert_eq!(builder.written_quads(), 1);
+    assert_eq!(builder.quad_limit(), 1);
+}
@@ -0,0 +1,33 @@
+use stabilkon::*;
+
+#[test]
+fn packed_grid_covers_entire_atlas() {
+    let grid = AtlasGrid::new([128.0, 64.0], [32.0, 32.0], [0.0, 0.0], [0.0, 0.0]);
+    assert_eq!((grid.columns, grid.rows), (4, 2));
+    assert_eq!(grid.tile_count(), 8);
+    assert_eq!(grid.source_of(0), Some([0.0, 0.0, 32.0, 32.0].into()));
+    assert_eq!(grid.source_of(3), Some([96.0, 0.0, 32.0, 32.0].into()));
+    assert_eq!(grid.source_of(5), Some([32.0, 32.0, 32.0, 32.0].into()));
+}
+
+#[test]
+fn spaced_grid_skips_margin_and_spacing() {
+    // 1 px margin, 2 px spacing: 1 + 16 + 2 + 16 + 2 + 16 + 1 = 54 px wide, two rows are 1 + 16 + 2 + 16 + 1 = 36 px.
+    let grid = AtlasGrid::new([54.0, 36.0], [16.0, 16.0], [2.0, 2.0], [1.0, 1.0]);
+    assert_eq!((grid.columns, grid.rows), (3, 2));
+    assert_eq!(grid.source_of(0), Some([1.0, 1.0, 16.0, 16.0].into()));
+    assert_eq!(grid.source_of(2), Some([37.0, 1.0, 16.0, 16.0].into()));
+    assert_eq!(grid.source_of(4), Some([19.0, 19.0, 16.0, 16.0].into()));
+}
+
+#[test]
+fn out_of_range_index_has_no_source() {
+    let grid = AtlasGrid::new([64.0, 64.0], [32.0, 32.0], [0.0, 0.0], [0.0, 0.0]);
+    assert_eq!(grid.source_of(3), Some([32.0, 32.0, 32.0, 32.0].into()));
+    assert_eq!(grid.source_of(4), None);
+    assert_eq!(grid.source_of(u32::MAX), None);
+
+    let empty = AtlasGrid::new([8.0, 8.0], [16.0, 16.0], [0.0, 0.0], [0.0, 0.0]);
+    assert_eq!(empty.tile_count(), 0);
+    assert_eq!(empty.source_of(0), None);
+}
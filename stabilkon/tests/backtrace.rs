@@ -0,0 +1,14 @@
+#![cfg(not(feature = "backtrace"))]
+
+use snafu::{Backtrace, ErrorCompat};
+use stabilkon::*;
+
+#[test]
+fn backtraces_are_inert_by_default() {
+    assert_eq!(std::mem::size_of::<Backtrace>(), 0);
+
+    let error = MeshFromQuads::<PosUvColor>::new([0.0, 0.0], false, 1).unwrap_err();
+    assert!(matches!(error, Error::InvalidTextureSize { .. }));
+    let backtrace = ErrorCompat::backtrace(&error).unwrap();
+    assert_eq!(backtrace.to_string(), "disabled backtrace");
+}
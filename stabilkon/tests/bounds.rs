@@ -0,0 +1,45 @@
+use stabilkon::*;
+
+#[test]
+fn bounds_are_none_for_empty_builder() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    assert_eq!(builder.bounds(), None);
+}
+
+#[test]
+fn bounds_enclose_every_corner_of_rotated_quads() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new_with_winding(
+            [64.0, 64.0],
+            false,
+            4,
+            use_indices,
+            WindingOrder::Clockwise,
+        )
+        .unwrap();
+        let rotations = [0.5_f32, -2.0];
+        for (quad_index, rotation) in rotations.iter().enumerate() {
+            let params = DetailedParams::new(
+                [quad_index as f32 * 100.0, -50.0],
+                [1.0, 1.0, 1.0, 1.0],
+                [16.0, 16.0],
+                [32.0, 32.0],
+                [1.5, 1.0],
+                *rotation,
+                [0.0, 0.0, 32.0, 32.0],
+                UvFlip::None,
+            );
+            assert!(builder.set(quad_index as u32, &params));
+        }
+
+        let (min, max) = builder.bounds().unwrap();
+        let positions: Vec<_> = builder.used_vertices().iter().map(|v| v.position).collect();
+        assert!(positions.iter().all(|p| p.x >= min.x && p.x <= max.x));
+        assert!(positions.iter().all(|p| p.y >= min.y && p.y <= max.y));
+        // The box is tight, each of its edges touches some corner:
+        assert!(positions.iter().any(|p| p.x == min.x));
+        assert!(positions.iter().any(|p| p.x == max.x));
+        assert!(positions.iter().any(|p| p.y == min.y));
+        assert!(positions.iter().any(|p| p.y == max.y));
+    }
+}
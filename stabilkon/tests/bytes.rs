@@ -0,0 +1,42 @@
+#![cfg(feature = "bytemuck")]
+
+use stabilkon::*;
+
+#[test]
+fn vertex_bytes_round_trip() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    builder.set_pos_color_source(
+        1,
+        [8.0, 16.0],
+        [0.25, 0.5, 0.75, 1.0],
+        [0.0, 0.0, 32.0, 32.0],
+        UvFlip::None,
+    );
+
+    let bytes = builder.vertices_as_bytes();
+    assert_eq!(
+        std::mem::size_of::<PosUvColor>(),
+        8 * std::mem::size_of::<f32>()
+    );
+    assert_eq!(
+        bytes.len(),
+        builder.vertices().len() * std::mem::size_of::<PosUvColor>()
+    );
+    let vertices: Vec<PosUvColor> = bytes
+        .chunks_exact(std::mem::size_of::<PosUvColor>())
+        .map(bytemuck::pod_read_unaligned)
+        .collect();
+    assert_eq!(vertices.as_slice(), builder.vertices().as_slice());
+
+    let index_bytes = builder.indices_as_bytes().unwrap();
+    assert_eq!(index_bytes.len(), 2 * 6 * std::mem::size_of::<u32>());
+
+    let expected_vertices = builder.vertices().clone();
+    let (vertex_bytes, index_bytes) = builder.into_bytes();
+    assert_eq!(
+        bytemuck::cast_slice::<PosUvColor, u8>(&expected_vertices),
+        vertex_bytes.as_slice()
+    );
+    assert!(index_bytes.is_some());
+}
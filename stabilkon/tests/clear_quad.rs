@@ -0,0 +1,57 @@
+mod common;
+
+use common::quad_at;
+use stabilkon::*;
+
+fn filled_builder() -> MeshFromQuads<PosUvColor> {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    for quad_index in 0..4 {
+        builder.set(quad_index, &quad_at(quad_index as f32 * 16.0 + 1.0));
+    }
+    builder.clear_dirty();
+    builder
+}
+
+#[test]
+fn clear_quad_blanks_only_that_quad() {
+    let mut builder = filled_builder();
+    let before = builder.vertices().clone();
+    assert!(builder.clear_quad(1));
+
+    // Builders created with `new` use zeroed vertices as blank ones:
+    let blank = PosUvColor::new([0.0, 0.0], [0.0, 0.0], [0.0, 0.0, 0.0, 0.0]);
+    assert!(builder.vertices()[4..8]
+        .iter()
+        .all(|vertex| *vertex == blank));
+    assert_eq!(builder.vertices()[..4], before[..4]);
+    assert_eq!(builder.vertices()[8..], before[8..]);
+    assert_eq!(builder.dirty_vertex_range(), Some(4..8));
+    assert_eq!(builder.written_quads(), 4);
+}
+
+#[test]
+fn clear_range_blanks_consecutive_quads() {
+    let mut builder = filled_builder();
+    let before = builder.vertices().clone();
+    assert!(builder.clear_range(1, 2));
+
+    let blank = PosUvColor::new([0.0, 0.0], [0.0, 0.0], [0.0, 0.0, 0.0, 0.0]);
+    assert!(builder.vertices()[4..12]
+        .iter()
+        .all(|vertex| *vertex == blank));
+    assert_eq!(builder.vertices()[..4], before[..4]);
+    assert_eq!(builder.vertices()[12..], before[12..]);
+    assert_eq!(builder.dirty_vertex_range(), Some(4..12));
+}
+
+#[test]
+fn clearing_out_of_range_changes_nothing() {
+    let mut builder = filled_builder();
+    let before = builder.vertices().clone();
+    assert!(!builder.clear_quad(4));
+    assert!(!builder.clear_range(3, 2));
+    assert!(!builder.clear_range(1, u32::MAX));
+    assert_eq!(builder.vertices(), &before);
+    assert_eq!(builder.dirty_vertex_range(), None);
+}
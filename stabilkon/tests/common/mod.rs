@@ -0,0 +1,23 @@
+//! Fixtures shared by integration tests, not every test uses all of them.
+#![allow(dead_code)]
+
+use stabilkon::*;
+
+pub const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// White quad showing the top-left 16x16 atlas tile, placed at the given x.
+pub fn quad_at(x: f32) -> PosColorSource {
+    PosColorSource::new([x, 0.0], WHITE, [0.0, 0.0, 16.0, 16.0], UvFlip::None)
+}
+
+/// Vertices of the given quad, as written by an indexed single-quad builder.
+pub fn emitted<T: QuadDrawParams>(
+    texture_size: [f32; 2],
+    use_half_pixel_offset: bool,
+    params: &T,
+) -> Vec<PosUvColor> {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(texture_size, use_half_pixel_offset, 1).unwrap();
+    builder.set(0, params);
+    builder.vertices().to_vec()
+}
@@ -0,0 +1,68 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [128.0, 64.0];
+
+fn assert_same_state(actual: &MeshFromQuads<PosUvColor>, expected: &MeshFromQuads<PosUvColor>) {
+    assert_eq!(actual.uv_inset(), expected.uv_inset());
+    assert_eq!(actual.quad_limit(), expected.quad_limit());
+    assert_eq!(actual.vertices_per_quad(), expected.vertices_per_quad());
+    assert_eq!(actual.vertices_limit(), expected.vertices_limit());
+    assert_eq!(actual.winding(), expected.winding());
+    assert_eq!(actual.alpha_mode(), expected.alpha_mode());
+    assert_eq!(actual.indices(), expected.indices());
+    assert_eq!(actual.vertices(), expected.vertices());
+    assert_eq!(actual.written_quads(), expected.written_quads());
+}
+
+#[test]
+fn default_config_matches_new() {
+    let from_config = MeshFromQuadsConfig::new(TEXTURE_SIZE, 16).build().unwrap();
+    let from_new = MeshFromQuads::new(TEXTURE_SIZE, false, 16).unwrap();
+    assert_same_state(&from_config, &from_new);
+}
+
+#[test]
+fn configured_options_match_equivalent_constructors() {
+    let from_config = MeshFromQuadsConfig::new(TEXTURE_SIZE, 16)
+        .with_half_pixel_offset(true)
+        .with_indices(false)
+        .build()
+        .unwrap();
+    let from_new = MeshFromQuads::new_without_indices(TEXTURE_SIZE, true, 16).unwrap();
+    assert_same_state(&from_config, &from_new);
+
+    let from_config = MeshFromQuadsConfig::new(TEXTURE_SIZE, 4)
+        .with_quad_limit(8)
+        .with_winding(WindingOrder::CounterClockwise)
+        .with_alpha_mode(AlphaMode::UnpremultiplyColor)
+        .build()
+        .unwrap();
+    let mut from_new = MeshFromQuads::new_with_winding(
+        TEXTURE_SIZE,
+        false,
+        8,
+        true,
+        WindingOrder::CounterClockwise,
+    )
+    .unwrap();
+    from_new.set_alpha_mode(AlphaMode::UnpremultiplyColor);
+    assert_same_state(&from_config, &from_new);
+}
+
+#[test]
+fn uv_inset_overrides_half_pixel_offset() {
+    let inset = UvInset::new(1.0, 0.0, 1.0, 0.0);
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new(TEXTURE_SIZE, 1)
+        .with_half_pixel_offset(true)
+        .with_uv_inset(inset)
+        .build()
+        .unwrap();
+    assert_eq!(builder.uv_inset(), inset);
+}
+
+#[test]
+fn invalid_config_fails_to_build() {
+    let result: Result<MeshFromQuads<PosUvColor>, _> =
+        MeshFromQuadsConfig::new([0.0, 64.0], 1).build();
+    assert!(result.is_err());
+}
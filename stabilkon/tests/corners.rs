@@ -0,0 +1,40 @@
+use stabilkon::*;
+
+#[test]
+fn corners_are_emitted_verbatim() {
+    let corners = [[0.0, 0.0], [-8.0, 32.0], [40.0, 32.0], [32.0, 0.0]];
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new_with_winding(
+            [64.0, 64.0],
+            false,
+            2,
+            use_indices,
+            WindingOrder::Clockwise,
+        )
+        .unwrap();
+        assert!(builder.set_corners(
+            1,
+            corners,
+            [1.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 32.0, 32.0],
+            UvFlip::None
+        ));
+        assert!(!builder.set_corners(
+            2,
+            corners,
+            [1.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 32.0, 32.0],
+            UvFlip::None
+        ));
+
+        let offset = builder.vertices_per_quad() as usize;
+        let quad = &builder.vertices()[offset..];
+        let emitted: Vec<[f32; 2]> = quad.iter().map(|v| [v.position.x, v.position.y]).collect();
+        if use_indices {
+            assert_eq!(emitted, corners.to_vec());
+        } else {
+            let [c1, c2, c3, c4] = corners;
+            assert_eq!(emitted, vec![c1, c2, c3, c3, c4, c1]);
+        }
+    }
+}
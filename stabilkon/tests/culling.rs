@@ -0,0 +1,56 @@
+use stabilkon::*;
+
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+/// 4×4 grid of 16×16 quads, quad index is `row * 4 + column`.
+fn grid_builder() -> MeshFromQuads<PosUvColor> {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 20).unwrap();
+    builder.fill_grid_uniform(
+        0,
+        4,
+        4,
+        [16.0, 16.0],
+        [0.0, 0.0],
+        WHITE,
+        SOURCE,
+        UvFlip::None,
+    );
+    builder
+}
+
+#[test]
+fn finds_quads_intersecting_view() {
+    let builder = grid_builder();
+    // View covers columns 1..=2 and rows 2..=3:
+    let visible = builder.visible_quad_indices([20.0, 40.0], [40.0, 100.0]);
+    assert_eq!(visible, vec![9, 10, 13, 14]);
+}
+
+#[test]
+fn view_outside_of_grid_sees_nothing() {
+    let builder = grid_builder();
+    assert!(builder
+        .visible_quad_indices([100.0, 100.0], [200.0, 200.0])
+        .is_empty());
+    // Unwritten quads are never visible, even though their zeroed vertices are inside the view:
+    let visible = builder.visible_quad_indices([-100.0, -100.0], [100.0, 100.0]);
+    assert_eq!(visible, (0..16).collect::<Vec<u32>>());
+}
+
+#[test]
+fn collects_indices_for_visible_quads() {
+    let builder = grid_builder();
+    let visible = builder.visible_quad_indices([0.0, 0.0], [10.0, 10.0]);
+    assert_eq!(visible, vec![0]);
+    assert_eq!(
+        builder.collect_indices_for(&[0, 5]),
+        vec![0, 1, 2, 2, 3, 0, 20, 21, 22, 22, 23, 20]
+    );
+    assert_eq!(builder.collect_indices_for(&[20]), Vec::<u32>::new());
+
+    let unindexed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 4).unwrap();
+    assert!(unindexed.collect_indices_for(&[0]).is_empty());
+}
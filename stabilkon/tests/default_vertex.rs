@@ -0,0 +1,50 @@
+use stabilkon::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct MarkedVertex {
+    position: [f32; 2],
+    marker: u32,
+}
+
+impl Default for MarkedVertex {
+    fn default() -> Self {
+        Self {
+            position: [-1.0, -1.0],
+            marker: 0xDEAD_BEEF,
+        }
+    }
+}
+
+impl From<PosUvColor> for MarkedVertex {
+    fn from(vertex: PosUvColor) -> Self {
+        Self {
+            position: vertex.position.into(),
+            marker: 1,
+        }
+    }
+}
+
+#[test]
+fn new_default_fills_vertices_with_default() {
+    let mut builder: MeshFromQuads<MarkedVertex> =
+        MeshFromQuads::new_default([64.0, 64.0], false, 3).unwrap();
+    assert!(builder
+        .vertices()
+        .iter()
+        .all(|vertex| *vertex == MarkedVertex::default()));
+
+    assert!(builder.set_pos_color_source(
+        1,
+        [8.0, 8.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None
+    ));
+    assert_eq!(builder.vertices()[4].marker, 1);
+
+    builder.clear();
+    assert!(builder
+        .vertices()
+        .iter()
+        .all(|vertex| *vertex == MarkedVertex::default()));
+}
@@ -0,0 +1,43 @@
+use stabilkon::*;
+
+#[test]
+fn pos_color_source_z_vertices_carry_depth() {
+    let params = PosColorSourceZ::new(
+        [10.0, 20.0],
+        0.75,
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    );
+    for use_indices in [true, false] {
+        let vertices: Vec<PosUvColorZ> =
+            params.to_vertices_z([64.0, 64.0].into(), VertexOptions::new(false, use_indices));
+        assert_eq!(vertices.len() as u32, vertices_per_quad(use_indices));
+        assert!(vertices.iter().all(|v| v.position.z == 0.75));
+    }
+}
+
+#[test]
+fn set_pos_color_source_z_matches_flat_quad_with_depth() {
+    let mut builder: MeshFromQuads<PosUvColorZ> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    let mut flat_builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    let color = [0.5, 0.5, 0.5, 1.0];
+    let source = [16.0, 0.0, 16.0, 16.0];
+    assert!(builder.set_pos_color_source_z(1, [32.0, 0.0], -2.0, color, source, UvFlip::Vertical));
+    flat_builder.set_pos_color_source(1, [32.0, 0.0], color, source, UvFlip::Vertical);
+    assert!(!builder.set_pos_color_source_z(2, [0.0, 0.0], -2.0, color, source, UvFlip::None));
+    assert_eq!(builder.written_quads(), 2);
+
+    for (z_vertex, flat_vertex) in builder.vertices()[4..]
+        .iter()
+        .zip(&flat_builder.vertices()[4..])
+    {
+        assert_eq!(z_vertex.position.z, -2.0);
+        assert_eq!(z_vertex.position.x, flat_vertex.position.x);
+        assert_eq!(z_vertex.position.y, flat_vertex.position.y);
+        assert_eq!(z_vertex.uv, flat_vertex.uv);
+        assert_eq!(z_vertex.color, flat_vertex.color);
+    }
+}
@@ -0,0 +1,128 @@
+use stabilkon::*;
+
+const EPSILON: f32 = 0.001;
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 32.0, 32.0];
+
+fn assert_corners(builder: &MeshFromQuads<PosUvColor>, quad_index: usize, expected: [[f32; 2]; 4]) {
+    let offset = quad_index * 4;
+    for (vertex, expected) in builder.vertices()[offset..offset + 4].iter().zip(expected) {
+        assert!(
+            (vertex.position.x - expected[0]).abs() < EPSILON
+                && (vertex.position.y - expected[1]).abs() < EPSILON,
+            "{:?} is not close to {:?}",
+            vertex.position,
+            expected
+        );
+    }
+}
+
+#[test]
+fn rotates_quad_around_its_center() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    assert!(builder.set_pos_color_origin_size_scale_rotation_source(
+        0,
+        [0.0, 0.0],
+        WHITE,
+        [16.0, 16.0],
+        [32.0, 32.0],
+        [1.0, 1.0],
+        0.0,
+        SOURCE,
+        UvFlip::None,
+    ));
+    assert_corners(
+        &builder,
+        0,
+        [[0.0, 0.0], [0.0, 32.0], [32.0, 32.0], [32.0, 0.0]],
+    );
+
+    let rotated = DetailedParams::new(
+        [0.0, 0.0],
+        WHITE,
+        [16.0, 16.0],
+        [32.0, 32.0],
+        [1.0, 1.0],
+        std::f32::consts::FRAC_PI_2,
+        SOURCE,
+        UvFlip::None,
+    );
+    assert!(builder.set_detailed(1, rotated));
+    // Every corner moves to the place of its clockwise neighbour:
+    assert_corners(
+        &builder,
+        1,
+        [[32.0, 0.0], [0.0, 0.0], [0.0, 32.0], [32.0, 32.0]],
+    );
+}
+
+#[test]
+fn detailed_setters_reject_out_of_range_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    let params = DetailedParams::new(
+        [0.0, 0.0],
+        WHITE,
+        [0.0, 0.0],
+        [32.0, 32.0],
+        [1.0, 1.0],
+        0.0,
+        SOURCE,
+        UvFlip::None,
+    );
+    assert!(!builder.set_detailed(1, params));
+}
+
+#[test]
+fn rotates_quad_around_world_pivot() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    // Quad spans 100..132 along x, pivot is 100 units to the left of it:
+    let orbiting = DetailedParams::new(
+        [100.0, -16.0],
+        WHITE,
+        [16.0, 16.0],
+        [32.0, 32.0],
+        [1.0, 1.0],
+        std::f32::consts::PI,
+        SOURCE,
+        UvFlip::None,
+    )
+    .with_world_pivot([0.0, 0.0]);
+    assert!(builder.set(0, &orbiting));
+    // Half a turn moves the quad to the opposite side of the pivot, turning it upside down:
+    assert_corners(
+        &builder,
+        0,
+        [
+            [-100.0, 16.0],
+            [-100.0, -16.0],
+            [-132.0, -16.0],
+            [-132.0, 16.0],
+        ],
+    );
+}
+
+#[test]
+fn world_pivot_without_rotation_keeps_quad_in_place() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    let params = DetailedParams::new(
+        [10.0, 20.0],
+        WHITE,
+        [0.0, 0.0],
+        [32.0, 32.0],
+        [1.0, 1.0],
+        0.0,
+        SOURCE,
+        UvFlip::None,
+    )
+    .with_world_pivot([500.0, 500.0]);
+    assert!(builder.set(0, &params));
+    assert_corners(
+        &builder,
+        0,
+        [[10.0, 20.0], [10.0, 52.0], [42.0, 52.0], [42.0, 20.0]],
+    );
+}
@@ -0,0 +1,78 @@
+use stabilkon::*;
+
+/// Emits corner UVs of a quad covering the entire 4×2 texture, in `corner_points` order.
+fn corner_uvs(flip: UvFlip) -> Vec<(f32, f32)> {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([4.0, 2.0], false, 1).unwrap();
+    builder.set_pos_color_source(
+        0,
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 4.0, 2.0],
+        flip,
+    );
+    builder
+        .vertices()
+        .iter()
+        .map(|v| (v.uv.x, v.uv.y))
+        .collect()
+}
+
+// With `UvFlip::None`, corners get UVs (0, 1), (0, 0), (1, 0) and (1, 1):
+const BL: (f32, f32) = (0.0, 1.0);
+const TL: (f32, f32) = (0.0, 0.0);
+const TR: (f32, f32) = (1.0, 0.0);
+const BR: (f32, f32) = (1.0, 1.0);
+
+#[test]
+fn no_flip_is_the_reference() {
+    assert_eq!(corner_uvs(UvFlip::None), vec![BL, TL, TR, BR]);
+}
+
+#[test]
+fn diagonal_left_rotates_uvs_counter_clockwise() {
+    assert_eq!(corner_uvs(UvFlip::DiagonalLeft), vec![TL, TR, BR, BL]);
+}
+
+#[test]
+fn diagonal_right_rotates_uvs_clockwise() {
+    assert_eq!(corner_uvs(UvFlip::DiagonalRight), vec![BR, BL, TL, TR]);
+}
+
+#[test]
+fn diagonal_flips_are_retained_and_reapplied() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([4.0, 2.0], false, 1).unwrap();
+    builder.set_retain_params(true);
+    let params = PosColorSizeSource::new(
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [8.0, 8.0],
+        [0.0, 0.0, 4.0, 2.0],
+        UvFlip::DiagonalRight,
+    );
+    builder.set(0, &params);
+    let uvs: Vec<_> = builder
+        .vertices()
+        .iter()
+        .map(|v| (v.uv.x, v.uv.y))
+        .collect();
+    assert_eq!(uvs, vec![BR, BL, TL, TL, TR, BR]);
+    assert_eq!(
+        builder.get_quad_params(0).unwrap().flip(),
+        UvFlip::DiagonalRight
+    );
+}
+
+#[test]
+fn nine_slice_rejects_diagonal_flips() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([4.0, 2.0], false, 9).unwrap();
+    assert!(!builder.set_nine_slice(
+        0,
+        [0.0, 0.0],
+        [8.0, 8.0],
+        [0.0, 0.0, 4.0, 2.0],
+        [1.0, 1.0, 1.0, 1.0],
+        UvFlip::DiagonalLeft
+    ));
+    assert_eq!(builder.written_quads(), 0);
+}
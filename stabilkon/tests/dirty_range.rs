@@ -0,0 +1,75 @@
+use stabilkon::*;
+
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+#[test]
+fn dirty_range_widens_with_each_set() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 16).unwrap();
+    assert_eq!(builder.dirty_vertex_range(), None);
+
+    builder.set_pos_color_source(5, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(builder.dirty_vertex_range(), Some(20..24));
+    builder.set_pos_color_source(2, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(builder.dirty_vertex_range(), Some(8..24));
+    builder.set_pos_color_source(9, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(builder.dirty_vertex_range(), Some(8..40));
+    // Failed sets do not touch anything:
+    builder.set_pos_color_source(16, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(builder.dirty_vertex_range(), Some(8..40));
+}
+
+#[test]
+fn dirty_range_resets_on_clear_dirty() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 4).unwrap();
+    builder.set_pos_color_source(1, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(builder.dirty_vertex_range(), Some(6..12));
+    builder.clear_dirty();
+    assert_eq!(builder.dirty_vertex_range(), None);
+
+    builder.set_pos_color_source(3, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(builder.dirty_vertex_range(), Some(18..24));
+    builder.clear();
+    assert_eq!(builder.dirty_vertex_range(), Some(0..24));
+}
+
+#[test]
+fn dirty_range_is_clamped_by_shrinking_resize() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 8).unwrap();
+    builder.set_pos_color_source(1, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    builder.set_pos_color_source(6, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    builder.resize(3).unwrap();
+    assert_eq!(builder.dirty_vertex_range(), Some(4..12));
+    builder.resize(1).unwrap();
+    assert_eq!(builder.dirty_vertex_range(), None);
+}
+
+#[test]
+fn set_if_changed_skips_identical_writes() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new([64.0, 64.0], 4)
+            .with_indices(use_indices)
+            .build()
+            .unwrap();
+        let params = PosColorSource::new([8.0, 8.0], WHITE, SOURCE, UvFlip::None);
+        assert!(builder.set_if_changed(1, &params));
+        let vertices_per_quad = builder.vertices_per_quad() as usize;
+        assert_eq!(
+            builder.dirty_vertex_range(),
+            Some(vertices_per_quad..vertices_per_quad * 2)
+        );
+        assert_eq!(builder.written_quads(), 2);
+
+        builder.clear_dirty();
+        assert!(!builder.set_if_changed(1, &params));
+        assert_eq!(builder.dirty_vertex_range(), None);
+
+        let moved = PosColorSource::new([9.0, 8.0], WHITE, SOURCE, UvFlip::None);
+        assert!(builder.set_if_changed(1, &moved));
+        assert_eq!(builder.get_quad(1).unwrap().position.x, 9.0);
+        assert!(!builder.set_if_changed(4, &moved));
+    }
+}
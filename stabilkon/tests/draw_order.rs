@@ -0,0 +1,114 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 8.0, 8.0];
+
+fn set_quads(builder: &mut MeshFromQuads<PosUvColor>, quad_indices: &[u32]) {
+    for &quad_index in quad_indices {
+        let position = [quad_index as f32 * 8.0, 0.0];
+        assert!(builder.set_pos_color_source(quad_index, position, WHITE, SOURCE, UvFlip::None));
+    }
+}
+
+fn top_left_xs(builder: &MeshFromQuads<PosUvColor>) -> Vec<f32> {
+    builder
+        .used_vertices()
+        .chunks_exact(builder.vertices_per_quad() as usize)
+        .map(|quad_vertices| quad_vertices[0].position.x)
+        .collect()
+}
+
+#[test]
+fn indexed_order_rebuilds_indices_only() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    set_quads(&mut builder, &[0, 1, 2]);
+    let vertices = builder.vertices().to_vec();
+
+    builder.set_draw_order(&[2, 0], false).unwrap();
+    assert_eq!(builder.vertices(), vertices.as_slice());
+    assert_eq!(
+        builder.indices().unwrap(),
+        &vec![8, 9, 10, 10, 11, 8, 0, 1, 2, 2, 3, 0]
+    );
+
+    builder.set_draw_order(&[2, 0, 2], true).unwrap();
+    assert_eq!(builder.indices().unwrap().len(), 18);
+    assert_eq!(&builder.indices().unwrap()[12..], &[4, 5, 6, 6, 7, 4]);
+}
+
+#[test]
+fn unindexed_order_moves_listed_quads_and_clears_the_rest() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 3).unwrap();
+    set_quads(&mut builder, &[0, 1, 2]);
+
+    builder.set_draw_order(&[2, 0], false).unwrap();
+    assert_eq!(builder.written_quads(), 2);
+    assert_eq!(top_left_xs(&builder), vec![16.0, 0.0]);
+    #[cfg(debug_assertions)]
+    assert_eq!(builder.assert_fully_written(), Err(vec![2]));
+}
+
+#[test]
+fn unindexed_order_appends_unlisted_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 3).unwrap();
+    set_quads(&mut builder, &[0, 1, 2]);
+
+    builder.set_draw_order(&[2], true).unwrap();
+    assert_eq!(builder.written_quads(), 3);
+    assert_eq!(top_left_xs(&builder), vec![16.0, 0.0, 8.0]);
+}
+
+#[test]
+fn out_of_range_order_changes_nothing() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 2).unwrap();
+    set_quads(&mut builder, &[0, 1]);
+    let indices = builder.indices().unwrap().clone();
+    assert!(matches!(
+        builder.set_draw_order(&[1, 2], true),
+        Err(Error::QuadIndexOutOfRange {
+            index: 2,
+            limit: 2,
+            ..
+        })
+    ));
+    assert_eq!(builder.indices().unwrap(), &indices);
+}
+
+#[test]
+fn lazy_unindexed_order_populates_moved_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 4)
+        .unwrap()
+        .to_unindexed()
+        .unwrap();
+    builder.set_draw_order(&[3], true).unwrap();
+    assert_eq!(builder.written_quads(), 4);
+    assert_eq!(builder.vertices().len(), 24);
+    assert_eq!(builder.used_vertices().len(), 24);
+
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 4)
+        .unwrap()
+        .to_unindexed()
+        .unwrap();
+    set_quads(&mut builder, &[0, 1]);
+    builder.set_draw_order(&[1, 0], false).unwrap();
+    assert_eq!(builder.written_quads(), 2);
+    assert_eq!(top_left_xs(&builder), vec![8.0, 0.0]);
+}
+
+#[test]
+fn lazy_indexed_order_populates_drawn_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 4).unwrap();
+    set_quads(&mut builder, &[0]);
+    assert_eq!(builder.vertices().len(), 4);
+
+    builder.set_draw_order(&[3], true).unwrap();
+    // First used quad is quad 3 now, so its vertices have to exist:
+    assert_eq!(builder.used_indices(), &[12, 13, 14, 14, 15, 12]);
+    assert_eq!(builder.vertices().len(), 16);
+}
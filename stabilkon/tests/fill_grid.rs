@@ -0,0 +1,144 @@
+use stabilkon::*;
+
+#[test]
+fn uniform_grid_places_last_quad_at_far_corner() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 14).unwrap();
+    assert!(builder.fill_grid_uniform(
+        2,
+        4,
+        3,
+        [16.0, 8.0],
+        [100.0, 200.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    ));
+    assert_eq!(builder.written_quads(), 14);
+
+    let last_quad = builder.get_quad(13).unwrap();
+    assert_eq!(last_quad.position.x, 100.0 + 3.0 * 16.0);
+    assert_eq!(last_quad.position.y, 200.0 + 2.0 * 8.0);
+    let last_vertices = &builder.vertices()[13 * 4..14 * 4];
+    assert_eq!(last_vertices[2].position.x, 100.0 + 4.0 * 16.0);
+    assert_eq!(last_vertices[2].position.y, 200.0 + 3.0 * 8.0);
+}
+
+#[test]
+fn uniform_grid_not_fitting_writes_nothing() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 12).unwrap();
+    assert!(!builder.fill_grid_uniform(
+        1,
+        4,
+        3,
+        [16.0, 16.0],
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    ));
+    assert_eq!(builder.written_quads(), 0);
+    assert!(!builder.fill_grid_uniform(
+        0,
+        u32::MAX,
+        2,
+        [16.0, 16.0],
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    ));
+    assert_eq!(builder.written_quads(), 0);
+}
+
+#[test]
+fn closure_grid_skips_none_cells_and_counts_set_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 8).unwrap();
+    let mut visited = Vec::new();
+    let set_quads = builder.fill_grid(1, 3, 2, [10.0, 20.0], [16.0, 16.0], |column, row| {
+        visited.push((column, row));
+        if (column + row) % 2 == 0 {
+            Some((
+                [0.0, 0.0, 16.0, 16.0].into(),
+                [1.0, 1.0, 1.0, 1.0].into(),
+                UvFlip::None,
+            ))
+        } else {
+            None
+        }
+    });
+    assert_eq!(set_quads, Some(3));
+    assert_eq!(
+        visited,
+        vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+    );
+
+    // Cells (0, 0), (2, 0) and (1, 1) go to quads 1, 3 and 5, the rest stay blank:
+    #[cfg(debug_assertions)]
+    assert_eq!(
+        builder.assert_fully_written().unwrap_err(),
+        vec![0, 2, 4, 6, 7]
+    );
+    assert_eq!(builder.written_quads(), 6);
+    let last_set = builder.get_quad(5).unwrap();
+    assert_eq!(last_set.position.x, 10.0 + 16.0);
+    assert_eq!(last_set.position.y, 20.0 + 16.0);
+}
+
+#[test]
+fn closure_grid_with_all_cells_skipped_sets_nothing() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    assert_eq!(
+        builder.fill_grid(0, 2, 2, [0.0, 0.0], [16.0, 16.0], |_, _| None),
+        Some(0)
+    );
+    assert_eq!(builder.written_quads(), 0);
+}
+
+#[test]
+fn closure_grid_overflowing_cell_count_returns_none() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    let mut calls = 0;
+    let mut cell = |_, _| {
+        calls += 1;
+        Some((
+            [0.0, 0.0, 16.0, 16.0].into(),
+            [1.0, 1.0, 1.0, 1.0].into(),
+            UvFlip::None,
+        ))
+    };
+    // columns * rows overflows:
+    assert_eq!(
+        builder.fill_grid(0, u32::MAX, 2, [0.0, 0.0], [16.0, 16.0], &mut cell),
+        None
+    );
+    // start_quad + columns * rows overflows:
+    assert_eq!(
+        builder.fill_grid(u32::MAX, 1, 1, [0.0, 0.0], [16.0, 16.0], &mut cell),
+        None
+    );
+    assert_eq!(calls, 0);
+    assert_eq!(builder.written_quads(), 0);
+}
+
+#[test]
+fn closure_grid_past_quad_limit_leaves_mesh_untouched() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 6).unwrap();
+    let untouched = builder.vertices().to_vec();
+    let set_quads = builder.fill_grid(1, 3, 2, [0.0, 0.0], [16.0, 16.0], |_, _| {
+        Some((
+            [0.0, 0.0, 16.0, 16.0].into(),
+            [1.0, 1.0, 1.0, 1.0].into(),
+            UvFlip::None,
+        ))
+    });
+    assert_eq!(set_quads, None);
+    assert_eq!(builder.vertices(), untouched.as_slice());
+    assert_eq!(builder.written_quads(), 0);
+    assert_eq!(builder.dirty_vertex_range(), None);
+}
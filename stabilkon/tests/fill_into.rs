@@ -0,0 +1,57 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+
+fn garbage_vertex() -> PosUvColor {
+    PosUvColor::new([13.0, -7.0], [0.5, 0.25], [0.1, 0.2, 0.3, 0.4])
+}
+
+fn blank_vertex() -> PosUvColor {
+    PosUvColor::new([0.0, 0.0], [0.0, 0.0], [0.0, 0.0, 0.0, 0.0])
+}
+
+#[test]
+fn recycled_larger_vecs_keep_capacity_and_are_reinitialised() {
+    let vertices = vec![garbage_vertex(); 1000];
+    let vertex_capacity = vertices.capacity();
+    let indices = vec![u32::MAX; 3000];
+    let index_capacity = indices.capacity();
+
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::fill_into(TEXTURE_SIZE, false, 4, true, vertices, Some(indices)).unwrap();
+    assert_eq!(builder.quad_limit(), 4);
+    assert_eq!(builder.written_quads(), 0);
+    #[cfg(debug_assertions)]
+    assert_eq!(builder.assert_fully_written(), Err(vec![0, 1, 2, 3]));
+
+    let (vertices, indices) = builder.into_vertices_and_indices();
+    assert_eq!(vertices.capacity(), vertex_capacity);
+    assert_eq!(vertices, vec![blank_vertex(); 16]);
+    let indices = indices.unwrap();
+    assert_eq!(indices.capacity(), index_capacity);
+    assert_eq!(indices, generate_quad_indices(4).unwrap());
+}
+
+#[test]
+fn recycled_vertices_are_reinitialised_for_unindexed_builder() {
+    let vertices = vec![garbage_vertex(); 100];
+    let vertex_capacity = vertices.capacity();
+
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::fill_into(TEXTURE_SIZE, false, 3, false, vertices, Some(vec![1, 2, 3]))
+            .unwrap();
+    let (vertices, indices) = builder.into_vertices_and_indices();
+    assert_eq!(vertices.capacity(), vertex_capacity);
+    assert_eq!(vertices, vec![blank_vertex(); 18]);
+    assert_eq!(indices, None);
+}
+
+#[test]
+fn recycled_smaller_vecs_grow_to_fit() {
+    let vertices = vec![garbage_vertex(); 2];
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::fill_into(TEXTURE_SIZE, false, 8, true, vertices, None).unwrap();
+    let (vertices, indices) = builder.into_vertices_and_indices();
+    assert_eq!(vertices, vec![blank_vertex(); 32]);
+    assert_eq!(indices.unwrap(), generate_quad_indices(8).unwrap());
+}
@@ -0,0 +1,63 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+fn builder(quad_limit: u32) -> MeshFromQuads<PosUvColor> {
+    MeshFromQuads::new(TEXTURE_SIZE, false, quad_limit).unwrap()
+}
+
+fn set_quad(builder: &mut MeshFromQuads<PosUvColor>, quad_index: u32) -> bool {
+    builder.set_pos_color_source(quad_index, [0.0, 0.0], WHITE, SOURCE, UvFlip::None)
+}
+
+#[test]
+fn empty_builder_has_all_quads_remaining() {
+    let builder = builder(8);
+    assert_eq!(builder.quads_remaining(), 8);
+    assert_eq!(builder.fill_ratio(), 0.0);
+}
+
+#[test]
+fn partly_written_builder_counts_up_to_last_set_quad() {
+    let mut builder = builder(8);
+    assert!(set_quad(&mut builder, 1));
+    assert_eq!(builder.quads_remaining(), 6);
+    assert_eq!(builder.fill_ratio(), 0.25);
+
+    // Setting an earlier quad does not move the cursor back:
+    assert!(set_quad(&mut builder, 0));
+    assert_eq!(builder.quads_remaining(), 6);
+    assert!(set_quad(&mut builder, 5));
+    assert_eq!(builder.quads_remaining(), 2);
+    assert_eq!(builder.fill_ratio(), 0.75);
+}
+
+#[test]
+fn full_builder_has_nothing_remaining() {
+    let mut builder = builder(4);
+    for quad_index in 0..4 {
+        let params =
+            PosColorSource::new([quad_index as f32 * 16.0, 0.0], WHITE, SOURCE, UvFlip::None);
+        assert_eq!(builder.push_quad(&params), Some(quad_index));
+    }
+    assert_eq!(builder.quads_remaining(), 0);
+    assert_eq!(builder.fill_ratio(), 1.0);
+    assert!(!set_quad(&mut builder, 4));
+    assert_eq!(builder.quads_remaining(), 0);
+}
+
+#[test]
+fn zero_quad_limit_is_full_without_nan() {
+    let builder = builder(0);
+    assert_eq!(builder.quads_remaining(), 0);
+    assert_eq!(builder.fill_ratio(), 1.0);
+
+    let from_nothing: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::from_texture_vertices_indices(TEXTURE_SIZE, false, Vec::new(), None)
+            .unwrap();
+    assert_eq!(from_nothing.quad_limit(), 0);
+    assert!(!from_nothing.fill_ratio().is_nan());
+    assert_eq!(from_nothing.fill_ratio(), 1.0);
+}
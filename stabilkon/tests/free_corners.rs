@@ -0,0 +1,83 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [16.0, 0.0, 16.0, 32.0];
+// A trapezoid, wider at the bottom:
+const CORNERS: [[f32; 2]; 4] = [[4.0, 0.0], [0.0, 10.0], [20.0, 10.0], [16.0, 0.0]];
+
+fn positions(vertices: &[PosUvColor]) -> Vec<[f32; 2]> {
+    vertices
+        .iter()
+        .map(|v| [v.position.x, v.position.y])
+        .collect()
+}
+
+fn uvs(vertices: &[PosUvColor]) -> Vec<[f32; 2]> {
+    vertices.iter().map(|v| [v.uv.x, v.uv.y]).collect()
+}
+
+#[test]
+fn corners_are_used_verbatim() {
+    let params = FreeCornersSource::new(CORNERS, WHITE, SOURCE, UvFlip::None);
+    let indexed: Vec<PosUvColor> =
+        params.to_vertices(TEXTURE_SIZE.into(), VertexOptions::new(false, true));
+    assert_eq!(positions(&indexed), CORNERS.to_vec());
+
+    let unindexed: Vec<PosUvColor> =
+        params.to_vertices(TEXTURE_SIZE.into(), VertexOptions::new(false, false));
+    let [c1, c2, c3, c4] = CORNERS;
+    assert_eq!(positions(&unindexed), vec![c1, c2, c3, c3, c4, c1]);
+}
+
+#[test]
+fn corner_uvs_match_plain_quad_with_same_source() {
+    for flip in [
+        UvFlip::None,
+        UvFlip::Horizontal,
+        UvFlip::Vertical,
+        UvFlip::Both,
+        UvFlip::DiagonalLeft,
+        UvFlip::DiagonalRight,
+    ] {
+        let free = FreeCornersSource::new(CORNERS, WHITE, SOURCE, flip);
+        let plain = PosColorSource::new([0.0, 0.0], WHITE, SOURCE, flip);
+        for uv_inset in [false, true] {
+            let options = VertexOptions::new(uv_inset, true);
+            let free_vertices: Vec<PosUvColor> = free.to_vertices(TEXTURE_SIZE.into(), options);
+            let plain_vertices: Vec<PosUvColor> = plain.to_vertices(TEXTURE_SIZE.into(), options);
+            assert_eq!(uvs(&free_vertices), uvs(&plain_vertices), "{:?}", flip);
+        }
+    }
+}
+
+#[test]
+fn normalized_source_uvs_ignore_texture_size() {
+    let params = FreeCornersSource::new(CORNERS, WHITE, [0.25, 0.0, 0.25, 0.5], UvFlip::None)
+        .with_source_space(SourceSpace::Normalized);
+    let vertices: Vec<PosUvColor> =
+        params.to_vertices(TEXTURE_SIZE.into(), VertexOptions::new(false, true));
+    assert_eq!(
+        uvs(&vertices),
+        vec![[0.25, 0.5], [0.25, 0.0], [0.5, 0.0], [0.5, 0.5]]
+    );
+    assert_eq!(positions(&vertices), CORNERS.to_vec());
+}
+
+#[test]
+fn quad_corners_alias_sets_the_same_quad() {
+    let alias: QuadCorners = QuadCorners::new(CORNERS, WHITE, SOURCE, UvFlip::Vertical);
+    let free = FreeCornersSource::new(CORNERS, WHITE, SOURCE, UvFlip::Vertical);
+    assert_eq!(alias, free);
+
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 2).unwrap();
+    builder.set_retain_params(true);
+    assert!(builder.set_corners(0, CORNERS, WHITE, SOURCE, UvFlip::Vertical));
+    assert!(builder.set(1, &free));
+    assert_eq!(&builder.vertices()[..4], &builder.vertices()[4..]);
+    assert_eq!(
+        builder.get_quad_params(0),
+        Some(&StoredParams::FreeCornersSource(free))
+    );
+}
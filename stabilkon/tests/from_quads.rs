@@ -0,0 +1,57 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+fn quads() -> Vec<PosColorSource> {
+    vec![
+        PosColorSource::new([0.0, 0.0], WHITE, [0.0, 0.0, 16.0, 16.0], UvFlip::None),
+        PosColorSource::new([16.0, 0.0], WHITE, [16.0, 0.0, 16.0, 16.0], UvFlip::None),
+        PosColorSource::new([32.0, 8.0], WHITE, [0.0, 16.0, 8.0, 32.0], UvFlip::None),
+    ]
+}
+
+#[test]
+fn builds_mesh_with_exactly_given_quads() {
+    for use_indices in [true, false] {
+        let builder: MeshFromQuads<PosUvColor> =
+            MeshFromQuads::from_quads(TEXTURE_SIZE, false, use_indices, quads()).unwrap();
+        assert_eq!(builder.quad_limit(), 3);
+        assert_eq!(builder.written_quads(), 3);
+        assert_eq!(builder.quads().collect::<Vec<_>>(), quads());
+
+        let vertices_per_quad = builder.vertices_per_quad() as usize;
+        let first_top_left = builder.vertices()[0].position;
+        let last_bottom_right = builder.vertices()[2 * vertices_per_quad + 2].position;
+        assert_eq!(first_top_left, [0.0, 0.0].into());
+        assert_eq!(last_bottom_right, [40.0, 40.0].into());
+    }
+}
+
+#[test]
+fn builds_mesh_from_mapped_iterator() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::from_quads(
+        TEXTURE_SIZE,
+        false,
+        true,
+        (0..4).map(|i| {
+            PosColorSource::new(
+                [i as f32 * 16.0, 0.0],
+                WHITE,
+                [0.0, 0.0, 16.0, 16.0],
+                UvFlip::None,
+            )
+        }),
+    )
+    .unwrap();
+    assert_eq!(builder.quad_limit(), 4);
+    assert_eq!(builder.get_quad(3).unwrap().position, [48.0, 0.0].into());
+}
+
+#[test]
+fn empty_iterator_builds_empty_mesh() {
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::from_quads(TEXTURE_SIZE, false, true, Vec::new()).unwrap();
+    assert_eq!(builder.quad_limit(), 0);
+    assert!(builder.vertices().is_empty());
+}
@@ -0,0 +1,33 @@
+#![cfg(debug_assertions)]
+
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+#[test]
+fn fully_written_mesh_passes() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    for quad_index in 0..3 {
+        let position = [quad_index as f32 * 16.0, 0.0];
+        builder.set_pos_color_source(quad_index, position, WHITE, SOURCE, UvFlip::None);
+    }
+    assert_eq!(builder.assert_fully_written(), Ok(()));
+}
+
+#[test]
+fn skipped_quads_are_reported() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new(TEXTURE_SIZE, 4)
+            .with_indices(use_indices)
+            .build()
+            .unwrap();
+        for quad_index in [0, 2] {
+            let position = [quad_index as f32 * 16.0, 0.0];
+            builder.set_pos_color_source(quad_index, position, WHITE, SOURCE, UvFlip::None);
+        }
+        assert_eq!(builder.assert_fully_written(), Err(vec![1, 3]));
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "ggez")]
+
+use ggez::graphics::{Color, Rect};
+use stabilkon::*;
+
+#[test]
+fn color_round_trips_field_by_field() {
+    let color = Color::new(0.1, 0.2, 0.3, 0.4);
+    let converted = color_from_ggez(color);
+    assert_eq!(
+        (converted.x, converted.y, converted.z, converted.w),
+        (0.1, 0.2, 0.3, 0.4)
+    );
+    assert_eq!(color_into_ggez(converted), color);
+}
+
+#[test]
+fn rect_round_trips_field_by_field() {
+    let rect = Rect::new(1.0, 2.0, 3.0, 4.0);
+    let converted = rectangle_from_ggez(rect);
+    assert_eq!(
+        (converted.x, converted.y, converted.z, converted.w),
+        (1.0, 2.0, 3.0, 4.0)
+    );
+    assert_eq!(rectangle_into_ggez(converted), rect);
+}
+
+#[test]
+fn converted_types_are_accepted_by_setters() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    assert!(builder.set_pos_color_source(
+        0,
+        [0.0, 0.0],
+        color_from_ggez(Color::RED),
+        rectangle_from_ggez(Rect::new(0.0, 0.0, 16.0, 16.0)),
+        UvFlip::None,
+    ));
+    assert_eq!(builder.vertices()[0].color, [1.0, 0.0, 0.0, 1.0].into());
+}
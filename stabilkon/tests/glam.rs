@@ -0,0 +1,30 @@
+#![cfg(feature = "glam")]
+
+use stabilkon::*;
+
+#[test]
+fn glam_types_are_accepted_by_setters() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(glam::Vec2::new(64.0, 64.0), false, 1).unwrap();
+    assert!(builder.set_pos_color_source(
+        0,
+        glam::Vec2::new(8.0, 4.0),
+        glam::Vec4::new(1.0, 0.5, 0.25, 1.0),
+        glam::Vec4::new(0.0, 0.0, 16.0, 16.0),
+        UvFlip::None,
+    ));
+    assert_eq!(builder.vertices()[0].position, [8.0, 4.0].into());
+    assert_eq!(builder.vertices()[2].position, [24.0, 20.0].into());
+    assert_eq!(builder.vertices()[0].color, [1.0, 0.5, 0.25, 1.0].into());
+}
+
+#[test]
+fn glam_vectors_convert_into_mint_field_by_field() {
+    let position: mint::Vector2<f32> = glam::Vec2::new(1.0, 2.0).into();
+    assert_eq!((position.x, position.y), (1.0, 2.0));
+    let rectangle: mint::Vector4<f32> = glam::Vec4::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(
+        (rectangle.x, rectangle.y, rectangle.z, rectangle.w),
+        (1.0, 2.0, 3.0, 4.0)
+    );
+}
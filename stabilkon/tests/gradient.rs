@@ -0,0 +1,25 @@
+use stabilkon::*;
+
+#[test]
+fn gradient_corners_carry_their_own_colors() {
+    let top_left = [1.0, 0.0, 0.0, 1.0];
+    let bottom_left = [0.0, 1.0, 0.0, 1.0];
+    let bottom_right = [0.0, 0.0, 1.0, 1.0];
+    let top_right = [1.0, 1.0, 1.0, 1.0];
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    assert!(builder.set_pos_gradient_source(
+        1,
+        [0.0, 0.0],
+        [top_left, bottom_left, bottom_right, top_right],
+        [0.0, 0.0, 32.0, 32.0],
+        UvFlip::None,
+    ));
+
+    let vertices = &builder.vertices()[4..8];
+    assert_eq!(vertices[0].color, top_left.into());
+    assert_eq!(vertices[2].color, bottom_right.into());
+    assert_ne!(vertices[0].color, vertices[2].color);
+    assert_eq!(vertices[1].color, bottom_left.into());
+    assert_eq!(vertices[3].color, top_right.into());
+}
@@ -0,0 +1,84 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+
+#[test]
+fn grid_builder_holds_all_tiles() {
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::for_grid(TEXTURE_SIZE, false, 4, 3, [16.0, 16.0]).unwrap();
+    assert_eq!(builder.quad_limit(), 12);
+    assert_eq!(
+        builder.grid(),
+        Some(TileGrid {
+            columns: 4,
+            rows: 3,
+            tile_size: [16.0, 16.0].into(),
+        })
+    );
+}
+
+#[test]
+fn tile_coordinates_map_to_quad_indices_row_by_row() {
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::for_grid(TEXTURE_SIZE, false, 4, 3, [16.0, 16.0]).unwrap();
+    assert_eq!(builder.tile_quad_index(0, 0), Some(0));
+    assert_eq!(builder.tile_quad_index(3, 0), Some(3));
+    assert_eq!(builder.tile_quad_index(0, 1), Some(4));
+    assert_eq!(builder.tile_quad_index(3, 2), Some(11));
+    assert_eq!(builder.tile_quad_index(4, 0), None);
+    assert_eq!(builder.tile_quad_index(0, 3), None);
+}
+
+#[test]
+fn builders_without_grid_do_not_map_tiles() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 12).unwrap();
+    assert_eq!(builder.grid(), None);
+    assert_eq!(builder.tile_quad_index(0, 0), None);
+}
+
+#[test]
+fn huge_grid_overflows_quad_count() {
+    let result: Result<MeshFromQuads<PosUvColor>, _> =
+        MeshFromQuads::for_grid(TEXTURE_SIZE, false, 100_000, 100_000, [1.0, 1.0]);
+    assert!(matches!(result, Err(Error::QuadCountIsTooLarge { .. })));
+}
+
+#[test]
+fn set_tile_writes_quad_at_its_grid_cell() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::for_grid(TEXTURE_SIZE, false, 4, 3, [16.0, 8.0]).unwrap();
+    let white = [1.0, 1.0, 1.0, 1.0];
+    let source = [16.0, 0.0, 16.0, 16.0];
+    assert!(builder.set_tile(2, 1, white, source, UvFlip::None));
+
+    let quad_index = builder.tile_quad_index(2, 1).unwrap();
+    assert_eq!(quad_index, 6);
+    let written = builder.get_quad(quad_index).unwrap();
+    assert_eq!(written.position, [32.0, 8.0].into());
+    let bottom_right = builder.vertices()[quad_index as usize * 4 + 2];
+    assert_eq!(bottom_right.position, [48.0, 16.0].into());
+    assert_eq!(builder.written_quads(), 7);
+}
+
+#[test]
+fn set_tile_rejects_out_of_bounds_tiles() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::for_grid(TEXTURE_SIZE, false, 4, 3, [16.0, 16.0]).unwrap();
+    let white = [1.0, 1.0, 1.0, 1.0];
+    let source = [0.0, 0.0, 16.0, 16.0];
+    assert!(!builder.set_tile(4, 0, white, source, UvFlip::None));
+    assert!(!builder.set_tile(0, 3, white, source, UvFlip::None));
+    assert_eq!(builder.written_quads(), 0);
+
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 12).unwrap();
+    assert!(!builder.set_tile(0, 0, white, source, UvFlip::None));
+}
+
+#[test]
+fn grid_texture_and_tile_sizes_accept_different_types() {
+    let tile_size = mint::Vector2 { x: 16.0, y: 8.0 };
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::for_grid(TEXTURE_SIZE, false, 2, 2, tile_size).unwrap();
+    assert_eq!(builder.grid().map(|grid| grid.tile_size), Some(tile_size));
+}
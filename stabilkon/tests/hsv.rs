@@ -0,0 +1,48 @@
+use stabilkon::*;
+
+const EPSILON: f32 = 0.0001;
+
+fn assert_rgba(actual: mint::Vector4<f32>, expected: [f32; 4]) {
+    let actual: [f32; 4] = actual.into();
+    assert!(
+        actual
+            .iter()
+            .zip(expected)
+            .all(|(actual, expected)| (actual - expected).abs() < EPSILON),
+        "{:?} is not close to {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn primary_and_secondary_hues() {
+    assert_rgba(color_from_hsv(0.0, 1.0, 1.0, 1.0), [1.0, 0.0, 0.0, 1.0]);
+    assert_rgba(color_from_hsv(60.0, 1.0, 1.0, 1.0), [1.0, 1.0, 0.0, 1.0]);
+    assert_rgba(color_from_hsv(120.0, 1.0, 1.0, 1.0), [0.0, 1.0, 0.0, 1.0]);
+    assert_rgba(color_from_hsv(180.0, 1.0, 1.0, 1.0), [0.0, 1.0, 1.0, 1.0]);
+    assert_rgba(color_from_hsv(240.0, 1.0, 1.0, 1.0), [0.0, 0.0, 1.0, 1.0]);
+    assert_rgba(color_from_hsv(300.0, 1.0, 1.0, 0.5), [1.0, 0.0, 1.0, 0.5]);
+}
+
+#[test]
+fn intermediate_values() {
+    // Orange at half saturation and 80% value:
+    assert_rgba(color_from_hsv(30.0, 0.5, 0.8, 1.0), [0.8, 0.6, 0.4, 1.0]);
+}
+
+#[test]
+fn zero_saturation_is_gray() {
+    assert_rgba(color_from_hsv(0.0, 0.0, 0.5, 1.0), [0.5, 0.5, 0.5, 1.0]);
+    assert_rgba(
+        color_from_hsv(200.0, 0.0, 0.25, 0.75),
+        [0.25, 0.25, 0.25, 0.75],
+    );
+}
+
+#[test]
+fn hue_wraps_around() {
+    assert_rgba(color_from_hsv(360.0, 1.0, 1.0, 1.0), [1.0, 0.0, 0.0, 1.0]);
+    assert_rgba(color_from_hsv(540.0, 1.0, 1.0, 1.0), [0.0, 1.0, 1.0, 1.0]);
+    assert_rgba(color_from_hsv(-120.0, 1.0, 1.0, 1.0), [0.0, 0.0, 1.0, 1.0]);
+}
@@ -0,0 +1,107 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+const RESTART: u32 = STRIP_RESTART_INDEX;
+
+#[test]
+fn strip_indices_of_two_quads_are_separated_by_restart() {
+    assert_eq!(
+        generate_quad_indices_strip(2).unwrap(),
+        vec![1, 2, 0, 3, RESTART, 5, 6, 4, 7, RESTART]
+    );
+    assert!(generate_quad_indices_strip(0).unwrap().is_empty());
+}
+
+#[test]
+fn strip_triangles_match_list_triangles() {
+    // Strip [a, b, c, d] draws triangles (a, b, c) and (c, b, d):
+    let strip = generate_quad_indices_strip(1).unwrap();
+    let list = generate_quad_indices(1).unwrap();
+    let mut strip_triangles = vec![
+        [strip[0], strip[1], strip[2]],
+        [strip[2], strip[1], strip[3]],
+    ];
+    let mut list_triangles = vec![[list[0], list[1], list[2]], [list[3], list[4], list[5]]];
+    // Triangles are compared by their vertex sets, since rotating vertices keeps facing:
+    for triangle in strip_triangles.iter_mut().chain(list_triangles.iter_mut()) {
+        let min_position = (0..3).min_by_key(|&i| triangle[i]).unwrap();
+        triangle.rotate_left(min_position);
+    }
+    strip_triangles.sort();
+    list_triangles.sort();
+    assert_eq!(strip_triangles, list_triangles);
+}
+
+#[test]
+fn builder_stores_topology_and_uses_strip_layout() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new(TEXTURE_SIZE, 3)
+        .with_index_topology(IndexTopology::TriangleStrip)
+        .build()
+        .unwrap();
+    assert_eq!(builder.index_topology(), IndexTopology::TriangleStrip);
+    assert_eq!(builder.indices().unwrap().len(), 15);
+
+    builder.set_pos_color_source(1, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    assert_eq!(
+        builder.used_indices(),
+        &[1, 2, 0, 3, RESTART, 5, 6, 4, 7, RESTART]
+    );
+    assert_eq!(
+        builder.collect_indices_for(&[2]),
+        vec![9, 10, 8, 11, RESTART]
+    );
+
+    builder.set_draw_order(&[1], true).unwrap();
+    assert_eq!(&builder.indices().unwrap()[..5], &[5, 6, 4, 7, RESTART]);
+}
+
+#[test]
+fn switching_topology_back_restores_list_indices() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 2).unwrap();
+    assert_eq!(builder.index_topology(), IndexTopology::TriangleList);
+    builder
+        .set_index_topology(IndexTopology::TriangleStrip)
+        .unwrap();
+    builder
+        .set_index_topology(IndexTopology::TriangleList)
+        .unwrap();
+    assert_eq!(builder.indices(), Some(&generate_quad_indices(2).unwrap()));
+}
+
+#[test]
+fn builders_with_different_topologies_can_not_be_appended() {
+    let mut strip: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 1).unwrap();
+    strip
+        .set_index_topology(IndexTopology::TriangleStrip)
+        .unwrap();
+    let list: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 1).unwrap();
+    assert!(matches!(
+        strip.append(&list),
+        Err(Error::VertexLayoutMismatch { .. })
+    ));
+}
+
+#[test]
+fn u16_strip_builders_can_not_grow_into_the_restart_index() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_u16_indexed(TEXTURE_SIZE, false, 2).unwrap();
+    builder
+        .set_index_topology(IndexTopology::TriangleStrip)
+        .unwrap();
+
+    let result = builder.resize(MAX_U16_INDEXED_QUADS);
+    assert!(matches!(result, Err(Error::IndicesExceedU16 { .. })));
+    assert_eq!(builder.quad_limit(), 2);
+
+    builder.resize(MAX_U16_INDEXED_QUADS - 1).unwrap();
+    let indices_u16 = builder.indices_u16().unwrap();
+    let last_vertex = indices_u16.iter().filter(|&&index| index != u16::MAX).max();
+    assert_eq!(last_vertex, Some(&(u16::MAX - 4)));
+    builder
+        .set_index_topology(IndexTopology::TriangleList)
+        .unwrap();
+    builder.resize(MAX_U16_INDEXED_QUADS).unwrap();
+}
@@ -0,0 +1,66 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+fn set_quad(builder: &mut MeshFromQuads<PosUvColor>, quad_index: u32) -> bool {
+    let position = [quad_index as f32 * 16.0, 0.0];
+    builder.set_pos_color_source(quad_index, position, WHITE, SOURCE, UvFlip::None)
+}
+
+#[test]
+fn lazy_builder_starts_without_vertices() {
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 10_000).unwrap();
+    assert!(builder.is_lazy());
+    assert!(builder.vertices().is_empty());
+    assert_eq!(builder.quad_limit(), 10_000);
+    assert_eq!(builder.vertices_limit(), 40_000);
+    assert_eq!(builder.indices().unwrap().len(), 60_000);
+}
+
+#[test]
+fn setting_quad_grows_vertices_up_to_it() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 10_000).unwrap();
+    assert!(set_quad(&mut builder, 100));
+    assert_eq!(builder.vertices().len(), 101 * 4);
+    assert_eq!(builder.written_quads(), 101);
+    assert_eq!(builder.used_indices().len(), 101 * 6);
+
+    // Setting an earlier quad does not grow the buffer:
+    assert!(set_quad(&mut builder, 5));
+    assert_eq!(builder.vertices().len(), 101 * 4);
+    assert!(!set_quad(&mut builder, 10_000));
+    assert_eq!(builder.vertices().len(), 101 * 4);
+}
+
+#[test]
+fn lazy_builder_writes_same_vertices_as_preallocated_one() {
+    let mut lazy: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 8).unwrap();
+    let mut preallocated: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 8).unwrap();
+    for quad_index in [3, 1] {
+        set_quad(&mut lazy, quad_index);
+        set_quad(&mut preallocated, quad_index);
+    }
+    assert_eq!(lazy.vertices()[..], preallocated.vertices()[..16]);
+    assert_eq!(lazy.get_quad(3), preallocated.get_quad(3));
+    assert_eq!(lazy.get_quad(4), None);
+    #[cfg(debug_assertions)]
+    assert_eq!(lazy.assert_fully_written(), Err(vec![0, 2, 4, 5, 6, 7]));
+}
+
+#[test]
+fn resizing_lazy_builder_does_not_populate_vertices() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 8).unwrap();
+    set_quad(&mut builder, 5);
+    builder.resize(100).unwrap();
+    assert_eq!(builder.vertices().len(), 6 * 4);
+    builder.resize(2).unwrap();
+    assert_eq!(builder.vertices().len(), 2 * 4);
+    assert_eq!(builder.written_quads(), 2);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "macroquad")]
+
+use macroquad::models::Vertex;
+use stabilkon::*;
+
+#[test]
+fn small_macroquad_mesh_is_created() {
+    let mut builder: MeshFromQuads<Vertex> = MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    assert!(builder.set_pos_color_source(
+        1,
+        [8.0, 16.0],
+        [1.0, 0.5, 0.25, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    ));
+
+    let mesh = builder.create_mesh(None).unwrap();
+    assert_eq!(mesh.vertices.len(), 4 * 4);
+    assert_eq!(mesh.indices.len(), 4 * 6);
+    assert_eq!(mesh.indices[6..12], [4, 5, 6, 6, 7, 4]);
+    assert_eq!(mesh.vertices[4].position.x, 8.0);
+    assert_eq!(mesh.vertices[4].position.z, 0.0);
+    assert_eq!(mesh.vertices[4].color.g, 0.5);
+
+    let used_mesh = builder.create_mesh_used(None).unwrap();
+    assert_eq!(used_mesh.vertices.len(), 2 * 4);
+    assert_eq!(used_mesh.indices.len(), 2 * 6);
+}
+
+#[test]
+fn unindexed_macroquad_mesh_gets_sequential_indices() {
+    let builder: MeshFromQuads<Vertex> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 2).unwrap();
+    let mesh = builder.create_mesh(None).unwrap();
+    assert_eq!(mesh.indices, (0..12).collect::<Vec<u16>>());
+}
+
+#[test]
+fn macroquad_mesh_with_too_many_vertices_is_rejected() {
+    let builder: MeshFromQuads<Vertex> = MeshFromQuads::new([64.0, 64.0], false, 16384).unwrap();
+    assert!(builder.create_mesh(None).is_ok());
+    let builder: MeshFromQuads<Vertex> = MeshFromQuads::new([64.0, 64.0], false, 16385).unwrap();
+    assert!(matches!(
+        builder.create_mesh(None),
+        Err(Error::VertexBufferIsTooLarge { .. })
+    ));
+}
@@ -0,0 +1,88 @@
+use stabilkon::*;
+
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+fn builder_with_quad(use_indices: bool) -> MeshFromQuads<PosUvColor> {
+    let mut builder = MeshFromQuadsConfig::new([64.0, 64.0], 1)
+        .with_indices(use_indices)
+        .build()
+        .unwrap();
+    builder.set_pos_color_source(0, [10.0, 20.0], WHITE, SOURCE, UvFlip::None);
+    builder
+}
+
+/// Gets doubled signed areas of all mesh triangles, their signs determine triangle facing.
+fn triangle_areas(builder: &MeshFromQuads<PosUvColor>) -> Vec<f32> {
+    let vertex_indices: Vec<usize> = match builder.indices() {
+        Some(indices) => indices.iter().map(|&index| index as usize).collect(),
+        None => (0..builder.vertices().len()).collect(),
+    };
+    vertex_indices
+        .chunks_exact(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| builder.vertices()[triangle[i]].position);
+            (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+        })
+        .collect()
+}
+
+fn sorted_corners(builder: &MeshFromQuads<PosUvColor>) -> Vec<(i32, i32, i32, i32)> {
+    let mut corners: Vec<_> = builder
+        .vertices()
+        .iter()
+        .map(|vertex| {
+            (
+                vertex.position.x as i32,
+                vertex.position.y as i32,
+                (vertex.uv.x * 64.0) as i32,
+                (vertex.uv.y * 64.0) as i32,
+            )
+        })
+        .collect();
+    corners.sort();
+    corners.dedup();
+    corners
+}
+
+#[test]
+fn mirroring_across_vertical_line_flips_x_and_keeps_winding() {
+    for use_indices in [true, false] {
+        let mut builder = builder_with_quad(use_indices);
+        let areas_before = triangle_areas(&builder);
+        builder.mirror(Axis::X, 0.0);
+
+        // Positions are mirrored, while each corner keeps the UV it had before mirroring:
+        assert_eq!(
+            sorted_corners(&builder),
+            vec![
+                (-26, 20, 16, 16),
+                (-26, 36, 16, 0),
+                (-10, 20, 0, 16),
+                (-10, 36, 0, 0),
+            ]
+        );
+        let areas_after = triangle_areas(&builder);
+        assert_eq!(areas_before.len(), areas_after.len());
+        for (before, after) in areas_before.iter().zip(areas_after) {
+            assert!(
+                before * after > 0.0,
+                "winding of mirrored triangle was reversed"
+            );
+        }
+    }
+}
+
+#[test]
+fn mirroring_across_horizontal_line_flips_y() {
+    let mut builder = builder_with_quad(true);
+    let areas_before = triangle_areas(&builder);
+    builder.mirror(Axis::Y, 50.0);
+    let ys: Vec<f32> = builder
+        .vertices()
+        .iter()
+        .map(|vertex| vertex.position.y)
+        .collect();
+    assert!(ys.iter().all(|&y| y == 80.0 || y == 64.0));
+    assert_eq!(triangle_areas(&builder), areas_before);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{emitted, WHITE};
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [128.0, 64.0];
+
+#[test]
+fn negative_width_matches_horizontal_flip() {
+    for use_half_pixel_offset in [false, true] {
+        let mirrored =
+            PosColorSource::new([4.0, 4.0], WHITE, [48.0, 16.0, -32.0, 16.0], UvFlip::None);
+        let flipped = PosColorSource::new(
+            [4.0, 4.0],
+            WHITE,
+            [16.0, 16.0, 32.0, 16.0],
+            UvFlip::Horizontal,
+        );
+        assert_eq!(
+            emitted(TEXTURE_SIZE, use_half_pixel_offset, &mirrored),
+            emitted(TEXTURE_SIZE, use_half_pixel_offset, &flipped)
+        );
+    }
+}
+
+#[test]
+fn negative_height_matches_vertical_flip() {
+    let mirrored = PosColorSource::new([0.0, 0.0], WHITE, [16.0, 32.0, 32.0, -16.0], UvFlip::None);
+    let flipped = PosColorSource::new(
+        [0.0, 0.0],
+        WHITE,
+        [16.0, 16.0, 32.0, 16.0],
+        UvFlip::Vertical,
+    );
+    assert_eq!(
+        emitted(TEXTURE_SIZE, true, &mirrored),
+        emitted(TEXTURE_SIZE, true, &flipped)
+    );
+}
+
+#[test]
+fn negative_dimensions_combine_with_flip() {
+    let mirrored = PosColorSource::new(
+        [0.0, 0.0],
+        WHITE,
+        [48.0, 32.0, -32.0, -16.0],
+        UvFlip::Vertical,
+    );
+    let flipped = PosColorSource::new(
+        [0.0, 0.0],
+        WHITE,
+        [16.0, 16.0, 32.0, 16.0],
+        UvFlip::Horizontal,
+    );
+    assert_eq!(
+        emitted(TEXTURE_SIZE, false, &mirrored),
+        emitted(TEXTURE_SIZE, false, &flipped)
+    );
+}
+
+#[test]
+fn zero_dimensions_still_use_full_texture() {
+    let params = PosColorSource::new([0.0, 0.0], WHITE, [0.0, 0.0, 0.0, 0.0], UvFlip::None);
+    let vertices = emitted(TEXTURE_SIZE, false, &params);
+    assert_eq!(vertices[2].position.x, TEXTURE_SIZE[0]);
+    assert_eq!(vertices[2].position.y, TEXTURE_SIZE[1]);
+}
@@ -0,0 +1,86 @@
+use stabilkon::*;
+
+const EPSILON: f32 = 0.001;
+
+fn quad_bounds(builder: &MeshFromQuads<PosUvColor>, quad_index: usize) -> (f32, f32, f32, f32) {
+    let vertices = &builder.vertices()[quad_index * 4..quad_index * 4 + 4];
+    (
+        vertices[0].position.x,
+        vertices[0].position.y,
+        vertices[2].position.x,
+        vertices[2].position.y,
+    )
+}
+
+#[test]
+fn nine_slices_tile_destination_exactly() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([128.0, 128.0], false, 10).unwrap();
+    let position = [10.0, 20.0];
+    let size = [200.0, 100.0];
+    let border = [4.0, 6.0, 8.0, 10.0];
+    assert!(builder.set_nine_slice(
+        1,
+        position,
+        size,
+        [0.0, 0.0, 48.0, 48.0],
+        border,
+        UvFlip::Vertical
+    ));
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let (left, top, right, bottom) = quad_bounds(&builder, 1 + row * 3 + column);
+            // Each slice starts where its left and upper neighbours end:
+            if column == 0 {
+                assert!((left - position[0]).abs() < EPSILON);
+            } else {
+                let (_, _, previous_right, _) = quad_bounds(&builder, row * 3 + column);
+                assert!((left - previous_right).abs() < EPSILON);
+            }
+            if row == 0 {
+                assert!((top - position[1]).abs() < EPSILON);
+            } else {
+                let (_, _, _, previous_bottom) = quad_bounds(&builder, 1 + (row - 1) * 3 + column);
+                assert!((top - previous_bottom).abs() < EPSILON);
+            }
+            if column == 2 {
+                assert!((right - (position[0] + size[0])).abs() < EPSILON);
+            }
+            if row == 2 {
+                assert!((bottom - (position[1] + size[1])).abs() < EPSILON);
+            }
+        }
+    }
+
+    // Corners keep their source size and thus aspect:
+    let corner_sizes = [(0, 4.0, 6.0), (2, 8.0, 6.0), (6, 4.0, 10.0), (8, 8.0, 10.0)];
+    for &(slice, width, height) in &corner_sizes {
+        let (left, top, right, bottom) = quad_bounds(&builder, 1 + slice);
+        assert!((right - left - width).abs() < EPSILON);
+        assert!((bottom - top - height).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn nine_slice_not_fitting_into_quad_limit_writes_nothing() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([128.0, 128.0], false, 9).unwrap();
+    assert!(!builder.set_nine_slice(
+        1,
+        [0.0, 0.0],
+        [64.0, 64.0],
+        [0.0, 0.0, 48.0, 48.0],
+        [4.0, 4.0, 4.0, 4.0],
+        UvFlip::Vertical,
+    ));
+    assert_eq!(builder.written_quads(), 0);
+    assert!(builder.set_nine_slice(
+        0,
+        [0.0, 0.0],
+        [64.0, 64.0],
+        [0.0, 0.0, 48.0, 48.0],
+        [4.0, 4.0, 4.0, 4.0],
+        UvFlip::Vertical,
+    ));
+}
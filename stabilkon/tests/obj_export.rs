@@ -0,0 +1,62 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+fn write_two_quads(mut builder: MeshFromQuads<PosUvColor>) -> String {
+    builder.set_pos_color_source(0, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    builder.set_pos_color_source(1, [16.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    let mut obj = Vec::new();
+    builder.write_obj(&mut obj).unwrap();
+    String::from_utf8(obj).unwrap()
+}
+
+fn count_lines(obj: &str, prefix: &str) -> usize {
+    obj.lines()
+        .filter(|line| line.split_whitespace().next() == Some(prefix))
+        .count()
+}
+
+#[test]
+fn indexed_mesh_is_written_with_shared_vertices() {
+    let obj = write_two_quads(MeshFromQuads::new(TEXTURE_SIZE, false, 2).unwrap());
+    assert_eq!(count_lines(&obj, "v"), 8);
+    assert_eq!(count_lines(&obj, "vt"), 8);
+    assert_eq!(count_lines(&obj, "f"), 4);
+    assert_eq!(obj.lines().count(), 20);
+    assert!(obj.lines().any(|line| line == "v 32 16 0"));
+    assert!(obj.lines().any(|line| line == "f 5/5 6/6 7/7"));
+}
+
+#[test]
+fn unindexed_mesh_is_written_as_vertex_triples() {
+    let obj = write_two_quads(MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 2).unwrap());
+    assert_eq!(count_lines(&obj, "v"), 12);
+    assert_eq!(count_lines(&obj, "vt"), 12);
+    assert_eq!(count_lines(&obj, "f"), 4);
+    let faces: Vec<&str> = obj.lines().filter(|line| line.starts_with("f ")).collect();
+    assert_eq!(faces[0], "f 1/1 2/2 3/3");
+    assert_eq!(faces[3], "f 10/10 11/11 12/12");
+}
+
+#[test]
+fn lazy_mesh_faces_refer_only_to_populated_vertices() {
+    let indexed = MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 8).unwrap();
+    let unindexed = MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 8)
+        .unwrap()
+        .to_unindexed()
+        .unwrap();
+    for builder in [indexed, unindexed] {
+        let obj = write_two_quads(builder);
+        let vertex_count = count_lines(&obj, "v");
+        assert!(vertex_count < 8 * 4);
+        assert_eq!(count_lines(&obj, "f"), 4);
+        for face in obj.lines().filter(|line| line.starts_with("f ")) {
+            for corner in face.split_whitespace().skip(1) {
+                let index: usize = corner.split('/').next().unwrap().parse().unwrap();
+                assert!((1..=vertex_count).contains(&index), "{}", face);
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+#![cfg(feature = "rayon")]
+
+use stabilkon::*;
+
+fn quad_params(quad_index: u32) -> Option<PosColorSource> {
+    // Leave every seventh quad unset to check skipped quads stay intact:
+    if quad_index % 7 == 6 {
+        return None;
+    }
+    let column = quad_index % 512;
+    let row = quad_index / 512;
+    Some(PosColorSource::new(
+        [column as f32 * 32.0, row as f32 * 32.0],
+        [1.0, 1.0, 1.0, (quad_index % 3) as f32 / 2.0],
+        [(quad_index % 9) as f32 * 32.0, 0.0, 32.0, 32.0],
+        UvFlip::Vertical,
+    ))
+}
+
+#[test]
+fn parallel_fill_matches_sequential_fill() {
+    let quad_limit = 512 * 512;
+    for &use_indices in &[true, false] {
+        let mut sequential: MeshFromQuads<PosUvColor> = MeshFromQuads::new_with_winding(
+            [288.0, 128.0],
+            true,
+            quad_limit,
+            use_indices,
+            WindingOrder::Clockwise,
+        )
+        .unwrap();
+        for quad_index in 0..quad_limit {
+            if let Some(params) = quad_params(quad_index) {
+                assert!(sequential.set(quad_index, &params));
+            }
+        }
+
+        let mut parallel: MeshFromQuads<PosUvColor> = MeshFromQuads::new_with_winding(
+            [288.0, 128.0],
+            true,
+            quad_limit,
+            use_indices,
+            WindingOrder::Clockwise,
+        )
+        .unwrap();
+        parallel.set_retain_params(true);
+        parallel.par_set_each(quad_params);
+
+        assert_eq!(parallel.vertices(), sequential.vertices());
+        assert_eq!(parallel.written_quads(), sequential.written_quads());
+        assert_eq!(
+            parallel.get_quad_params(0),
+            Some(&StoredParams::PosColorSource(quad_params(0).unwrap()))
+        );
+        assert_eq!(parallel.get_quad_params(6), None);
+    }
+}
+
+fn grid_cell(column: u32, row: u32) -> Option<(mint::Vector4<f32>, mint::Vector4<f32>, UvFlip)> {
+    // Leave a diagonal pattern of cells unset:
+    if (column + row) % 5 == 4 {
+        return None;
+    }
+    let flip = if row % 2 == 1 {
+        UvFlip::DiagonalLeft
+    } else {
+        UvFlip::Vertical
+    };
+    Some((
+        [(column % 9) as f32 * 32.0, 0.0, 32.0, 32.0].into(),
+        [1.0, 1.0, 1.0, (row % 3) as f32 / 2.0].into(),
+        flip,
+    ))
+}
+
+#[test]
+fn parallel_grid_matches_serial_fill_grid() {
+    let (columns, rows) = (37, 23);
+    let parallel: MeshFromQuads<PosUvColor> = MeshFromQuads::from_grid_parallel(
+        [288.0, 128.0],
+        true,
+        columns,
+        rows,
+        [-100.0, 50.0],
+        [24.0, 16.0],
+        grid_cell,
+    )
+    .unwrap();
+
+    let mut serial: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([288.0, 128.0], true, columns * rows).unwrap();
+    let set_quads = serial
+        .fill_grid(0, columns, rows, [-100.0, 50.0], [24.0, 16.0], grid_cell)
+        .unwrap();
+    assert!(set_quads < columns * rows);
+    assert_eq!(parallel.vertices(), serial.vertices());
+    assert_eq!(parallel.indices(), serial.indices());
+    assert_eq!(parallel.quad_limit(), serial.quad_limit());
+
+    let mut serial_set: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([288.0, 128.0], true, columns * rows).unwrap();
+    for row in 0..rows {
+        for column in 0..columns {
+            if let Some((source, color, flip)) = grid_cell(column, row) {
+                let position = [-100.0 + column as f32 * 24.0, 50.0 + row as f32 * 16.0];
+                let params = PosColorSizeSource::new(position, color, [24.0, 16.0], source, flip);
+                assert!(serial_set.set(row * columns + column, &params));
+            }
+        }
+    }
+    assert_eq!(parallel.vertices(), serial_set.vertices());
+}
+
+#[test]
+fn parallel_grid_written_quads_end_at_last_set_cell() {
+    let (columns, rows) = (4, 3);
+    // Last row is left empty:
+    let cell = |column: u32, row: u32| {
+        (row + 1 < rows).then_some((
+            mint::Vector4 {
+                x: 16.0 * column as f32,
+                y: 0.0,
+                z: 16.0,
+                w: 16.0,
+            },
+            mint::Vector4 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+                w: 1.0,
+            },
+            UvFlip::None,
+        ))
+    };
+    let parallel: MeshFromQuads<PosUvColor> = MeshFromQuads::from_grid_parallel(
+        [64.0, 64.0],
+        false,
+        columns,
+        rows,
+        [0.0, 0.0],
+        [16.0, 16.0],
+        cell,
+    )
+    .unwrap();
+    let mut serial: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, columns * rows).unwrap();
+    serial
+        .fill_grid(0, columns, rows, [0.0, 0.0], [16.0, 16.0], cell)
+        .unwrap();
+
+    assert_eq!(parallel.written_quads(), 8);
+    assert_eq!(parallel.written_quads(), serial.written_quads());
+    assert_eq!(parallel.fill_ratio(), serial.fill_ratio());
+    assert_eq!(parallel.used_vertices(), serial.used_vertices());
+
+    let empty: MeshFromQuads<PosUvColor> = MeshFromQuads::from_grid_parallel(
+        [64.0, 64.0],
+        false,
+        columns,
+        rows,
+        [0.0, 0.0],
+        [16.0, 16.0],
+        |_, _| None,
+    )
+    .unwrap();
+    assert_eq!(empty.written_quads(), 0);
+}
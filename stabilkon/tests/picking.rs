@@ -0,0 +1,64 @@
+use stabilkon::*;
+
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+#[test]
+fn picks_axis_aligned_quads() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new([64.0, 64.0], 4)
+            .with_indices(use_indices)
+            .build()
+            .unwrap();
+        builder.set_pos_color_source(0, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+        builder.set_pos_color_source(1, [32.0, 0.0], WHITE, SOURCE, UvFlip::None);
+
+        assert_eq!(builder.quad_at_point([8.0, 8.0]), Some(0));
+        assert_eq!(builder.quad_at_point([40.0, 15.0]), Some(1));
+        assert_eq!(builder.quad_at_point([16.0, 16.0]), Some(0));
+        assert_eq!(builder.quad_at_point([24.0, 8.0]), None);
+        assert_eq!(builder.quad_at_point([8.0, -1.0]), None);
+    }
+}
+
+#[test]
+fn picks_last_written_of_overlapping_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 3).unwrap();
+    builder.set_pos_color_source(0, [0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    builder.set_pos_color_source(1, [8.0, 8.0], WHITE, SOURCE, UvFlip::None);
+
+    assert_eq!(builder.quad_at_point([12.0, 12.0]), Some(1));
+    assert_eq!(builder.quad_at_point([4.0, 4.0]), Some(0));
+    // Cleared quads are degenerate, so they are never picked:
+    builder.clear_quad(1);
+    assert_eq!(builder.quad_at_point([12.0, 12.0]), Some(0));
+    assert_eq!(builder.quad_at_point([0.0, 0.0]), Some(0));
+}
+
+#[test]
+fn picks_rotated_quad_by_its_corners() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    // 32×32 square rotated by 45 degrees around its center at (16, 16) becomes a diamond:
+    builder.set(
+        0,
+        &DetailedParams::new(
+            [0.0, 0.0],
+            WHITE,
+            [16.0, 16.0],
+            [32.0, 32.0],
+            [1.0, 1.0],
+            std::f32::consts::FRAC_PI_4,
+            SOURCE,
+            UvFlip::None,
+        ),
+    );
+
+    assert_eq!(builder.quad_at_point([16.0, 16.0]), Some(0));
+    assert_eq!(builder.quad_at_point([16.0, -6.0]), Some(0));
+    assert_eq!(builder.quad_at_point([37.0, 16.0]), Some(0));
+    // Corners of the original square lie outside of the diamond, though inside of its bounding box:
+    assert_eq!(builder.quad_at_point([1.0, 1.0]), None);
+    assert_eq!(builder.quad_at_point([31.0, 31.0]), None);
+}
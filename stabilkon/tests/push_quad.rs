@@ -0,0 +1,104 @@
+mod common;
+
+use common::quad_at;
+use stabilkon::*;
+
+#[test]
+fn clear_resets_push_cursor() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    for expected_index in 0..3 {
+        assert_eq!(builder.push_quad(&quad_at(1.0)), Some(expected_index));
+    }
+
+    builder.clear();
+    assert_eq!(builder.written_quads(), 0);
+    assert_eq!(builder.push_quad(&quad_at(2.0)), Some(0));
+    assert_eq!(builder.vertices()[0].position.x, 2.0);
+}
+
+#[test]
+fn clear_keeping_cursor_appends_after_old_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    builder.push_quad(&quad_at(1.0));
+    builder.push_quad(&quad_at(1.0));
+
+    builder.clear_keeping_cursor();
+    assert_eq!(builder.vertices()[0].position.x, 0.0);
+    assert_eq!(builder.push_quad(&quad_at(2.0)), Some(2));
+    assert_eq!(builder.push_quad(&quad_at(2.0)), Some(3));
+    assert_eq!(builder.push_quad(&quad_at(2.0)), None);
+}
+
+#[test]
+fn sequential_pushes_land_at_consecutive_indices() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 3).unwrap();
+    for expected_index in 0..3 {
+        assert_eq!(
+            builder.push(&quad_at(expected_index as f32)).unwrap(),
+            expected_index
+        );
+        assert_eq!(builder.written_quads(), expected_index + 1);
+    }
+    assert_eq!(builder.vertices()[8].position.x, 2.0);
+
+    let overflow = builder.push(&quad_at(3.0));
+    assert!(matches!(
+        overflow,
+        Err(Error::QuadIndexOutOfRange {
+            index: 3,
+            limit: 3,
+            ..
+        })
+    ));
+    assert_eq!(builder.written_quads(), 3);
+}
+
+#[test]
+fn reset_cursor_overwrites_quads_from_start() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    builder.push(&quad_at(1.0)).unwrap();
+    builder.push(&quad_at(1.0)).unwrap();
+
+    builder.reset_cursor();
+    assert_eq!(builder.written_quads(), 0);
+    assert_eq!(builder.vertices()[4].position.x, 1.0);
+    assert_eq!(builder.push(&quad_at(2.0)).unwrap(), 0);
+    assert_eq!(builder.vertices()[0].position.x, 2.0);
+}
+
+#[test]
+fn reset_behaves_as_freshly_constructed() {
+    let fresh: MeshFromQuads<PosUvColor> = MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    for expected_index in 0..3 {
+        assert_eq!(builder.push(&quad_at(1.0)).unwrap(), expected_index);
+    }
+    let buffer = builder.vertices().as_ptr();
+
+    builder.reset();
+    assert_eq!(builder.written_quads(), 0);
+    assert_eq!(builder.dirty_vertex_range(), None);
+    assert_eq!(builder.vertices(), fresh.vertices());
+    assert_eq!(builder.vertices().as_ptr(), buffer);
+    assert_eq!(builder.push(&quad_at(2.0)).unwrap(), 0);
+    assert_eq!(builder.written_quads(), 1);
+}
+
+#[test]
+fn reset_empties_lazy_builder() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy([64.0, 64.0], false, 4).unwrap();
+    builder.push(&quad_at(1.0)).unwrap();
+    builder.push(&quad_at(2.0)).unwrap();
+
+    builder.reset();
+    assert!(builder.vertices().is_empty());
+    assert_eq!(builder.get_quad(0), None);
+    assert_eq!(builder.push(&quad_at(3.0)).unwrap(), 0);
+    assert_eq!(builder.vertices()[0].position.x, 3.0);
+}
@@ -0,0 +1,142 @@
+use stabilkon::*;
+
+fn builder_with_quad(use_indices: bool) -> MeshFromQuads<PosUvColor> {
+    let mut builder = MeshFromQuads::new_with_winding(
+        [64.0, 64.0],
+        false,
+        2,
+        use_indices,
+        WindingOrder::Clockwise,
+    )
+    .unwrap();
+    builder.set_retain_params(true);
+    builder.set_pos_color_source(
+        0,
+        [10.0, 20.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [16.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    );
+    builder
+}
+
+#[test]
+fn set_quad_color_keeps_positions_and_uvs() {
+    for use_indices in [true, false] {
+        let mut builder = builder_with_quad(use_indices);
+        let before = builder.used_vertices().to_vec();
+
+        assert!(builder.set_quad_color(0, [0.25, 0.5, 0.75, 1.0]));
+
+        for (old, new) in before.iter().zip(builder.used_vertices()) {
+            assert_eq!(new.position, old.position);
+            assert_eq!(new.uv, old.uv);
+            assert_eq!(new.color, [0.25, 0.5, 0.75, 1.0].into());
+        }
+        assert_eq!(builder.get_quad_params(0), None);
+    }
+}
+
+#[test]
+fn set_quad_alpha_scales_only_alpha() {
+    for use_indices in [true, false] {
+        let mut builder = builder_with_quad(use_indices);
+        assert!(builder.set_quad_color(0, [0.25, 0.5, 0.75, 0.8]));
+        let before = builder.used_vertices().to_vec();
+
+        assert!(builder.set_quad_alpha(0, 0.5));
+
+        for (old, new) in before.iter().zip(builder.used_vertices()) {
+            assert_eq!(new.position, old.position);
+            assert_eq!(new.uv, old.uv);
+            assert_eq!(new.color, [0.25, 0.5, 0.75, 0.4].into());
+        }
+    }
+}
+
+#[test]
+fn set_quad_alpha_clamps_factor_and_ignores_unwritten_quads() {
+    let mut builder = builder_with_quad(true);
+    assert!(builder.set_quad_alpha(0, 2.0));
+    assert_eq!(builder.vertices()[0].color.w, 1.0);
+    assert!(builder.set_quad_alpha(0, -1.0));
+    assert_eq!(builder.vertices()[0].color.w, 0.0);
+    assert!(!builder.set_quad_alpha(1, 0.5));
+}
+
+#[test]
+fn set_quad_color_ignores_unwritten_quads() {
+    let mut builder = builder_with_quad(true);
+    assert!(!builder.set_quad_color(1, [0.0, 0.0, 0.0, 1.0]));
+    assert!(!builder.set_quad_color(2, [0.0, 0.0, 0.0, 1.0]));
+    assert!(builder.vertices()[4..]
+        .iter()
+        .all(|vertex| vertex.color == [0.0, 0.0, 0.0, 0.0].into()));
+}
+
+#[test]
+fn move_quad_shifts_positions_only() {
+    for use_indices in [true, false] {
+        let mut builder = builder_with_quad(use_indices);
+        let before = builder.used_vertices().to_vec();
+
+        assert!(builder.move_quad(0, [5.0, -2.5]));
+
+        for (old, new) in before.iter().zip(builder.used_vertices()) {
+            assert_eq!(new.position.x, old.position.x + 5.0);
+            assert_eq!(new.position.y, old.position.y - 2.5);
+            assert_eq!(new.uv, old.uv);
+            assert_eq!(new.color, old.color);
+        }
+        assert!(!builder.move_quad(1, [5.0, -2.5]));
+    }
+}
+
+#[test]
+fn set_quad_position_moves_top_left_corner_to_target() {
+    for use_indices in [true, false] {
+        let mut builder = builder_with_quad(use_indices);
+        let before = builder.used_vertices().to_vec();
+
+        assert!(builder.set_quad_position(0, [100.0, 200.0]));
+
+        let moved = builder.used_vertices();
+        assert_eq!(moved[0].position, [100.0, 200.0].into());
+        for (old, new) in before.iter().zip(moved) {
+            assert_eq!(new.position.x, old.position.x + 90.0);
+            assert_eq!(new.position.y, old.position.y + 180.0);
+            assert_eq!(new.uv, old.uv);
+            assert_eq!(new.color, old.color);
+        }
+        assert!(!builder.set_quad_position(1, [0.0, 0.0]));
+    }
+}
+
+#[test]
+fn clone_quad_copies_uvs_and_colors_to_new_position() {
+    for use_indices in [true, false] {
+        let mut builder = builder_with_quad(use_indices);
+        assert!(builder.clone_quad(0, 1, [40.0, 5.0]));
+        assert_eq!(builder.written_quads(), 2);
+        assert_eq!(builder.get_quad_params(1), None);
+
+        let vertices_per_quad = builder.vertices_per_quad() as usize;
+        let (original, copy) = builder.vertices().split_at(vertices_per_quad);
+        for (original, copy) in original.iter().zip(copy) {
+            assert_eq!(copy.uv, original.uv);
+            assert_eq!(copy.color, original.color);
+            assert_eq!(copy.position.x, original.position.x + 30.0);
+            assert_eq!(copy.position.y, original.position.y - 15.0);
+        }
+    }
+}
+
+#[test]
+fn clone_quad_rejects_out_of_range_indices() {
+    let mut builder = builder_with_quad(true);
+    let before = builder.vertices().clone();
+    assert!(!builder.clone_quad(0, 2, [0.0, 0.0]));
+    assert!(!builder.clone_quad(2, 1, [0.0, 0.0]));
+    assert_eq!(builder.vertices(), &before);
+    assert_eq!(builder.written_quads(), 1);
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::quad_at;
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+
+#[test]
+fn set_matches_for_plain_and_typed_index() {
+    let mut plain: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    let mut typed: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+
+    assert!(plain.set(2, &quad_at(32.0)));
+    assert!(typed.set(QuadIndex(2), &quad_at(32.0)));
+
+    assert_eq!(plain.vertices(), typed.vertices());
+    assert_eq!(typed.written_quads(), 3);
+    assert_eq!(typed.get_quad(QuadIndex(2)), plain.get_quad(2));
+}
+
+#[test]
+fn set_accepts_plain_index_and_rejects_out_of_range() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 1).unwrap();
+    let quad_index: u32 = 0;
+    assert!(builder.set(quad_index, &quad_at(0.0)));
+    assert!(!builder.set(QuadIndex(1), &quad_at(16.0)));
+    assert_eq!(builder.written_quads(), 1);
+}
+
+#[test]
+fn index_taking_setters_accept_typed_indices() {
+    let white = [1.0, 1.0, 1.0, 1.0];
+    let source = [0.0, 0.0, 16.0, 16.0];
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 13).unwrap();
+    assert!(builder.fill_grid_uniform(
+        QuadIndex(0),
+        2,
+        1,
+        [16.0, 16.0],
+        [0.0, 0.0],
+        white,
+        source,
+        UvFlip::None
+    ));
+    assert_eq!(
+        builder.set_regular_polygon(QuadIndex(2), [32.0, 32.0], 8.0, 4, white, source),
+        Some(2)
+    );
+    assert!(builder.set_nine_slice(
+        QuadIndex(4),
+        [0.0, 0.0],
+        [48.0, 48.0],
+        source,
+        [4.0, 4.0, 4.0, 4.0],
+        UvFlip::None
+    ));
+    builder
+        .set_draw_order(&[QuadIndex(1), QuadIndex(0)], true)
+        .unwrap();
+    assert_eq!(builder.vertex_range_of(QuadIndex(1)), Some(4..8));
+}
+
+#[test]
+fn quad_index_converts_to_and_from_u32() {
+    assert_eq!(QuadIndex::from(7), QuadIndex(7));
+    assert_eq!(u32::from(QuadIndex(7)), 7);
+}
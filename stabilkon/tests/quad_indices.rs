@@ -0,0 +1,85 @@
+use proptest::prelude::*;
+use stabilkon::*;
+
+/// Largest quad count which still has its index count fit into u32.
+const MAX_INDEXED_QUADS: u32 = u32::MAX / 6;
+
+proptest! {
+    #[test]
+    fn indices_reference_exactly_their_quad_vertices(quad_count in 0_u32..4096) {
+        let indices = generate_quad_indices(quad_count).unwrap();
+        prop_assert_eq!(indices.len(), quad_count as usize * 6);
+        for (quad, quad_indices) in indices.chunks(6).enumerate() {
+            let first_vertex = quad as u32 * 4;
+            let mut referenced: Vec<u32> = quad_indices.to_vec();
+            referenced.sort_unstable();
+            referenced.dedup();
+            prop_assert_eq!(
+                referenced,
+                vec![first_vertex, first_vertex + 1, first_vertex + 2, first_vertex + 3]
+            );
+            // Both triangles share the diagonal between the first and the third quad vertices:
+            prop_assert_eq!(quad_indices[0], quad_indices[5]);
+            prop_assert_eq!(quad_indices[2], quad_indices[3]);
+        }
+    }
+
+    #[test]
+    fn offset_indices_are_shifted_by_start_vertex(quad_count in 0_u32..1024, start_vertex in 0_u32..1_000_000) {
+        let indices = generate_quad_indices(quad_count).unwrap();
+        let offset_indices = generate_quad_indices_offset(quad_count, start_vertex).unwrap();
+        prop_assert_eq!(indices.len(), offset_indices.len());
+        for (index, offset_index) in indices.iter().zip(offset_indices.iter()) {
+            prop_assert_eq!(index + start_vertex, *offset_index);
+        }
+    }
+
+    #[test]
+    fn index_count_fits_up_to_overflow_boundary(quad_count in 0..=MAX_INDEXED_QUADS) {
+        prop_assert_eq!(total_indices_in_quads(quad_count).unwrap(), quad_count * 6);
+    }
+
+    #[test]
+    fn overflowing_quad_count_is_rejected(quad_count in MAX_INDEXED_QUADS + 1..=u32::MAX) {
+        let is_rejected = matches!(
+            generate_quad_indices(quad_count),
+            Err(Error::QuadCountIsTooLarge { .. })
+        );
+        prop_assert!(is_rejected);
+    }
+}
+
+#[test]
+fn overflow_boundary_is_exact() {
+    assert_eq!(
+        total_indices_in_quads(MAX_INDEXED_QUADS).unwrap(),
+        MAX_INDEXED_QUADS * 6
+    );
+    assert!(matches!(
+        total_indices_in_quads(MAX_INDEXED_QUADS + 1),
+        Err(Error::QuadCountIsTooLarge { .. })
+    ));
+    assert!(matches!(
+        generate_quad_indices(MAX_INDEXED_QUADS + 1),
+        Err(Error::QuadCountIsTooLarge { .. })
+    ));
+}
+
+#[test]
+fn offset_overflow_is_rejected() {
+    assert!(matches!(
+        generate_quad_indices_offset(1, u32::MAX - 3),
+        Err(Error::QuadCountIsTooLarge { .. })
+    ));
+    assert_eq!(
+        generate_quad_indices_offset(1, u32::MAX - 4).unwrap(),
+        vec![
+            u32::MAX - 4,
+            u32::MAX - 3,
+            u32::MAX - 2,
+            u32::MAX - 2,
+            u32::MAX - 1,
+            u32::MAX - 4
+        ]
+    );
+}
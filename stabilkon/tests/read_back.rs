@@ -0,0 +1,108 @@
+use stabilkon::*;
+
+const EPSILON: f32 = 0.001;
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < EPSILON,
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+fn assert_read_back(builder: &mut MeshFromQuads<PosUvColor>, flip: UvFlip) {
+    let source = [64.0, 32.0, 32.0, 16.0];
+    let color = [0.2, 0.4, 0.6, 1.0];
+    assert!(builder.set_pos_color_source(3, [100.0, -50.0], color, source, flip));
+
+    let quad = builder.get_quad(3).unwrap();
+    assert_close(quad.position.x, 100.0);
+    assert_close(quad.position.y, -50.0);
+    assert_eq!(quad.color, color.into());
+    assert_close(quad.source.x, source[0]);
+    assert_close(quad.source.y, source[1]);
+    assert_close(quad.source.z, source[2]);
+    assert_close(quad.source.w, source[3]);
+    assert_eq!(quad.flip, flip);
+}
+
+#[test]
+fn quad_is_read_back_from_indexed_builder() {
+    for &use_half_pixel_offset in &[false, true] {
+        let mut builder: MeshFromQuads<PosUvColor> =
+            MeshFromQuads::new([256.0, 128.0], use_half_pixel_offset, 4).unwrap();
+        for &flip in &[
+            UvFlip::None,
+            UvFlip::Horizontal,
+            UvFlip::Vertical,
+            UvFlip::Both,
+        ] {
+            assert_read_back(&mut builder, flip);
+        }
+    }
+}
+
+#[test]
+fn quad_is_read_back_from_unindexed_builder() {
+    for &use_half_pixel_offset in &[false, true] {
+        let mut builder: MeshFromQuads<PosUvColor> =
+            MeshFromQuads::new_without_indices([256.0, 128.0], use_half_pixel_offset, 4).unwrap();
+        for &flip in &[
+            UvFlip::None,
+            UvFlip::Horizontal,
+            UvFlip::Vertical,
+            UvFlip::Both,
+        ] {
+            assert_read_back(&mut builder, flip);
+        }
+    }
+}
+
+#[test]
+fn out_of_range_quad_is_not_read_back() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([256.0, 128.0], false, 4).unwrap();
+    assert!(builder.get_quad(4).is_none());
+}
+
+#[test]
+fn quads_iterates_written_quads_in_order() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([256.0, 128.0], true, 8).unwrap();
+    let expected = [
+        PosColorSource::new(
+            [0.0, 0.0],
+            [1.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 16.0, 16.0],
+            UvFlip::None,
+        ),
+        PosColorSource::new(
+            [16.0, 0.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [16.0, 0.0, 32.0, 16.0],
+            UvFlip::Vertical,
+        ),
+        PosColorSource::new(
+            [48.0, 8.0],
+            [0.0, 0.0, 1.0, 0.5],
+            [64.0, 32.0, 8.0, 8.0],
+            UvFlip::Both,
+        ),
+    ];
+    for (quad_index, params) in expected.iter().enumerate() {
+        assert!(builder.set(quad_index as u32, params));
+    }
+
+    let quads: Vec<PosColorSource> = builder.quads().collect();
+    assert_eq!(quads.len(), 3);
+    for (quad, expected) in quads.iter().zip(&expected) {
+        assert_close(quad.position.x, expected.position.x);
+        assert_close(quad.position.y, expected.position.y);
+        assert_eq!(quad.color, expected.color);
+        assert_close(quad.source.x, expected.source.x);
+        assert_close(quad.source.y, expected.source.y);
+        assert_close(quad.source.z, expected.source.z);
+        assert_close(quad.source.w, expected.source.w);
+        assert_eq!(quad.flip, expected.flip);
+    }
+}
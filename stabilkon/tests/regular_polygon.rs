@@ -0,0 +1,89 @@
+use stabilkon::*;
+
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOLID: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+
+/// Collects signed areas of all triangles drawn by the builder's used quads.
+fn triangle_areas(builder: &MeshFromQuads<PosUvColor>) -> Vec<f32> {
+    let vertices = builder.vertices();
+    builder
+        .used_indices()
+        .chunks(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| vertices[triangle[i] as usize].position);
+            ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)) / 2.0
+        })
+        .collect()
+}
+
+fn assert_polygon_drawn(sides: u32, expected_quads: u32) {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([1.0, 1.0], false, 8).unwrap();
+    let radius = 10.0;
+    assert_eq!(
+        builder.set_regular_polygon(0, [5.0, -5.0], radius, sides, WHITE, SOLID),
+        Some(expected_quads)
+    );
+    assert_eq!(regular_polygon_quad_count(sides), expected_quads);
+    assert_eq!(builder.written_quads(), expected_quads);
+
+    // Polygon points lie on the circle, and every quad starts at the center:
+    for quad in builder.used_vertices().chunks(4) {
+        assert_eq!((quad[0].position.x, quad[0].position.y), (5.0, -5.0));
+        for vertex in &quad[1..] {
+            let distance = (vertex.position.x - 5.0).hypot(vertex.position.y + 5.0);
+            assert!((distance - radius).abs() < 1e-4);
+        }
+    }
+
+    // Fan triangles share the winding of a regular quad and cover the whole polygon:
+    let mut reference: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([1.0, 1.0], false, 1).unwrap();
+    reference.set_pos_color_size_source(0, [0.0, 0.0], WHITE, [1.0, 1.0], SOLID, UvFlip::None);
+    let reference_sign = triangle_areas(&reference)[0].signum();
+    let areas = triangle_areas(&builder);
+    let drawn: Vec<f32> = areas.into_iter().filter(|area| area.abs() > 1e-6).collect();
+    assert_eq!(drawn.len() as u32, sides);
+    assert!(drawn.iter().all(|area| area.signum() == reference_sign));
+    let total_area: f32 = drawn.iter().map(|area| area.abs()).sum();
+    let expected_area =
+        sides as f32 / 2.0 * radius * radius * (std::f32::consts::TAU / sides as f32).sin();
+    assert!((total_area - expected_area).abs() < 1e-3);
+}
+
+#[test]
+fn square_takes_two_quads() {
+    assert_polygon_drawn(4, 2);
+}
+
+#[test]
+fn hexagon_takes_three_quads() {
+    assert_polygon_drawn(6, 3);
+}
+
+#[test]
+fn octagon_takes_four_quads() {
+    assert_polygon_drawn(8, 4);
+}
+
+#[test]
+fn odd_sides_pad_last_quad_with_degenerate_triangle() {
+    assert_polygon_drawn(5, 3);
+}
+
+#[test]
+fn polygon_not_fitting_is_not_set() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([1.0, 1.0], false, 4).unwrap();
+    assert_eq!(
+        builder.set_regular_polygon(1, [0.0, 0.0], 1.0, 8, WHITE, SOLID),
+        None
+    );
+    assert_eq!(
+        builder.set_regular_polygon(0, [0.0, 0.0], 1.0, 2, WHITE, SOLID),
+        None
+    );
+    assert_eq!(
+        builder.set_regular_polygon(u32::MAX, [0.0, 0.0], 1.0, 3, WHITE, SOLID),
+        None
+    );
+    assert_eq!(builder.written_quads(), 0);
+}
@@ -0,0 +1,49 @@
+use stabilkon::*;
+
+fn set_quad(builder: &mut MeshFromQuads<PosUvColor>, quad_index: u32) -> bool {
+    builder.set_pos_color_source(
+        quad_index,
+        [quad_index as f32 * 16.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    )
+}
+
+#[test]
+fn resize_preserves_written_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    assert!(set_quad(&mut builder, 0));
+    assert!(set_quad(&mut builder, 1));
+    let written_vertices = builder.vertices().clone();
+
+    builder.resize(5).unwrap();
+    assert_eq!(builder.quad_limit(), 5);
+    assert_eq!(builder.vertices_limit(), 5 * 4);
+    assert_eq!(builder.vertices().len(), 5 * 4);
+    assert_eq!(builder.indices().unwrap().len(), 5 * 6);
+    assert_eq!(&builder.vertices()[..8], written_vertices.as_slice());
+    assert!(set_quad(&mut builder, 4));
+    assert_eq!(builder.written_quads(), 5);
+
+    builder.resize(1).unwrap();
+    assert_eq!(builder.quad_limit(), 1);
+    assert_eq!(builder.vertices_limit(), 4);
+    assert_eq!(builder.written_quads(), 1);
+    assert_eq!(builder.indices().unwrap().len(), 6);
+    assert_eq!(builder.vertices().as_slice(), &written_vertices[..4]);
+    assert!(!set_quad(&mut builder, 1));
+}
+
+#[test]
+fn resize_rejects_overflowing_limit() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 2).unwrap();
+    assert!(matches!(
+        builder.resize(u32::MAX),
+        Err(Error::QuadCountIsTooLarge { .. })
+    ));
+    assert_eq!(builder.quad_limit(), 2);
+    assert_eq!(builder.vertices_limit(), 2 * 6);
+}
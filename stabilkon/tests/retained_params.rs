@@ -0,0 +1,32 @@
+use stabilkon::*;
+
+#[test]
+fn rotated_params_round_trip_when_retained() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 4).unwrap();
+    let params = DetailedParams::new(
+        [10.0, 20.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [16.0, 16.0],
+        [32.0, 32.0],
+        [2.0, 2.0],
+        0.5,
+        [0.0, 0.0, 32.0, 32.0],
+        UvFlip::None,
+    );
+
+    assert!(builder.set(0, &params));
+    assert_eq!(builder.get_quad_params(0), None);
+
+    builder.set_retain_params(true);
+    assert!(builder.set(1, &params));
+    assert_eq!(
+        builder.get_quad_params(1),
+        Some(&StoredParams::DetailedParams(params))
+    );
+    assert_eq!(builder.get_quad_params(0), None);
+    assert_eq!(builder.get_quad_params(4), None);
+
+    builder.clear();
+    assert_eq!(builder.get_quad_params(1), None);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use stabilkon::*;
+
+#[test]
+fn filled_builder_round_trips_through_json() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([64.0, 32.0], true, 4).unwrap();
+    builder.set_retain_params(true);
+    builder.set_alpha_mode(AlphaMode::UnpremultiplyColor);
+    builder.set_pos_color_source(
+        0,
+        [0.0, 0.0],
+        [1.0, 0.5, 0.5, 0.5],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    );
+    builder.set_pos_color_size_source(
+        2,
+        [16.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [32.0, 32.0],
+        [16.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    );
+
+    let json = serde_json::to_string(&builder).unwrap();
+    let loaded: MeshFromQuads<PosUvColor> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(loaded.vertices(), builder.vertices());
+    assert_eq!(loaded.indices(), builder.indices());
+    assert_eq!(loaded.quad_limit(), 4);
+    assert_eq!(loaded.vertices_limit(), builder.vertices_limit());
+    assert_eq!(loaded.vertices_per_quad(), 4);
+    assert_eq!(loaded.written_quads(), 3);
+    assert_eq!(loaded.alpha_mode(), AlphaMode::UnpremultiplyColor);
+    assert_eq!(loaded.get_quad_params(2), builder.get_quad_params(2));
+}
+
+#[test]
+fn quad_limit_is_rebuilt_from_loaded_vertices() {
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 3).unwrap();
+    let mut value = serde_json::to_value(&builder).unwrap();
+    value["vertices"].as_array_mut().unwrap().truncate(6);
+    let loaded: MeshFromQuads<PosUvColor> = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(loaded.quad_limit(), 1);
+    assert_eq!(loaded.vertices_limit(), 6);
+
+    value["vertices"].as_array_mut().unwrap().truncate(5);
+    assert!(serde_json::from_value::<MeshFromQuads<PosUvColor>>(value).is_err());
+}
+
+#[test]
+fn lazy_builder_keeps_its_quad_limit() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy([64.0, 64.0], false, 100).unwrap();
+    builder.set_pos_color_source(
+        2,
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    );
+
+    let json = serde_json::to_string(&builder).unwrap();
+    let loaded: MeshFromQuads<PosUvColor> = serde_json::from_str(&json).unwrap();
+
+    assert!(loaded.is_lazy());
+    assert_eq!(loaded.quad_limit(), 100);
+    assert_eq!(loaded.vertices_limit(), 400);
+    assert_eq!(loaded.vertices(), builder.vertices());
+    assert_eq!(loaded.written_quads(), 3);
+}
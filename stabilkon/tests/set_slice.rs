@@ -0,0 +1,37 @@
+mod common;
+
+use common::quad_at;
+use stabilkon::*;
+
+#[test]
+fn set_slice_writes_consecutive_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 5).unwrap();
+    let params = [quad_at(10.0), quad_at(20.0), quad_at(30.0)];
+    assert!(builder.set_slice(1, &params));
+
+    assert_eq!(builder.written_quads(), 4);
+    for (quad_index, expected_x) in [(1, 10.0), (2, 20.0), (3, 30.0)] {
+        let vertices = &builder.vertices()[quad_index * 4..quad_index * 4 + 4];
+        assert_eq!(vertices[0].position, [expected_x, 0.0].into());
+        assert_eq!(vertices[2].position, [expected_x + 16.0, 16.0].into());
+    }
+    assert_eq!(builder.vertices()[0].position, [0.0, 0.0].into());
+}
+
+#[test]
+fn too_large_slice_writes_nothing() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 3).unwrap();
+    let before = builder.vertices().to_vec();
+    let params = [quad_at(10.0), quad_at(20.0), quad_at(30.0)];
+
+    assert!(!builder.set_slice(1, &params));
+    assert!(!builder.set_slice(u32::MAX, &params));
+    assert_eq!(builder.vertices(), &before[..]);
+    assert_eq!(builder.written_quads(), 0);
+    assert_eq!(builder.dirty_vertex_range(), None);
+
+    assert!(builder.set_slice(0, &params));
+    assert_eq!(builder.written_quads(), 3);
+}
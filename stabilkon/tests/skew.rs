@@ -0,0 +1,54 @@
+use stabilkon::*;
+
+#[test]
+fn horizontal_skew_displaces_bottom_corners_only() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    assert!(builder.set_pos_color_skew_source(
+        0,
+        [10.0, 20.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [32.0, 16.0],
+        [0.5, 0.0],
+        [0.0, 0.0, 32.0, 16.0],
+        UvFlip::None,
+    ));
+
+    let positions: Vec<_> = builder
+        .vertices()
+        .iter()
+        .map(|v| (v.position.x, v.position.y))
+        .collect();
+    // Top edge stays put, bottom edge is shifted by skew.x * height:
+    assert_eq!(
+        positions,
+        vec![(10.0, 20.0), (18.0, 36.0), (50.0, 36.0), (42.0, 20.0)]
+    );
+}
+
+#[test]
+fn vertical_skew_displaces_right_corners_only() {
+    let params = PosColorSkewSource::new(
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [10.0, 10.0],
+        [0.0, -0.25],
+        [0.0, 0.0, 10.0, 10.0],
+        UvFlip::None,
+    );
+    let mut vertices = [PosUvColor::new([0.0, 0.0], [0.0, 0.0], [0.0, 0.0, 0.0, 0.0]); 4];
+    params.set_vertices(
+        [64.0, 64.0].into(),
+        VertexOptions::new(false, true),
+        0,
+        &mut vertices,
+    );
+    let positions: Vec<_> = vertices
+        .iter()
+        .map(|v| (v.position.x, v.position.y))
+        .collect();
+    assert_eq!(
+        positions,
+        vec![(0.0, 0.0), (0.0, 10.0), (10.0, 7.5), (10.0, -2.5)]
+    );
+}
@@ -0,0 +1,76 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 8.0, 8.0];
+const Y_POSITIONS: [f32; 4] = [30.0, 10.0, 20.0, 10.0];
+
+fn top_left_y(quad_vertices: &[PosUvColor]) -> i32 {
+    quad_vertices[0].position.y as i32
+}
+
+fn fill(builder: &mut MeshFromQuads<PosUvColor>) {
+    for (quad_index, &y) in Y_POSITIONS.iter().enumerate() {
+        let x = quad_index as f32 * 8.0;
+        builder.push_quad(&PosColorSource::new([x, y], WHITE, SOURCE, UvFlip::None));
+    }
+}
+
+fn top_left_positions(builder: &MeshFromQuads<PosUvColor>) -> Vec<(f32, f32)> {
+    builder
+        .used_vertices()
+        .chunks_exact(builder.vertices_per_quad() as usize)
+        .map(|quad_vertices| (quad_vertices[0].position.x, quad_vertices[0].position.y))
+        .collect()
+}
+
+#[test]
+fn sorts_unindexed_quads_by_top_left_y() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 6).unwrap();
+    fill(&mut builder);
+    builder.sort_quads_by(top_left_y);
+
+    // Quads with equal keys keep their relative order:
+    assert_eq!(
+        top_left_positions(&builder),
+        vec![(8.0, 10.0), (24.0, 10.0), (16.0, 20.0), (0.0, 30.0)]
+    );
+    assert_eq!(builder.written_quads(), 4);
+    assert_eq!(builder.dirty_vertex_range(), Some(0..24));
+}
+
+#[test]
+fn sorts_indexed_quads_and_resets_draw_order() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 6).unwrap();
+    fill(&mut builder);
+    builder.set_draw_order(&[3, 2, 1, 0], true).unwrap();
+    builder.sort_quads_by(top_left_y);
+
+    assert_eq!(
+        top_left_positions(&builder),
+        vec![(8.0, 10.0), (24.0, 10.0), (16.0, 20.0), (0.0, 30.0)]
+    );
+    assert_eq!(builder.indices(), Some(&generate_quad_indices(6).unwrap()));
+}
+
+#[test]
+fn sorting_moves_retained_params_along() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 6).unwrap();
+    builder.set_retain_params(true);
+    fill(&mut builder);
+    builder.sort_quads_by(|quad_vertices| -top_left_y(quad_vertices));
+
+    let params = builder.get_quad_params(0).unwrap();
+    assert_eq!(
+        params,
+        &StoredParams::PosColorSource(PosColorSource::new(
+            [0.0, 30.0],
+            WHITE,
+            SOURCE,
+            UvFlip::None
+        ))
+    );
+}
@@ -0,0 +1,64 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 32.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+fn quad_with_source(source: [f32; 4]) -> PosColorSource {
+    PosColorSource::new([0.0, 0.0], WHITE, source, UvFlip::None)
+}
+
+#[test]
+fn checked_set_rejects_over_wide_source() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 2).unwrap();
+    let result = builder.checked_set(0, &quad_with_source([48.0, 0.0, 32.0, 16.0]));
+    assert!(matches!(
+        result,
+        Err(Error::SourceOutOfBounds { quad_index: 0, .. })
+    ));
+    assert_eq!(builder.written_quads(), 0);
+
+    let result = builder.checked_set(0, &quad_with_source([0.0, -1.0, 16.0, 16.0]));
+    assert!(matches!(result, Err(Error::SourceOutOfBounds { .. })));
+}
+
+#[test]
+fn checked_set_accepts_sources_up_to_texture_edges() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, true, 2).unwrap();
+    builder
+        .checked_set(0, &quad_with_source([0.0, 0.0, 64.0, 32.0]))
+        .unwrap();
+    builder
+        .checked_set(1, &quad_with_source([64.0, 16.0, -16.0, 16.0]))
+        .unwrap();
+    assert_eq!(builder.written_quads(), 2);
+    assert!(matches!(
+        builder.checked_set(2, &quad_with_source([0.0, 0.0, 16.0, 16.0])),
+        Err(Error::QuadIndexOutOfRange { .. })
+    ));
+}
+
+#[test]
+fn clamp_source_fits_over_wide_source_into_texture() {
+    let clamped = clamp_source(TEXTURE_SIZE, [48.0, 0.0, 32.0, 16.0]);
+    assert_eq!(clamped, [48.0, 0.0, 16.0, 16.0].into());
+
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 1).unwrap();
+    builder
+        .checked_set(0, &quad_with_source(clamped.into()))
+        .unwrap();
+    assert_eq!(builder.vertices()[2].uv.x, 1.0);
+}
+
+#[test]
+fn clamp_source_keeps_in_bounds_and_mirrored_sources() {
+    assert_eq!(
+        clamp_source(TEXTURE_SIZE, [8.0, 8.0, 16.0, 16.0]),
+        [8.0, 8.0, 16.0, 16.0].into()
+    );
+    assert_eq!(
+        clamp_source(TEXTURE_SIZE, [72.0, -8.0, -16.0, 16.0]),
+        [64.0, 0.0, -8.0, 8.0].into()
+    );
+}
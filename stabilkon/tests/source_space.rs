@@ -0,0 +1,83 @@
+mod common;
+
+use common::{emitted, WHITE};
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [256.0, 128.0];
+const PIXEL_SOURCE: [f32; 4] = [64.0, 32.0, 32.0, 16.0];
+const NORMALIZED_SOURCE: [f32; 4] = [0.25, 0.25, 0.125, 0.125];
+
+#[test]
+fn normalized_source_matches_pixel_source() {
+    for flip in [
+        UvFlip::None,
+        UvFlip::Horizontal,
+        UvFlip::Vertical,
+        UvFlip::Both,
+    ] {
+        let pixels = PosColorSource::new([10.0, 10.0], WHITE, PIXEL_SOURCE, flip);
+        let normalized = PosColorSource::new([10.0, 10.0], WHITE, NORMALIZED_SOURCE, flip)
+            .with_source_space(SourceSpace::Normalized);
+        // Quad size is derived from the source size in pixels in both cases:
+        assert_eq!(
+            emitted(TEXTURE_SIZE, false, &pixels),
+            emitted(TEXTURE_SIZE, false, &normalized)
+        );
+    }
+}
+
+#[test]
+fn normalized_source_works_for_sized_and_detailed_params() {
+    let pixels = PosColorSizeSource::new(
+        [0.0, 0.0],
+        WHITE,
+        [8.0, 8.0],
+        PIXEL_SOURCE,
+        UvFlip::Vertical,
+    );
+    let normalized = PosColorSizeSource::new(
+        [0.0, 0.0],
+        WHITE,
+        [8.0, 8.0],
+        NORMALIZED_SOURCE,
+        UvFlip::Vertical,
+    )
+    .with_source_space(SourceSpace::Normalized);
+    assert_eq!(
+        emitted(TEXTURE_SIZE, false, &pixels),
+        emitted(TEXTURE_SIZE, false, &normalized)
+    );
+
+    let detailed = |source, source_space| {
+        DetailedParams::new(
+            [5.0, 5.0],
+            WHITE,
+            [4.0, 4.0],
+            [8.0, 8.0],
+            [1.0, 1.0],
+            0.3,
+            source,
+            UvFlip::None,
+        )
+        .with_source_space(source_space)
+    };
+    assert_eq!(
+        emitted(
+            TEXTURE_SIZE,
+            false,
+            &detailed(PIXEL_SOURCE, SourceSpace::Pixels)
+        ),
+        emitted(
+            TEXTURE_SIZE,
+            false,
+            &detailed(NORMALIZED_SOURCE, SourceSpace::Normalized)
+        )
+    );
+}
+
+#[test]
+fn pixels_are_the_default_source_space() {
+    let params = PosColorSource::new([0.0, 0.0], WHITE, PIXEL_SOURCE, UvFlip::None);
+    assert_eq!(params.source_space, SourceSpace::Pixels);
+    assert_eq!(SourceSpace::default(), SourceSpace::Pixels);
+}
@@ -0,0 +1,44 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [256.0, 128.0];
+const SOURCE: [f32; 4] = [64.0, 32.0, 32.0, 16.0];
+
+fn as_pairs(uvs: [mint::Vector2<f32>; 4]) -> [(f32, f32); 4] {
+    uvs.map(|uv| (uv.x * TEXTURE_SIZE[0], uv.y * TEXTURE_SIZE[1]))
+}
+
+#[test]
+fn corner_uvs_without_half_pixel_offset() {
+    let uvs = source_to_uvs(TEXTURE_SIZE, false, SOURCE, UvFlip::None);
+    // Default bottom-to-top system puts the source bottom edge at the top corners:
+    assert_eq!(
+        as_pairs(uvs),
+        [(64.0, 48.0), (64.0, 32.0), (96.0, 32.0), (96.0, 48.0)]
+    );
+
+    let uvs = source_to_uvs(TEXTURE_SIZE, false, SOURCE, UvFlip::Vertical);
+    assert_eq!(
+        as_pairs(uvs),
+        [(64.0, 32.0), (64.0, 48.0), (96.0, 48.0), (96.0, 32.0)]
+    );
+}
+
+#[test]
+fn corner_uvs_with_half_pixel_offset() {
+    let uvs = source_to_uvs(TEXTURE_SIZE, true, SOURCE, UvFlip::Vertical);
+    assert_eq!(
+        as_pairs(uvs),
+        [(64.5, 32.5), (64.5, 47.5), (95.5, 47.5), (95.5, 32.5)]
+    );
+}
+
+#[test]
+fn corner_uvs_match_written_vertices() {
+    for flip in [UvFlip::None, UvFlip::Both, UvFlip::DiagonalLeft] {
+        let mut builder: MeshFromQuads<PosUvColor> =
+            MeshFromQuads::new(TEXTURE_SIZE, true, 1).unwrap();
+        builder.set_pos_color_source(0, [0.0, 0.0], [1.0, 1.0, 1.0, 1.0], SOURCE, flip);
+        let written: Vec<_> = builder.vertices().iter().map(|vertex| vertex.uv).collect();
+        assert_eq!(written, source_to_uvs(TEXTURE_SIZE, true, SOURCE, flip));
+    }
+}
@@ -0,0 +1,93 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+// Spans U from 0.25 to 0.75 and V from 0 to 0.25:
+const SOURCE: [f32; 4] = [16.0, 0.0, 32.0, 16.0];
+const FLIPS: [UvFlip; 6] = [
+    UvFlip::None,
+    UvFlip::Horizontal,
+    UvFlip::Vertical,
+    UvFlip::Both,
+    UvFlip::DiagonalLeft,
+    UvFlip::DiagonalRight,
+];
+
+fn corner_uvs<T: QuadDrawParams>(params: &T, uv_inset: impl Into<UvInset>) -> Vec<(f32, f32)> {
+    let vertices: Vec<PosUvColor> =
+        params.to_vertices(TEXTURE_SIZE.into(), VertexOptions::new(uv_inset, true));
+    vertices.iter().map(|v| (v.uv.x, v.uv.y)).collect()
+}
+
+fn window(offset: [f32; 2], scale: [f32; 2], flip: UvFlip) -> PosColorSubUvSource {
+    PosColorSubUvSource::new([0.0, 0.0], WHITE, SOURCE, offset, scale, flip)
+}
+
+#[test]
+fn full_window_matches_plain_quad() {
+    for flip in FLIPS {
+        let plain = PosColorSource::new([0.0, 0.0], WHITE, SOURCE, flip);
+        for uv_inset in [false, true] {
+            assert_eq!(
+                corner_uvs(&window([0.0, 0.0], [1.0, 1.0], flip), uv_inset),
+                corner_uvs(&plain, uv_inset),
+                "{:?}",
+                flip
+            );
+        }
+    }
+}
+
+#[test]
+fn sub_rectangle_window_covers_part_of_source() {
+    // Right half of the source horizontally, second quarter from the top vertically:
+    let uvs = corner_uvs(&window([0.5, 0.25], [0.5, 0.5], UvFlip::None), false);
+    assert_eq!(
+        uvs,
+        vec![(0.5, 0.1875), (0.5, 0.0625), (0.75, 0.0625), (0.75, 0.1875)]
+    );
+}
+
+#[test]
+fn mirroring_flips_move_window_in_quad_orientation() {
+    let horizontal = corner_uvs(&window([0.5, 0.25], [0.5, 0.5], UvFlip::Horizontal), false);
+    assert_eq!(
+        horizontal,
+        vec![(0.5, 0.1875), (0.5, 0.0625), (0.25, 0.0625), (0.25, 0.1875)]
+    );
+    let vertical = corner_uvs(&window([0.5, 0.25], [0.5, 0.5], UvFlip::Vertical), false);
+    assert_eq!(
+        vertical,
+        vec![(0.5, 0.0625), (0.5, 0.1875), (0.75, 0.1875), (0.75, 0.0625)]
+    );
+    let both = corner_uvs(&window([0.5, 0.25], [0.5, 0.5], UvFlip::Both), false);
+    assert_eq!(
+        both,
+        vec![(0.5, 0.0625), (0.5, 0.1875), (0.25, 0.1875), (0.25, 0.0625)]
+    );
+}
+
+#[test]
+fn diagonal_flip_rearranges_window_corners() {
+    let uvs = corner_uvs(
+        &window([0.5, 0.25], [0.5, 0.5], UvFlip::DiagonalLeft),
+        false,
+    );
+    assert_eq!(
+        uvs,
+        vec![(0.5, 0.0625), (0.75, 0.0625), (0.75, 0.1875), (0.5, 0.1875)]
+    );
+}
+
+#[test]
+fn window_stays_inside_inset_source() {
+    // Half pixel inset is 1/128 of the texture, so the corrected source spans U from 0.2578125 to 0.7421875:
+    let uvs = corner_uvs(&window([0.0, 0.0], [0.5, 1.0], UvFlip::None), true);
+    let (min_u, max_u) = uvs
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), &(u, _)| {
+            (min.min(u), max.max(u))
+        });
+    assert_eq!(min_u, 0.2578125);
+    assert_eq!(max_u, 0.5);
+}
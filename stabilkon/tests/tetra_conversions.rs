@@ -0,0 +1,94 @@
+#![cfg(feature = "tetra")]
+
+use stabilkon::*;
+use tetra::graphics::{Color, Rectangle};
+
+#[test]
+fn color_round_trips_field_by_field() {
+    let color = Color::rgba(0.1, 0.2, 0.3, 0.4);
+    let converted = color_from_tetra(color);
+    assert_eq!(
+        (converted.x, converted.y, converted.z, converted.w),
+        (0.1, 0.2, 0.3, 0.4)
+    );
+    assert_eq!(color_into_tetra(converted), color);
+}
+
+#[test]
+fn rectangle_round_trips_field_by_field() {
+    let rectangle = Rectangle::new(1.0, 2.0, 3.0, 4.0);
+    let converted = rectangle_from_tetra(rectangle);
+    assert_eq!(
+        (converted.x, converted.y, converted.z, converted.w),
+        (1.0, 2.0, 3.0, 4.0)
+    );
+    assert_eq!(rectangle_into_tetra(converted), rectangle);
+}
+
+#[test]
+fn converted_types_are_accepted_by_setters() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    assert!(builder.set_pos_color_source(
+        0,
+        [0.0, 0.0],
+        color_from_tetra(Color::RED),
+        rectangle_from_tetra(Rectangle::new(0.0, 0.0, 16.0, 16.0)),
+        UvFlip::None,
+    ));
+    assert_eq!(builder.vertices()[0].color, [1.0, 0.0, 0.0, 1.0].into());
+}
+
+fn corners_of(params: &DetailedParams) -> Vec<[f32; 2]> {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    assert!(builder.set(0, params));
+    builder
+        .vertices()
+        .iter()
+        .map(|vertex| [vertex.position.x, vertex.position.y])
+        .collect()
+}
+
+fn assert_close(actual: &[[f32; 2]], expected: &[[f32; 2]]) {
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!(
+            (actual[0] - expected[0]).abs() < 0.001 && (actual[1] - expected[1]).abs() < 0.001,
+            "{:?} is not close to {:?}",
+            actual,
+            expected
+        );
+    }
+}
+
+#[test]
+fn tetra_draw_params_rotate_around_origin_in_texels() {
+    let params = tetra::graphics::DrawParams::new()
+        .position(tetra::math::Vec2::new(10.0, 10.0))
+        .origin(tetra::math::Vec2::new(8.0, 8.0))
+        .rotation(std::f32::consts::FRAC_PI_2)
+        .color(Color::RED);
+    let detailed = DetailedParams::from_tetra_draw_params(&params, [0.0, 0.0, 16.0, 16.0]);
+    assert_eq!(detailed.color, [1.0, 0.0, 0.0, 1.0].into());
+    assert_eq!(detailed.flip, UvFlip::Vertical);
+    // Tetra places the origin at the position, then rotates corners around it:
+    assert_close(
+        &corners_of(&detailed),
+        &[[18.0, 2.0], [2.0, 2.0], [2.0, 18.0], [18.0, 18.0]],
+    );
+}
+
+#[test]
+fn tetra_negative_scale_flips_quad_in_place() {
+    let params = tetra::graphics::DrawParams::new()
+        .position(tetra::math::Vec2::new(100.0, 50.0))
+        .origin(tetra::math::Vec2::new(8.0, 4.0))
+        .scale(tetra::math::Vec2::new(2.0, -1.0));
+    let detailed = DetailedParams::from_tetra_draw_params(&params, [0.0, 0.0, 16.0, 8.0]);
+    // Mirrored vertically around the origin, which cancels out top-to-bottom UV flip:
+    assert_eq!(detailed.flip, UvFlip::None);
+    assert_close(
+        &corners_of(&detailed),
+        &[[84.0, 46.0], [84.0, 54.0], [116.0, 54.0], [116.0, 46.0]],
+    );
+}
@@ -0,0 +1,75 @@
+use stabilkon::*;
+
+#[test]
+fn tex_index_propagates_to_every_vertex() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColorTex> = MeshFromQuads::new_with_winding(
+            [64.0, 64.0],
+            false,
+            2,
+            use_indices,
+            WindingOrder::Clockwise,
+        )
+        .unwrap();
+        assert!(builder.set_pos_color_tex_source(
+            1,
+            [16.0, 0.0],
+            3.0,
+            [1.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 16.0, 16.0],
+            UvFlip::Vertical,
+        ));
+        assert!(!builder.set_pos_color_tex_source(
+            2,
+            [0.0, 0.0],
+            3.0,
+            [1.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 16.0, 16.0],
+            UvFlip::None
+        ));
+
+        let vertices_per_quad = builder.vertices_per_quad() as usize;
+        assert!(builder.vertices()[vertices_per_quad..]
+            .iter()
+            .all(|vertex| vertex.tex_index == 3.0));
+        // Regular quads sample the first texture:
+        builder.set_pos_color_source(
+            0,
+            [0.0, 0.0],
+            [1.0, 1.0, 1.0, 1.0],
+            [0.0, 0.0, 16.0, 16.0],
+            UvFlip::None,
+        );
+        assert!(builder.vertices()[..vertices_per_quad]
+            .iter()
+            .all(|vertex| vertex.tex_index == 0.0));
+    }
+}
+
+#[test]
+fn tex_params_match_regular_quad_geometry() {
+    let params = PosColorTexSource::new(
+        [8.0, 4.0],
+        1.0,
+        [0.5, 0.5, 0.5, 1.0],
+        [16.0, 16.0, 16.0, 16.0],
+        UvFlip::Both,
+    );
+    let vertices: Vec<PosUvColorTex> =
+        params.to_vertices_tex([64.0, 64.0].into(), VertexOptions::new(false, true));
+    let mut regular: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    regular.set_pos_color_source(
+        0,
+        [8.0, 4.0],
+        [0.5, 0.5, 0.5, 1.0],
+        [16.0, 16.0, 16.0, 16.0],
+        UvFlip::Both,
+    );
+    for (vertex, expected) in vertices.iter().zip(regular.vertices()) {
+        assert_eq!(vertex.position, expected.position);
+        assert_eq!(vertex.uv, expected.uv);
+        assert_eq!(vertex.color, expected.color);
+        assert_eq!(vertex.tex_index, 1.0);
+    }
+}
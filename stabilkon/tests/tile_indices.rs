@@ -0,0 +1,87 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [128.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+fn atlas() -> AtlasGrid {
+    AtlasGrid::new(TEXTURE_SIZE, [32.0, 32.0], [0.0, 0.0], [0.0, 0.0])
+}
+
+#[test]
+fn tiles_are_set_at_their_map_cells() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 6).unwrap();
+    #[rustfmt::skip]
+    let tiles = [
+        0, 3, EMPTY_TILE,
+        5, 100, 1,
+    ];
+    assert!(builder.fill_from_tile_indices(
+        &tiles,
+        3,
+        &atlas(),
+        [16.0, 16.0],
+        [100.0, 50.0],
+        WHITE,
+        UvFlip::None,
+    ));
+
+    let quad = builder.get_quad(1).unwrap();
+    assert_eq!(quad.position, [116.0, 50.0].into());
+    assert_eq!(quad.source, [96.0, 0.0, 32.0, 32.0].into());
+    let quad = builder.get_quad(3).unwrap();
+    assert_eq!(quad.position, [100.0, 66.0].into());
+    assert_eq!(quad.source, [32.0, 32.0, 32.0, 32.0].into());
+    let quad = builder.get_quad(5).unwrap();
+    assert_eq!(quad.position, [132.0, 66.0].into());
+    assert_eq!(quad.source, [32.0, 0.0, 32.0, 32.0].into());
+    // Quads are scaled to the map tile size:
+    assert_eq!(builder.vertices()[22].position, [148.0, 82.0].into());
+}
+
+#[test]
+fn empty_and_unknown_tiles_are_cleared() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    builder.fill_grid_uniform(
+        0,
+        3,
+        1,
+        [16.0, 16.0],
+        [0.0, 0.0],
+        WHITE,
+        [0.0, 0.0, 32.0, 32.0],
+        UvFlip::None,
+    );
+    assert!(builder.fill_from_tile_indices(
+        &[2, EMPTY_TILE, 8],
+        3,
+        &atlas(),
+        [16.0, 16.0],
+        [0.0, 0.0],
+        WHITE,
+        UvFlip::None,
+    ));
+    #[cfg(debug_assertions)]
+    assert_eq!(builder.assert_fully_written(), Err(vec![1, 2]));
+}
+
+#[test]
+fn oversized_maps_are_rejected() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    let fill = |builder: &mut MeshFromQuads<PosUvColor>, tiles: &[u16], columns| {
+        builder.fill_from_tile_indices(
+            tiles,
+            columns,
+            &atlas(),
+            [16.0, 16.0],
+            [0.0, 0.0],
+            WHITE,
+            UvFlip::None,
+        )
+    };
+    assert!(!fill(&mut builder, &[0, 1, 2, 3], 2));
+    assert!(!fill(&mut builder, &[0, 1], 0));
+    assert_eq!(builder.written_quads(), 0);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{emitted, WHITE};
+use stabilkon::*;
+
+const EPSILON: f32 = 0.0001;
+const TEXTURE_SIZE: [f32; 2] = [32.0, 16.0];
+const WHOLE_TEXTURE: [f32; 4] = [0.0, 0.0, 32.0, 16.0];
+
+fn assert_uv(vertex: &PosUvColor, expected: [f32; 2]) {
+    assert!(
+        (vertex.uv.x - expected[0]).abs() < EPSILON && (vertex.uv.y - expected[1]).abs() < EPSILON,
+        "{:?} is not close to {:?}",
+        vertex.uv,
+        expected
+    );
+}
+
+#[test]
+fn bottom_right_uv_is_multiplied_by_tile_count() {
+    let params = PosColorTiledSource::new(
+        [0.0, 0.0],
+        WHITE,
+        [320.0, 32.0],
+        [10.0, 2.0],
+        WHOLE_TEXTURE,
+        UvFlip::Vertical,
+    );
+    let vertices = emitted(TEXTURE_SIZE, false, &params);
+    assert_uv(&vertices[0], [0.0, 0.0]);
+    assert_uv(&vertices[2], [10.0, 2.0]);
+    assert_eq!(vertices[2].position.x, 320.0);
+    assert_eq!(vertices[2].position.y, 32.0);
+}
+
+#[test]
+fn fractional_tile_count_produces_partial_tiles() {
+    let params = PosColorTiledSource::new(
+        [0.0, 0.0],
+        WHITE,
+        [80.0, 16.0],
+        [2.5, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    );
+    let vertices = emitted(TEXTURE_SIZE, false, &params);
+    assert_uv(&vertices[0], [0.0, 0.0]);
+    assert_uv(&vertices[2], [1.25, 1.0]);
+}
+
+#[test]
+fn single_tile_matches_sized_quad() {
+    let tiled = PosColorTiledSource::new(
+        [4.0, 4.0],
+        WHITE,
+        [64.0, 64.0],
+        [1.0, 1.0],
+        WHOLE_TEXTURE,
+        UvFlip::None,
+    );
+    let sized =
+        PosColorSizeSource::new([4.0, 4.0], WHITE, [64.0, 64.0], WHOLE_TEXTURE, UvFlip::None);
+    assert_eq!(
+        emitted(TEXTURE_SIZE, false, &tiled),
+        emitted(TEXTURE_SIZE, false, &sized)
+    );
+}
@@ -0,0 +1,103 @@
+use stabilkon::*;
+
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+fn filled(builder: &mut MeshFromQuads<PosUvColor>) {
+    builder.set_pos_color_source(0, [0.0, 0.0], WHITE, [0.0, 0.0, 16.0, 16.0], UvFlip::None);
+    builder.set_pos_color_source(
+        1,
+        [16.0, 8.0],
+        [0.5, 0.5, 0.5, 1.0],
+        [16.0, 0.0, 16.0, 16.0],
+        UvFlip::Vertical,
+    );
+}
+
+#[test]
+fn expands_indexed_triangles_into_vertices() {
+    for winding in [WindingOrder::Clockwise, WindingOrder::CounterClockwise] {
+        let mut indexed: MeshFromQuads<PosUvColor> =
+            MeshFromQuads::new_with_winding([64.0, 64.0], false, 2, true, winding).unwrap();
+        filled(&mut indexed);
+        let triangles: Vec<PosUvColor> = indexed
+            .indices()
+            .unwrap()
+            .iter()
+            .map(|&index| indexed.vertices()[index as usize])
+            .collect();
+
+        let unindexed = indexed.to_unindexed().unwrap();
+        assert_eq!(unindexed.vertices().len(), 12);
+        assert_eq!(unindexed.vertices(), &triangles[..]);
+        assert_eq!(unindexed.indices(), None);
+        assert_eq!(unindexed.vertices_per_quad(), 6);
+        assert_eq!(unindexed.written_quads(), 2);
+    }
+}
+
+#[test]
+fn matches_builder_created_without_indices() {
+    let mut indexed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    filled(&mut indexed);
+    let mut unindexed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 2).unwrap();
+    filled(&mut unindexed);
+
+    let mut converted = indexed.to_unindexed().unwrap();
+    assert_eq!(converted.vertices(), unindexed.vertices());
+    assert_eq!(converted.vertices_limit(), unindexed.vertices_limit());
+    // Converted builder keeps working as an unindexed one:
+    assert!(converted.set_pos_color_source(
+        1,
+        [32.0, 32.0],
+        WHITE,
+        [0.0, 0.0, 8.0, 8.0],
+        UvFlip::None
+    ));
+    assert_eq!(converted.vertices()[6].position, [32.0, 32.0].into());
+}
+
+fn triangles(builder: &MeshFromQuads<PosUvColor>) -> Vec<PosUvColor> {
+    match builder.indices() {
+        Some(indices) => indices
+            .iter()
+            .map(|&index| builder.vertices()[index as usize])
+            .collect(),
+        None => builder.vertices().to_vec(),
+    }
+}
+
+#[test]
+fn to_indexed_collapses_duplicated_corners() {
+    for winding in [WindingOrder::Clockwise, WindingOrder::CounterClockwise] {
+        let mut unindexed: MeshFromQuads<PosUvColor> =
+            MeshFromQuads::new_with_winding([64.0, 64.0], false, 2, false, winding).unwrap();
+        filled(&mut unindexed);
+        let expected = triangles(&unindexed);
+        assert_eq!(unindexed.vertices().len(), 12);
+
+        let indexed = unindexed.to_indexed().unwrap();
+        assert_eq!(indexed.vertices().len(), 8);
+        assert_eq!(indexed.vertices_per_quad(), 4);
+        assert_eq!(indexed.written_quads(), 2);
+        assert_eq!(triangles(&indexed), expected);
+
+        let round_tripped = indexed.to_unindexed().unwrap();
+        assert_eq!(round_tripped.vertices(), &expected[..]);
+    }
+}
+
+#[test]
+fn to_indexed_matches_builder_created_with_indices() {
+    let mut unindexed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 2).unwrap();
+    filled(&mut unindexed);
+    let mut indexed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    filled(&mut indexed);
+
+    let converted = unindexed.to_indexed().unwrap();
+    assert_eq!(converted.vertices(), indexed.vertices());
+    assert_eq!(converted.indices(), indexed.indices());
+}
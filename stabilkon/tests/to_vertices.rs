@@ -0,0 +1,38 @@
+mod common;
+
+use common::quad_at;
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+
+fn to_vertices(params: &PosColorSource, use_indices: bool) -> Vec<PosUvColor> {
+    params.to_vertices(TEXTURE_SIZE.into(), VertexOptions::new(true, use_indices))
+}
+
+#[test]
+fn to_vertices_matches_builder_vertices() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new(TEXTURE_SIZE, 1)
+            .with_half_pixel_offset(true)
+            .with_indices(use_indices)
+            .build()
+            .unwrap();
+        builder.set(0, &quad_at(8.0));
+        assert_eq!(&to_vertices(&quad_at(8.0), use_indices), builder.vertices());
+    }
+}
+
+#[test]
+fn to_vertices_into_reuses_buffer_without_stale_vertices() {
+    let mut buffer: Vec<PosUvColor> = Vec::new();
+    for (x, use_indices) in [(0.0, false), (16.0, true), (32.0, false)] {
+        quad_at(x).to_vertices_into(
+            TEXTURE_SIZE.into(),
+            VertexOptions::new(true, use_indices),
+            &mut buffer,
+        );
+        assert_eq!(buffer, to_vertices(&quad_at(x), use_indices));
+    }
+    assert_eq!(buffer.len(), 6);
+    assert!(buffer.capacity() >= 6);
+}
@@ -0,0 +1,80 @@
+use stabilkon::{mint::ColumnMatrix3, *};
+
+#[test]
+fn transform_all_moves_positions_only() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    builder.set_pos_color_source(
+        1,
+        [10.0, 20.0],
+        [0.5, 0.5, 0.5, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    );
+    let before = builder.vertices().to_vec();
+
+    // Scale by 2 along x and 3 along y, then translate by (100, -50):
+    let matrix = ColumnMatrix3::from([[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [100.0, -50.0, 1.0]]);
+    builder.transform_all(matrix);
+
+    let top_left = builder.vertices()[4];
+    assert_eq!(top_left.position.x, 120.0);
+    assert_eq!(top_left.position.y, 10.0);
+    for (old, new) in before.iter().zip(builder.vertices()) {
+        assert_eq!(new.position.x, old.position.x * 2.0 + 100.0);
+        assert_eq!(new.position.y, old.position.y * 3.0 - 50.0);
+        assert_eq!(new.uv, old.uv);
+        assert_eq!(new.color, old.color);
+    }
+}
+
+#[test]
+fn affine2_matches_detailed_params_corners() {
+    const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+    let (position, origin, size, scale, rotation) =
+        ([30.0, 40.0], [8.0, 4.0], [16.0, 8.0], [2.0, 3.0], 0.7);
+
+    let mut detailed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    detailed.set(
+        0,
+        &DetailedParams::new(
+            position,
+            WHITE,
+            origin,
+            size,
+            scale,
+            rotation,
+            [0.0, 0.0, 16.0, 8.0],
+            UvFlip::None,
+        ),
+    );
+
+    // Same quad without transform, with corners relative to origin, then moved by the composed matrix:
+    let mut transformed: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 1).unwrap();
+    transformed.set_pos_color_size_source(
+        0,
+        [-origin[0], -origin[1]],
+        WHITE,
+        size,
+        [0.0, 0.0, 16.0, 8.0],
+        UvFlip::None,
+    );
+    let world_origin = [position[0] + origin[0], position[1] + origin[1]];
+    transformed.transform_all(affine2(world_origin, rotation, scale));
+
+    for (expected, actual) in detailed.vertices().iter().zip(transformed.vertices()) {
+        assert!((expected.position.x - actual.position.x).abs() < 0.001);
+        assert!((expected.position.y - actual.position.y).abs() < 0.001);
+    }
+}
+
+#[test]
+fn affine2_without_rotation_scales_then_translates() {
+    let matrix = affine2([100.0, -50.0], 0.0, [2.0, 3.0]);
+    assert_eq!(
+        matrix,
+        ColumnMatrix3::from([[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [100.0, -50.0, 1.0]])
+    );
+}
@@ -0,0 +1,38 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+
+#[test]
+fn indexed_builder_counts_triangles_and_indices() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 10).unwrap();
+    assert_eq!(builder.triangle_count(), 20);
+    assert_eq!(builder.index_count(), 60);
+}
+
+#[test]
+fn unindexed_builder_counts_triangles_without_indices() {
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 10).unwrap();
+    assert_eq!(builder.triangle_count(), 20);
+    assert_eq!(builder.index_count(), 0);
+}
+
+#[test]
+fn strip_builder_counts_two_triangles_per_quad() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 10).unwrap();
+    builder
+        .set_index_topology(IndexTopology::TriangleStrip)
+        .unwrap();
+    assert_eq!(builder.triangle_count(), 20);
+    assert_eq!(builder.index_count(), 50);
+}
+
+#[test]
+fn omitted_quads_are_not_counted() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 10).unwrap();
+    builder.set_draw_order(&[2, 5, 7], false).unwrap();
+    assert_eq!(builder.triangle_count(), 6);
+    assert_eq!(builder.index_count(), 18);
+}
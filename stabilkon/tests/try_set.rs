@@ -0,0 +1,54 @@
+mod common;
+
+use common::quad_at;
+use stabilkon::*;
+
+#[test]
+fn try_set_writes_quads_in_range() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    builder.try_set(1, &quad_at(8.0)).unwrap();
+    assert_eq!(builder.written_quads(), 2);
+    assert_eq!(builder.vertices()[4].position.x, 8.0);
+}
+
+#[test]
+fn try_set_reports_offending_index_and_limit() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    match builder.try_set(2, &quad_at(8.0)) {
+        Err(Error::QuadIndexOutOfRange { index, limit, .. }) => {
+            assert_eq!(index, 2);
+            assert_eq!(limit, 2);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(builder.written_quads(), 0);
+    assert_eq!(builder.dirty_vertex_range(), None);
+}
+
+#[test]
+fn try_set_does_not_overflow_for_huge_indices() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 2).unwrap();
+    assert!(builder.try_set(u32::MAX, &quad_at(8.0)).is_err());
+}
+
+#[test]
+fn set_rejects_indices_overflowing_vertex_offset() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new([64.0, 64.0], 2)
+            .with_indices(use_indices)
+            .build()
+            .unwrap();
+        let before = builder.vertices().to_vec();
+        // 2^30 * 4 vertices would wrap around to offset 0 with unchecked u32 arithmetic:
+        for quad_index in [u32::MAX, 1 << 30, u32::MAX / 6 + 1] {
+            assert!(!builder.set(quad_index, &quad_at(8.0)));
+            assert!(!builder.set_if_changed(quad_index, &quad_at(8.0)));
+        }
+        assert_eq!(builder.vertices(), &before[..]);
+        assert_eq!(builder.written_quads(), 0);
+        assert_eq!(builder.dirty_vertex_range(), None);
+    }
+}
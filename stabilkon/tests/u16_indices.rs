@@ -0,0 +1,60 @@
+use stabilkon::*;
+
+#[test]
+fn u16_indices_match_u32_indices() {
+    let indices = generate_quad_indices(100).unwrap();
+    let indices_u16 = generate_quad_indices_u16(100).unwrap();
+    assert_eq!(indices.len(), indices_u16.len());
+    assert!(indices
+        .iter()
+        .zip(&indices_u16)
+        .all(|(&index, &index_u16)| index == index_u16 as u32));
+}
+
+#[test]
+fn u16_indices_fit_up_to_overflow_boundary() {
+    assert_eq!(MAX_U16_INDEXED_QUADS, 16384);
+    let indices = generate_quad_indices_u16(MAX_U16_INDEXED_QUADS).unwrap();
+    assert_eq!(indices.iter().max(), Some(&u16::MAX));
+    assert!(matches!(
+        generate_quad_indices_u16(MAX_U16_INDEXED_QUADS + 1),
+        Err(Error::IndicesExceedU16 {
+            quad_count: 16385,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn u16_indexed_builder_keeps_indices_in_sync() {
+    assert!(MeshFromQuads::<PosUvColor>::new_u16_indexed([32.0, 32.0], false, 16385).is_err());
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_u16_indexed([32.0, 32.0], false, MAX_U16_INDEXED_QUADS).unwrap();
+    assert_eq!(
+        builder.indices_u16().unwrap().len(),
+        builder.indices().unwrap().len()
+    );
+
+    builder.set_draw_order(&[2, 0], true).unwrap();
+    let as_u32: Vec<u32> = builder
+        .indices_u16()
+        .unwrap()
+        .iter()
+        .map(|&index| index as u32)
+        .collect();
+    assert_eq!(&as_u32, builder.indices().unwrap());
+
+    assert!(builder.resize(MAX_U16_INDEXED_QUADS + 1).is_err());
+    assert_eq!(builder.quad_limit(), MAX_U16_INDEXED_QUADS);
+    builder.resize(3).unwrap();
+    assert_eq!(
+        builder.indices_u16().unwrap(),
+        &generate_quad_indices_u16(3).unwrap()
+    );
+}
+
+#[test]
+fn regular_builders_have_no_u16_indices() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new([32.0, 32.0], false, 4).unwrap();
+    assert!(builder.indices_u16().is_none());
+}
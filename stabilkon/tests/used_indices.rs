@@ -0,0 +1,71 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [0.0, 0.0, 16.0, 16.0];
+
+fn set_quad(builder: &mut MeshFromQuads<PosUvColor>, quad_index: u32) -> bool {
+    let position = [quad_index as f32 * 16.0, 0.0];
+    builder.set_pos_color_source(quad_index, position, WHITE, SOURCE, UvFlip::None)
+}
+
+#[test]
+fn fresh_builder_uses_no_indices() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 4).unwrap();
+    assert_eq!(builder.written_quads(), 0);
+    assert!(builder.used_indices().is_empty());
+    assert!(builder.used_vertices().is_empty());
+    assert_eq!(builder.indices().unwrap().len(), 24);
+}
+
+#[test]
+fn used_indices_are_prefix_up_to_last_set_quad() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 8).unwrap();
+    assert!(set_quad(&mut builder, 0));
+    assert!(set_quad(&mut builder, 2));
+
+    assert_eq!(builder.written_quads(), 3);
+    assert_eq!(builder.used_indices(), &builder.indices().unwrap()[..18]);
+    assert_eq!(builder.used_vertices(), &builder.vertices()[..12]);
+}
+
+#[test]
+fn unindexed_builder_has_no_used_indices() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 4).unwrap();
+    assert!(set_quad(&mut builder, 1));
+    assert!(builder.used_indices().is_empty());
+    assert_eq!(builder.used_vertices().len(), 12);
+}
+
+#[test]
+fn builder_from_existing_vertices_uses_all_of_them() {
+    let mut source: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    assert!(set_quad(&mut source, 0));
+    let vertices = source.vertices().to_vec();
+    let indices = source.indices().cloned();
+
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::from_texture_vertices_indices(TEXTURE_SIZE, false, vertices, indices)
+            .unwrap();
+    assert_eq!(builder.quad_limit(), 3);
+    assert_eq!(builder.written_quads(), 3);
+    assert_eq!(builder.used_indices().len(), 18);
+    assert_eq!(builder.used_vertices(), source.vertices());
+}
+
+#[test]
+fn partial_trailing_quad_of_existing_vertices_is_not_written() {
+    let mut source: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 2).unwrap();
+    assert!(set_quad(&mut source, 0));
+    assert!(set_quad(&mut source, 1));
+    let vertices = source.vertices()[..9].to_vec();
+
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::from_texture_vertices_indices(TEXTURE_SIZE, false, vertices, None).unwrap();
+    assert_eq!(builder.written_quads(), 1);
+    assert_eq!(builder.used_vertices(), &source.vertices()[..6]);
+    assert!(builder.used_indices().is_empty());
+}
@@ -0,0 +1,145 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [256.0, 128.0];
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const SOURCE: [f32; 4] = [64.0, 32.0, 32.0, 16.0];
+
+fn inset_vertices<T: QuadDrawParams>(params: &T, uv_inset: impl Into<UvInset>) -> [PosUvColor; 4] {
+    let mut vertices = [PosUvColor::new([0.0, 0.0], [0.0, 0.0], WHITE); 4];
+    params.set_vertices(
+        TEXTURE_SIZE.into(),
+        VertexOptions::new(uv_inset, true),
+        0,
+        &mut vertices,
+    );
+    vertices
+}
+
+#[test]
+fn half_pixel_offset_matches_symmetric_inset() {
+    for flip in [
+        UvFlip::None,
+        UvFlip::Horizontal,
+        UvFlip::Vertical,
+        UvFlip::Both,
+        UvFlip::DiagonalLeft,
+    ] {
+        let params = PosColorSource::new([10.0, 10.0], WHITE, SOURCE, flip);
+        assert_eq!(
+            inset_vertices(&params, true),
+            inset_vertices(&params, UvInset::uniform(0.5))
+        );
+        assert_eq!(
+            inset_vertices(&params, false),
+            inset_vertices(&params, UvInset::NONE)
+        );
+    }
+}
+
+#[test]
+fn half_pixel_offset_keeps_previous_uvs() {
+    let params = PosColorSource::new([0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    let vertices = inset_vertices(&params, true);
+    // Top-left vertex samples the bottom-left texel center in the default bottom-to-top system:
+    assert_eq!(vertices[0].uv.x, 64.5 / 256.0);
+    assert_eq!(vertices[0].uv.y, 47.5 / 128.0);
+    assert_eq!(vertices[2].uv.x, 95.5 / 256.0);
+    assert_eq!(vertices[2].uv.y, 32.5 / 128.0);
+}
+
+#[test]
+fn asymmetric_inset_offsets_each_edge() {
+    let params = PosColorSource::new([0.0, 0.0], WHITE, SOURCE, UvFlip::None);
+    let vertices = inset_vertices(&params, UvInset::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(vertices[0].uv.x, 65.0 / 256.0);
+    assert_eq!(vertices[0].uv.y, 44.0 / 128.0);
+    assert_eq!(vertices[2].uv.x, 93.0 / 256.0);
+    assert_eq!(vertices[2].uv.y, 34.0 / 128.0);
+    // Inset does not change quad size:
+    assert_eq!(vertices[2].position.x, 32.0);
+    assert_eq!(vertices[2].position.y, 16.0);
+}
+
+#[test]
+fn builder_uses_its_inset_and_reads_source_back() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, true, 2).unwrap();
+    assert_eq!(builder.uv_inset(), UvInset::HALF_PIXEL);
+
+    let inset = UvInset::new(1.0, 2.0, 3.0, 4.0);
+    builder.set_uv_inset(inset);
+    let params = PosColorSource::new([5.0, 6.0], WHITE, SOURCE, UvFlip::Vertical);
+    builder.set(1, &params);
+    assert_eq!(&builder.vertices()[4..8], &inset_vertices(&params, inset));
+    assert_eq!(builder.get_quad(1), Some(params));
+}
+
+fn uv_span_in_texels(source: [f32; 4]) -> (f32, f32, f32, f32) {
+    let params = PosColorSource::new([0.0, 0.0], WHITE, source, UvFlip::Vertical);
+    let vertices = inset_vertices(&params, true);
+    // With vertical flip top-left vertex gets the top-left UV:
+    let left = vertices[0].uv.x * TEXTURE_SIZE[0];
+    let top = vertices[0].uv.y * TEXTURE_SIZE[1];
+    let right = vertices[2].uv.x * TEXTURE_SIZE[0];
+    let bottom = vertices[2].uv.y * TEXTURE_SIZE[1];
+    (left, top, right, bottom)
+}
+
+#[test]
+fn half_pixel_offset_does_not_collapse_single_texel_source() {
+    let (left, top, right, bottom) = uv_span_in_texels([10.0, 20.0, 1.0, 1.0]);
+    // Insets are shrunk to a quarter texel, so the middle half of the texel is sampled:
+    assert_eq!((left, top, right, bottom), (10.25, 20.25, 10.75, 20.75));
+}
+
+#[test]
+fn half_pixel_offset_insets_small_and_regular_sources_by_half_texel() {
+    let (left, top, right, bottom) = uv_span_in_texels([10.0, 20.0, 2.0, 2.0]);
+    assert_eq!((left, top, right, bottom), (10.5, 20.5, 11.5, 21.5));
+
+    let (left, top, right, bottom) = uv_span_in_texels([64.0, 32.0, 32.0, 32.0]);
+    assert_eq!((left, top, right, bottom), (64.5, 32.5, 95.5, 63.5));
+}
+
+#[test]
+fn shrunk_inset_is_read_back_as_original_source() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, true, 3).unwrap();
+    for (quad_index, size) in [1.0, 2.0, 32.0].into_iter().enumerate() {
+        let params = PosColorSource::new([0.0, 0.0], WHITE, [8.0, 4.0, size, size], UvFlip::None);
+        builder.set(quad_index as u32, &params);
+        assert_eq!(builder.get_quad(quad_index as u32), Some(params));
+    }
+}
+
+fn corner_uvs(vertices: &[PosUvColor; 4]) -> [[f32; 2]; 4] {
+    vertices.map(|vertex| [vertex.uv.x, vertex.uv.y])
+}
+
+#[test]
+fn diagonal_flips_keep_inset_along_texture_axes() {
+    // Non-square source with different insets on every edge, so swapped axes would be noticeable:
+    let inset = UvInset::new(1.0, 2.0, 3.0, 4.0);
+    let axis_aligned = inset_vertices(
+        &PosColorSource::new([0.0, 0.0], WHITE, SOURCE, UvFlip::None),
+        inset,
+    );
+    for flip in [UvFlip::DiagonalLeft, UvFlip::DiagonalRight] {
+        let rotated = inset_vertices(&PosColorSource::new([0.0, 0.0], WHITE, SOURCE, flip), inset);
+        let expected = flip.arrange_corner_uvs(axis_aligned.map(|vertex| vertex.uv));
+        assert_eq!(
+            corner_uvs(&rotated),
+            expected.map(|uv| [uv.x, uv.y]),
+            "{:?}",
+            flip
+        );
+
+        // U still spans source width minus left and right insets, V spans height minus top and bottom ones:
+        let (min_u, max_u) = rotated.iter().fold((f32::MAX, f32::MIN), |(min, max), v| {
+            (min.min(v.uv.x), max.max(v.uv.x))
+        });
+        let (min_v, max_v) = rotated.iter().fold((f32::MAX, f32::MIN), |(min, max), v| {
+            (min.min(v.uv.y), max.max(v.uv.y))
+        });
+        assert_eq!((min_u, max_u), (65.0 / 256.0, 93.0 / 256.0));
+        assert_eq!((min_v, max_v), (34.0 / 128.0, 44.0 / 128.0));
+    }
+}
@@ -0,0 +1,255 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+const FLIPS: [UvFlip; 6] = [
+    UvFlip::None,
+    UvFlip::Horizontal,
+    UvFlip::Vertical,
+    UvFlip::Both,
+    UvFlip::DiagonalLeft,
+    UvFlip::DiagonalRight,
+];
+
+/// Wraps plain quad params, so only required methods are implemented and default vertex calculation is used.
+struct DefaultPath(PosColorSource);
+
+impl QuadDrawParams for DefaultPath {
+    fn get_color(&self) -> mint::Vector4<f32> {
+        self.0.get_color()
+    }
+
+    fn corner_points(
+        &self,
+        texture_size: mint::Vector2<f32>,
+        c1: &mut mint::Vector2<f32>,
+        c2: &mut mint::Vector2<f32>,
+        c3: &mut mint::Vector2<f32>,
+        c4: &mut mint::Vector2<f32>,
+    ) {
+        self.0.corner_points(texture_size, c1, c2, c3, c4);
+    }
+
+    fn uvs(
+        &self,
+        texture_size: mint::Vector2<f32>,
+        uv_inset: UvInset,
+        top_left: &mut mint::Vector2<f32>,
+        bottom_right: &mut mint::Vector2<f32>,
+    ) {
+        self.0.uvs(texture_size, uv_inset, top_left, bottom_right);
+    }
+
+    fn flip(&self) -> UvFlip {
+        self.0.flip()
+    }
+}
+
+fn all_options() -> Vec<VertexOptions> {
+    let mut all = Vec::new();
+    for use_indices in [true, false] {
+        for winding in [WindingOrder::Clockwise, WindingOrder::CounterClockwise] {
+            for alpha_mode in [AlphaMode::AsIs, AlphaMode::UnpremultiplyColor] {
+                all.push(
+                    VertexOptions::new(true, use_indices)
+                        .with_alpha_mode(alpha_mode)
+                        .with_winding(winding),
+                );
+            }
+        }
+    }
+    all
+}
+
+#[test]
+fn new_options_use_colors_as_is_and_clockwise_winding() {
+    let options = VertexOptions::new(true, false);
+    assert_eq!(options.uv_inset, UvInset::HALF_PIXEL);
+    assert!(!options.use_indices);
+    assert_eq!(options.alpha_mode, AlphaMode::AsIs);
+    assert_eq!(options.winding, WindingOrder::Clockwise);
+    assert_eq!(
+        VertexOptions::default(),
+        VertexOptions::new(UvInset::NONE, false)
+    );
+}
+
+#[test]
+fn plain_quad_overrides_match_default_vertices() {
+    for flip in FLIPS {
+        let params = PosColorSource::new(
+            [8.0, 4.0],
+            [0.5, 0.25, 0.5, 0.5],
+            [16.0, 0.0, 16.0, 32.0],
+            flip,
+        );
+        let default_path = DefaultPath(params.clone());
+        for options in all_options() {
+            let vertices: Vec<PosUvColor> = params.to_vertices(TEXTURE_SIZE.into(), options);
+            let expected: Vec<PosUvColor> = default_path.to_vertices(TEXTURE_SIZE.into(), options);
+            assert_eq!(vertices, expected, "{:?} {:?}", flip, options);
+
+            let mut set_vertices = vec![PosUvColor::new([0.0, 0.0], [0.0, 0.0], [0.0; 4]); 7];
+            params.set_vertices(TEXTURE_SIZE.into(), options, 1, &mut set_vertices);
+            assert_eq!(&set_vertices[1..1 + expected.len()], expected.as_slice());
+        }
+    }
+}
+
+#[test]
+fn builder_vertex_options_reproduce_builder_vertices() {
+    for use_indices in [true, false] {
+        let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new_with_winding(
+            TEXTURE_SIZE,
+            true,
+            1,
+            use_indices,
+            WindingOrder::CounterClockwise,
+        )
+        .unwrap();
+        builder.set_alpha_mode(AlphaMode::PremultiplyColor);
+        let params = PosColorSource::new(
+            [8.0, 4.0],
+            [1.0, 0.5, 0.5, 0.5],
+            [0.0, 0.0, 16.0, 16.0],
+            UvFlip::Vertical,
+        );
+        builder.set(0, &params);
+
+        let options = builder.vertex_options();
+        assert_eq!(options.uv_inset, UvInset::HALF_PIXEL);
+        assert_eq!(options.use_indices, use_indices);
+        assert_eq!(options.alpha_mode, AlphaMode::PremultiplyColor);
+        assert_eq!(options.winding, WindingOrder::CounterClockwise);
+        let vertices: Vec<PosUvColor> = params.to_vertices(TEXTURE_SIZE.into(), options);
+        assert_eq!(vertices.as_slice(), builder.vertices());
+    }
+}
+
+#[test]
+fn builder_placement_matches_set_and_write_vertices() {
+    let plain = PosColorSource::new(
+        [8.0, 4.0],
+        [0.5, 0.25, 0.5, 0.5],
+        [16.0, 0.0, 16.0, 32.0],
+        UvFlip::DiagonalLeft,
+    );
+    let detailed = DetailedParams::new(
+        [4.0, 8.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [8.0, 8.0],
+        [16.0, 16.0],
+        [1.0, 1.0],
+        0.5,
+        [0.0, 16.0, 16.0, 16.0],
+        UvFlip::Horizontal,
+    );
+    let reversed = detailed
+        .clone()
+        .with_winding(WindingOrder::CounterClockwise);
+    for use_indices in [true, false] {
+        for winding in [WindingOrder::Clockwise, WindingOrder::CounterClockwise] {
+            let mut builder: MeshFromQuads<PosUvColor> =
+                MeshFromQuads::new_with_winding(TEXTURE_SIZE, true, 3, use_indices, winding)
+                    .unwrap();
+            builder.set(0, &plain);
+            builder.set(1, &detailed);
+            assert!(builder.set_if_changed(2_u32, &reversed));
+
+            let options = builder.vertex_options();
+            let per_quad = vertices_per_quad(use_indices) as usize;
+            let mut set_vertices = vec![PosUvColor::new([0.0, 0.0], [0.0, 0.0], [0.0; 4]); 18];
+            plain.set_vertices(TEXTURE_SIZE.into(), options, 0, &mut set_vertices);
+            detailed.set_vertices(TEXTURE_SIZE.into(), options, per_quad, &mut set_vertices);
+            reversed.set_vertices(
+                TEXTURE_SIZE.into(),
+                options,
+                per_quad * 2,
+                &mut set_vertices,
+            );
+            assert_eq!(builder.vertices(), &set_vertices[..per_quad * 3]);
+
+            let mut written_vertices = set_vertices.clone();
+            if use_indices {
+                reversed.write_vertices::<_, true>(
+                    TEXTURE_SIZE.into(),
+                    options,
+                    per_quad * 2,
+                    &mut written_vertices,
+                );
+            } else {
+                reversed.write_vertices::<_, false>(
+                    TEXTURE_SIZE.into(),
+                    options,
+                    per_quad * 2,
+                    &mut written_vertices,
+                );
+            }
+            assert_eq!(
+                written_vertices, set_vertices,
+                "{:?} {:?}",
+                use_indices, winding
+            );
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_placement_matches_serial_set() {
+    for use_indices in [true, false] {
+        let quad_at = |quad_index: u32| {
+            PosColorSource::new(
+                [quad_index as f32 * 16.0, 0.0],
+                [1.0, 1.0, 1.0, 1.0],
+                [16.0 * (quad_index % 4) as f32, 0.0, 16.0, 16.0],
+                UvFlip::Vertical,
+            )
+        };
+        let mut serial: MeshFromQuads<PosUvColor> = MeshFromQuads::new_with_winding(
+            TEXTURE_SIZE,
+            true,
+            8,
+            use_indices,
+            WindingOrder::CounterClockwise,
+        )
+        .unwrap();
+        let mut parallel = serial.clone();
+        for quad_index in 0..8 {
+            serial.set(quad_index, &quad_at(quad_index));
+        }
+        parallel.par_set_each(|quad_index| Some(quad_at(quad_index)));
+        assert_eq!(parallel.vertices(), serial.vertices());
+    }
+}
+
+#[test]
+fn batch_setters_match_set_vertices_for_both_layouts() {
+    let quads: Vec<PosColorSource> = (0..3)
+        .map(|quad_index| {
+            PosColorSource::new(
+                [quad_index as f32 * 16.0, 0.0],
+                [1.0, 1.0, 1.0, 1.0],
+                [16.0 * quad_index as f32, 0.0, 16.0, 16.0],
+                UvFlip::Horizontal,
+            )
+        })
+        .collect();
+    for use_indices in [true, false] {
+        let from_quads: MeshFromQuads<PosUvColor> =
+            MeshFromQuads::from_quads(TEXTURE_SIZE, true, use_indices, quads.clone()).unwrap();
+        let mut from_slice: MeshFromQuads<PosUvColor> = MeshFromQuadsConfig::new(TEXTURE_SIZE, 3)
+            .with_half_pixel_offset(true)
+            .with_indices(use_indices)
+            .build()
+            .unwrap();
+        assert!(from_slice.set_slice(0, &quads));
+
+        let options = from_quads.vertex_options();
+        let expected: Vec<PosUvColor> = quads
+            .iter()
+            .flat_map(|params| params.to_vertices::<PosUvColor>(TEXTURE_SIZE.into(), options))
+            .collect();
+        assert_eq!(from_quads.vertices(), &expected);
+        assert_eq!(from_slice.vertices(), &expected);
+    }
+}
@@ -0,0 +1,64 @@
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 64.0];
+
+#[test]
+fn indexed_quads_span_four_vertices() {
+    let builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    assert_eq!(builder.vertex_range_of(0), Some(0..4));
+    assert_eq!(builder.vertex_range_of(2), Some(8..12));
+    assert_eq!(builder.vertex_range_of(3), None);
+}
+
+#[test]
+fn unindexed_quads_span_six_vertices() {
+    let builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 3).unwrap();
+    assert_eq!(builder.vertex_range_of(0), Some(0..6));
+    assert_eq!(builder.vertex_range_of(2), Some(12..18));
+    assert_eq!(builder.vertex_range_of(3), None);
+}
+
+#[test]
+fn range_covers_vertices_written_by_set() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices(TEXTURE_SIZE, false, 3).unwrap();
+    builder.set_pos_color_source(
+        1,
+        [8.0, 8.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 8.0, 8.0],
+        UvFlip::None,
+    );
+    let range = builder.vertex_range_of(1).unwrap();
+    assert_eq!(builder.dirty_vertex_range(), Some(range.clone()));
+    assert!(builder.vertices()[range]
+        .iter()
+        .all(|vertex| vertex.color == [1.0, 1.0, 1.0, 1.0].into()));
+}
+
+#[test]
+fn quad_vertices_mut_persists_changes_and_marks_them_dirty() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new(TEXTURE_SIZE, false, 3).unwrap();
+    builder.clear_dirty();
+
+    let quad_vertices = builder.quad_vertices_mut(1).unwrap();
+    assert_eq!(quad_vertices.len(), 4);
+    quad_vertices[2].color = [0.25, 0.5, 0.75, 1.0].into();
+
+    assert_eq!(builder.vertices()[6].color, [0.25, 0.5, 0.75, 1.0].into());
+    assert_eq!(builder.dirty_vertex_range(), Some(4..8));
+    assert!(builder.quad_vertices_mut(3).is_none());
+}
+
+#[test]
+fn quad_vertices_mut_populates_lazy_quads() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_lazy(TEXTURE_SIZE, false, 3).unwrap();
+    assert_eq!(
+        builder.quad_vertices_mut(2).map(|vertices| vertices.len()),
+        Some(4)
+    );
+    assert_eq!(builder.vertices().len(), 12);
+}
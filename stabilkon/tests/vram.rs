@@ -0,0 +1,66 @@
+mod common;
+
+use common::quad_at;
+use stabilkon::*;
+
+#[test]
+fn estimates_indexed_mesh_vram() {
+    // PosUvColor is 8 floats: 2 for position, 2 for UV and 4 for color.
+    assert_eq!(std::mem::size_of::<PosUvColor>(), 32);
+    // 1000 quads * (4 vertices * 32 bytes + 6 indices * 4 bytes):
+    assert_eq!(estimate_vram_bytes::<PosUvColor>(1000, true), 152_000);
+}
+
+#[test]
+fn estimates_unindexed_mesh_vram() {
+    // 1000 quads * 6 vertices * 32 bytes, no indices:
+    assert_eq!(estimate_vram_bytes::<PosUvColor>(1000, false), 192_000);
+}
+
+#[test]
+fn estimate_does_not_overflow_for_huge_quad_limits() {
+    assert_eq!(
+        estimate_vram_bytes::<PosUvColor>(u32::MAX, true),
+        u32::MAX as u64 * 152
+    );
+    assert!(estimate_vram_bytes::<PosUvColor>(u32::MAX, true) > MAX_MESH_VRAM_BYTES);
+}
+
+#[test]
+fn map_fits_recommended_budget() {
+    let quad_limit = quad_limit_for_map((256, 256)).unwrap();
+    assert!(estimate_vram_bytes::<PosUvColor>(quad_limit, true) <= RECOMMENDED_MESH_VRAM_BYTES);
+}
+
+#[test]
+fn chunks_quads_exceeding_budget_into_several_meshes() {
+    let all: Vec<_> = (0..10).map(|i| quad_at(i as f32 * 16.0)).collect();
+    // Budget fits 4 quads and a bit, so 10 quads are split into 4 + 4 + 2:
+    let max_bytes = estimate_vram_bytes::<PosUvColor>(4, true) + 100;
+    let meshes: Vec<MeshFromQuads<PosUvColor>> =
+        chunk_quads(all.clone(), [64.0, 64.0], false, max_bytes).unwrap();
+
+    let quad_counts: Vec<_> = meshes.iter().map(|mesh| mesh.quad_limit()).collect();
+    assert_eq!(quad_counts, [4, 4, 2]);
+    for mesh in &meshes {
+        assert!(estimate_vram_bytes::<PosUvColor>(mesh.quad_limit(), true) <= max_bytes);
+    }
+    let chunked: Vec<_> = meshes.iter().flat_map(|mesh| mesh.quads()).collect();
+    assert_eq!(chunked, all);
+}
+
+#[test]
+fn chunking_fits_into_single_mesh_within_budget() {
+    let all: Vec<_> = (0..10).map(|i| quad_at(i as f32)).collect();
+    let meshes: Vec<MeshFromQuads<PosUvColor>> =
+        chunk_quads(all, [64.0, 64.0], false, RECOMMENDED_MESH_VRAM_BYTES).unwrap();
+    assert_eq!(meshes.len(), 1);
+    assert_eq!(meshes[0].written_quads(), 10);
+}
+
+#[test]
+fn chunking_fails_when_budget_cannot_fit_a_quad() {
+    let result: Result<Vec<MeshFromQuads<PosUvColor>>, _> =
+        chunk_quads(vec![quad_at(0.0)], [64.0, 64.0], false, 100);
+    assert!(matches!(result, Err(Error::VramBudgetIsTooSmall { .. })));
+}
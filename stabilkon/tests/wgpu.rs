@@ -0,0 +1,44 @@
+#![cfg(feature = "wgpu")]
+
+use stabilkon::*;
+
+#[test]
+fn layout_matches_vertex_memory() {
+    let layout = PosUvColor::vertex_buffer_layout();
+    assert_eq!(
+        layout.array_stride,
+        std::mem::size_of::<PosUvColor>() as wgpu::BufferAddress
+    );
+    assert_eq!(layout.step_mode, wgpu::VertexStepMode::Vertex);
+
+    let formats: Vec<_> = layout
+        .attributes
+        .iter()
+        .map(|attribute| attribute.format)
+        .collect();
+    assert_eq!(
+        formats,
+        vec![
+            wgpu::VertexFormat::Float32x2,
+            wgpu::VertexFormat::Float32x2,
+            wgpu::VertexFormat::Float32x4
+        ]
+    );
+    let locations: Vec<_> = layout
+        .attributes
+        .iter()
+        .map(|attribute| attribute.shader_location)
+        .collect();
+    assert_eq!(locations, vec![0, 1, 2]);
+}
+
+#[test]
+fn attributes_are_tightly_packed_in_order() {
+    let layout = PosUvColor::vertex_buffer_layout();
+    let mut expected_offset = 0;
+    for attribute in layout.attributes {
+        assert_eq!(attribute.offset, expected_offset);
+        expected_offset += attribute.format.size();
+    }
+    assert_eq!(expected_offset, layout.array_stride);
+}
@@ -0,0 +1,97 @@
+use stabilkon::*;
+
+/// Twice the signed area of a triangle, its sign tells the triangle orientation.
+fn signed_area(a: &PosUvColor, b: &PosUvColor, c: &PosUvColor) -> f32 {
+    (b.position.x - a.position.x) * (c.position.y - a.position.y)
+        - (b.position.y - a.position.y) * (c.position.x - a.position.x)
+}
+
+fn triangles(builder: &MeshFromQuads<PosUvColor>) -> Vec<[PosUvColor; 3]> {
+    let vertices = builder.vertices();
+    match builder.indices() {
+        Some(indices) => indices
+            .chunks(3)
+            .map(|triangle| {
+                [
+                    vertices[triangle[0] as usize],
+                    vertices[triangle[1] as usize],
+                    vertices[triangle[2] as usize],
+                ]
+            })
+            .collect(),
+        None => vertices
+            .chunks(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+            .collect(),
+    }
+}
+
+fn built_with(use_indices: bool, winding: WindingOrder) -> MeshFromQuads<PosUvColor> {
+    let mut builder =
+        MeshFromQuads::new_with_winding([64.0, 64.0], false, 1, use_indices, winding).unwrap();
+    assert!(builder.set_pos_color_source(
+        0,
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    ));
+    builder
+}
+
+#[test]
+fn counter_clockwise_indices_differ() {
+    let clockwise = generate_quad_indices_with_winding(1, 0, WindingOrder::Clockwise).unwrap();
+    let counter_clockwise =
+        generate_quad_indices_with_winding(1, 0, WindingOrder::CounterClockwise).unwrap();
+    assert_eq!(clockwise, generate_quad_indices(1).unwrap());
+    assert_ne!(clockwise[0..3], counter_clockwise[0..3]);
+    assert_ne!(clockwise[3..6], counter_clockwise[3..6]);
+    assert_eq!(counter_clockwise, vec![0, 3, 2, 2, 1, 0]);
+}
+
+#[test]
+fn counter_clockwise_reverses_triangle_orientation() {
+    for &use_indices in &[true, false] {
+        let clockwise = triangles(&built_with(use_indices, WindingOrder::Clockwise));
+        let counter_clockwise = triangles(&built_with(use_indices, WindingOrder::CounterClockwise));
+        assert_eq!(clockwise.len(), 2);
+        for (cw, ccw) in clockwise.iter().zip(counter_clockwise.iter()) {
+            let cw_area = signed_area(&cw[0], &cw[1], &cw[2]);
+            let ccw_area = signed_area(&ccw[0], &ccw[1], &ccw[2]);
+            assert!(cw_area != 0.0);
+            assert_eq!(cw_area.signum(), -ccw_area.signum());
+        }
+    }
+}
+
+#[test]
+fn quad_winding_overrides_builder_winding() {
+    let mut builder: MeshFromQuads<PosUvColor> = MeshFromQuads::new_with_winding(
+        [64.0, 64.0],
+        false,
+        1,
+        true,
+        WindingOrder::CounterClockwise,
+    )
+    .unwrap();
+    let params = DetailedParams::new(
+        [0.0, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0],
+        [16.0, 16.0],
+        [1.0, 1.0],
+        0.0,
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    )
+    .with_winding(WindingOrder::Clockwise);
+    assert!(builder.set(0, &params));
+    let expected = triangles(&built_with(true, WindingOrder::Clockwise));
+    for (actual, expected) in triangles(&builder).iter().zip(expected.iter()) {
+        assert_eq!(
+            signed_area(&actual[0], &actual[1], &actual[2]).signum(),
+            signed_area(&expected[0], &expected[1], &expected[2]).signum()
+        );
+    }
+}
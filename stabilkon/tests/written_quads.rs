@@ -0,0 +1,40 @@
+use stabilkon::*;
+
+fn set_quad(builder: &mut MeshFromQuads<PosUvColor>, quad_index: u32) -> bool {
+    builder.set_pos_color_source(
+        quad_index,
+        [quad_index as f32, 0.0],
+        [1.0, 1.0, 1.0, 1.0],
+        [0.0, 0.0, 16.0, 16.0],
+        UvFlip::None,
+    )
+}
+
+#[test]
+fn written_quads_survive_out_of_order_set() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new([64.0, 64.0], false, 8).unwrap();
+    assert_eq!(builder.written_quads(), 0);
+    assert!(builder.used_vertices().is_empty());
+
+    assert!(set_quad(&mut builder, 2));
+    assert_eq!(builder.written_quads(), 3);
+    assert!(set_quad(&mut builder, 5));
+    assert_eq!(builder.written_quads(), 6);
+    assert!(set_quad(&mut builder, 1));
+    assert_eq!(builder.written_quads(), 6);
+    assert_eq!(builder.used_vertices().len(), 6 * 4);
+    assert!(!set_quad(&mut builder, 8));
+    assert_eq!(builder.written_quads(), 6);
+
+    builder.clear();
+    assert_eq!(builder.written_quads(), 0);
+}
+
+#[test]
+fn used_vertices_match_unindexed_layout() {
+    let mut builder: MeshFromQuads<PosUvColor> =
+        MeshFromQuads::new_without_indices([64.0, 64.0], false, 8).unwrap();
+    assert!(set_quad(&mut builder, 3));
+    assert_eq!(builder.used_vertices().len(), 4 * 6);
+}
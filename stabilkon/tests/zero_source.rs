@@ -0,0 +1,62 @@
+mod common;
+
+use common::{emitted, WHITE};
+use stabilkon::*;
+
+const TEXTURE_SIZE: [f32; 2] = [64.0, 32.0];
+
+#[test]
+fn zero_width_source_spans_whole_texture_width() {
+    let vertices = emitted(
+        TEXTURE_SIZE,
+        false,
+        &PosColorSource::new([4.0, 2.0], WHITE, [16.0, 8.0, 0.0, 8.0], UvFlip::None),
+    );
+    assert_eq!(
+        vertices[2].position.x - vertices[0].position.x,
+        TEXTURE_SIZE[0]
+    );
+    assert_eq!(vertices[2].position.y - vertices[0].position.y, 8.0);
+    assert_eq!(vertices[0].uv.x, 0.0);
+    assert_eq!(vertices[2].uv.x, 1.0);
+    assert_eq!(vertices[0].uv.y, 16.0 / TEXTURE_SIZE[1]);
+    assert_eq!(vertices[2].uv.y, 8.0 / TEXTURE_SIZE[1]);
+}
+
+#[test]
+fn zero_source_draws_entire_atlas() {
+    let zero = emitted(
+        TEXTURE_SIZE,
+        false,
+        &PosColorSource::new([0.0, 0.0], WHITE, [5.0, 7.0, 0.0, 0.0], UvFlip::None),
+    );
+    let whole = emitted(
+        TEXTURE_SIZE,
+        false,
+        &PosColorSource::new(
+            [0.0, 0.0],
+            WHITE,
+            [0.0, 0.0, TEXTURE_SIZE[0], TEXTURE_SIZE[1]],
+            UvFlip::None,
+        ),
+    );
+    assert_eq!(zero, whole);
+}
+
+#[test]
+fn sized_quad_with_zero_source_samples_entire_atlas() {
+    let vertices = emitted(
+        TEXTURE_SIZE,
+        false,
+        &PosColorSizeSource::new(
+            [0.0, 0.0],
+            WHITE,
+            [10.0, 10.0],
+            [0.0, 0.0, 0.0, 0.0],
+            UvFlip::None,
+        ),
+    );
+    assert_eq!(vertices[2].position.x, 10.0);
+    assert_eq!(vertices[0].uv, [0.0, 1.0].into());
+    assert_eq!(vertices[2].uv, [1.0, 0.0].into());
+}
@@ -88,10 +88,16 @@ impl GameState {
             terrain_tiles_count,
         )
         .map_err(|e| GameError::CustomError(e.to_string()))?;
-        let mut terrain_quad_index = 0_u32;
-        for y in -terrain_size[1] / 2..terrain_size[1] / 2 {
-            for x in -terrain_size[0] / 2..terrain_size[0] / 2 {
-                let position = [x as f32 * tile_size, y as f32 * tile_size];
+        terrain_mesh_builder.fill_grid(
+            0,
+            terrain_size[0] as u32,
+            terrain_size[1] as u32,
+            [
+                -(terrain_size[0] / 2) as f32 * tile_size,
+                -(terrain_size[1] / 2) as f32 * tile_size,
+            ],
+            [tile_size, tile_size],
+            |_, _| {
                 // For terrain, place 80 % of grass tiles and 20 % of flower tiles:
                 let tile_kind = rng.gen_range(0..10);
                 let source = match tile_kind {
@@ -99,16 +105,9 @@ impl GameState {
                     9 => flowers1_source,
                     _ => flowers2_source,
                 };
-                terrain_mesh_builder.set_pos_color_source(
-                    terrain_quad_index,
-                    position,
-                    white_color,
-                    source,
-                    UvFlip::Vertical,
-                );
-                terrain_quad_index += 1;
-            }
-        }
+                Some((source.into(), white_color.into(), UvFlip::Vertical))
+            },
+        );
         let terrain = terrain_mesh_builder.create_mesh(ctx, texture_atlas.clone())?;
 
         // Create bushes and stumps to lay over the grassy terrain:
@@ -50,6 +50,62 @@ pub(crate) fn get_resource_dir() -> path::PathBuf {
     }
 }
 
+fn stabilkon_error(error: stabilkon::Error) -> TetraError {
+    TetraError::PlatformError(error.to_string())
+}
+
+/// Checks that `BuiltMesh::update` reuses or replaces buffers as builder changes,
+/// and that dirty vertices are handed off to the built vertex buffer exactly once.
+fn check_built_mesh_updates(ctx: &mut Context, texture: &Texture) -> Result<()> {
+    let texture_size = [texture.width() as f32, texture.height() as f32];
+    let white_color = [1.0_f32, 1.0, 1.0, 1.0];
+    let source = [0.0, 0.0, 32.0, 32.0];
+    let mut builder: MeshFromQuads<Vertex> =
+        MeshFromQuads::new(texture_size, true, 4).map_err(stabilkon_error)?;
+    let mut built = builder.create_mesh(ctx, texture.clone())?;
+    assert_eq!((built.vertex_count, built.index_count), (16, 24));
+
+    // Update after set keeps buffers of the same length:
+    builder.set_pos_color_source(1, [32.0, 0.0], white_color, source, UvFlip::Vertical);
+    let vertex_buffer = built.vertex_buffer.clone();
+    built.update(ctx, &builder)?;
+    assert_eq!(built.vertex_buffer, vertex_buffer);
+    assert_eq!(built.mesh.vertex_buffer(), &vertex_buffer);
+    assert_eq!((built.vertex_count, built.index_count), (16, 24));
+
+    // Full update leaves dirty range to the builder, partial upload takes it:
+    assert_eq!(builder.dirty_vertex_range(), Some(4..8));
+    builder.update_mesh_dirty(ctx, &built.vertex_buffer);
+    assert_eq!(builder.dirty_vertex_range(), None);
+    builder.set_pos_color_source(3, [96.0, 0.0], white_color, source, UvFlip::Vertical);
+    assert_eq!(builder.dirty_vertex_range(), Some(12..16));
+    builder.update_mesh_dirty(ctx, &built.vertex_buffer);
+    assert_eq!(builder.dirty_vertex_range(), None);
+
+    // Growing the builder needs new buffers:
+    builder.resize(8).map_err(stabilkon_error)?;
+    built.update(ctx, &builder)?;
+    assert_ne!(built.vertex_buffer, vertex_buffer);
+    assert_eq!(built.mesh.vertex_buffer(), &built.vertex_buffer);
+    assert_eq!((built.vertex_count, built.index_count), (32, 48));
+
+    // Textures are validated against the builder texture size, even if mesh has no texture yet:
+    assert_eq!(built.texture_size, mint::Vector2::from(texture_size));
+    built.set_texture(texture.clone())?;
+    let tiny_texture = Texture::from_rgba(ctx, 1, 1, &[255, 255, 255, 255])?;
+    assert!(built.set_texture(tiny_texture.clone()).is_err());
+    built.mesh.reset_texture();
+    assert!(built.set_texture(tiny_texture).is_err());
+    assert!(built.mesh.texture().is_none());
+
+    // Dropping indices drops the index buffer:
+    let builder = builder.to_unindexed().map_err(stabilkon_error)?;
+    built.update(ctx, &builder)?;
+    assert!(built.mesh.index_buffer().is_none());
+    assert_eq!((built.vertex_count, built.index_count), (48, 0));
+    Ok(())
+}
+
 struct GameState {
     debug_text: Text,
     camera: Camera,
@@ -66,6 +122,8 @@ impl GameState {
         texture_atlas.set_filter_mode(ctx, FilterMode::Nearest);
         let use_half_pixel_offset = true;
 
+        check_built_mesh_updates(ctx, &texture_atlas)?;
+
         let texture_atlas_size = [texture_atlas.width() as f32, texture_atlas.height() as f32];
         let tile_size = 32.0_f32;
         let terrain_size = Vec2::from(1024_i32);
@@ -88,10 +146,16 @@ impl GameState {
             terrain_tiles_count,
         )
         .map_err(|e| TetraError::PlatformError(e.to_string()))?;
-        let mut terrain_quad_index = 0_u32;
-        for y in -terrain_size.y / 2..terrain_size.y / 2 {
-            for x in -terrain_size.x / 2..terrain_size.x / 2 {
-                let position = [x as f32 * tile_size, y as f32 * tile_size];
+        terrain_mesh_builder.fill_grid(
+            0,
+            terrain_size.x as u32,
+            terrain_size.y as u32,
+            [
+                -(terrain_size.x / 2) as f32 * tile_size,
+                -(terrain_size.y / 2) as f32 * tile_size,
+            ],
+            [tile_size, tile_size],
+            |_, _| {
                 // For terrain, place 80 % of grass tiles and 20 % of flower tiles:
                 let tile_kind = rng.gen_range(0..10);
                 let source = match tile_kind {
@@ -99,17 +163,12 @@ impl GameState {
                     9 => flowers1_source,
                     _ => flowers2_source,
                 };
-                terrain_mesh_builder.set_pos_color_source(
-                    terrain_quad_index,
-                    position,
-                    white_color,
-                    source,
-                    UvFlip::Vertical,
-                );
-                terrain_quad_index += 1;
-            }
-        }
-        let (terrain, _) = terrain_mesh_builder.create_mesh(ctx, texture_atlas.clone())?;
+                Some((source.into(), white_color.into(), UvFlip::Vertical))
+            },
+        );
+        let terrain = terrain_mesh_builder
+            .create_mesh(ctx, texture_atlas.clone())?
+            .mesh;
 
         // Create bushes and stumps to lay over the grassy terrain:
         let doodads_count = ((terrain_size.x / 2) * (terrain_size.y / 2)) as u32;
@@ -136,7 +195,16 @@ impl GameState {
                 }
             }
         }
-        let (doodads, _doodads_vb) = doodads_mesh_builder.create_mesh(ctx, texture_atlas)?;
+        let mut doodads = doodads_mesh_builder.create_mesh(ctx, texture_atlas)?.mesh;
+
+        // Stop drawing unused doodad quads by dropping their indices, vertex buffer should stay intact:
+        let doodads_vertex_buffer = doodads.vertex_buffer().clone();
+        let used_doodads: Vec<u32> = (0..doodad_quad_index).collect();
+        doodads_mesh_builder
+            .set_draw_order(&used_doodads, false)
+            .map_err(|e| TetraError::PlatformError(e.to_string()))?;
+        doodads_mesh_builder.update_indices_only(ctx, &mut doodads)?;
+        assert_eq!(doodads.vertex_buffer(), &doodads_vertex_buffer);
 
         let camera = Camera::with_window_size(ctx);
         let font = Font::bmfont(ctx, resouce_dir.join("DejaVuSansMono.fnt"))?;